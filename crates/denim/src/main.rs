@@ -0,0 +1,157 @@
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use stdx::DENIM_EXTENSION;
+use syntax::{ast, LineIndex};
+
+#[derive(Parser)]
+#[command(version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Formats Denim source files in place.
+    ///
+    /// Directories are searched for `.👖` files recursively. If no paths
+    /// are given, source is read from stdin and formatted to stdout.
+    #[command(name = "fmt")]
+    Fmt {
+        /// Lists the files that are not formatted instead of formatting them.
+        #[arg(long)]
+        check: bool,
+        /// Files and directories to format.
+        paths: Vec<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Commands::Fmt { check, paths } => fmt(check, &paths),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err:?}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Formats `paths`, returning `false` if any of them could not be formatted,
+/// or, with `check`, if any of them are not formatted already.
+fn fmt(check: bool, paths: &[PathBuf]) -> Result<bool> {
+    if paths.is_empty() {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text).context("failed to read stdin")?;
+        let Some(formatted) = format_text(Path::new("<stdin>"), &text) else {
+            return Ok(false);
+        };
+        if check {
+            return Ok(formatted == text);
+        }
+        io::stdout().write_all(formatted.as_bytes()).context("failed to write stdout")?;
+        return Ok(true);
+    }
+
+    let mut files = Vec::new();
+    for path in paths {
+        collect_denim_files(path, &mut files)?;
+    }
+
+    let mut ok = true;
+    for file in files {
+        let text = fs::read_to_string(&file).with_context(|| format!("failed to read {file:?}"))?;
+        let Some(formatted) = format_text(&file, &text) else {
+            ok = false;
+            continue;
+        };
+        if formatted == text {
+            continue;
+        }
+        if check {
+            println!("{}", file.display());
+            ok = false;
+        } else {
+            fs::write(&file, formatted).with_context(|| format!("failed to write {file:?}"))?;
+        }
+    }
+    Ok(ok)
+}
+
+/// Formats `text`, or reports its syntax errors and returns `None` if it does
+/// not parse.
+fn format_text(path: &Path, text: &str) -> Option<String> {
+    let parse = ast::SourceFile::parse(text);
+    if parse.errors().is_empty() {
+        return Some(formatter::format(&parse.tree()));
+    }
+
+    let line_index = LineIndex::new(text);
+    for error in parse.errors() {
+        let line_col = line_index.line_col(error.range().start());
+        eprintln!("{}:{}:{}: {error}", path.display(), line_col.line + 1, line_col.col + 1);
+    }
+    None
+}
+
+/// Adds `path` to `files` if it is a file, or every `.👖` file underneath
+/// it if it is a directory.
+fn collect_denim_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_owned());
+        return Ok(());
+    }
+
+    let mut entries = fs::read_dir(path)
+        .with_context(|| format!("failed to read {path:?}"))?
+        .map(|entry| entry.map(|it| it.path()))
+        .collect::<io::Result<Vec<_>>>()
+        .with_context(|| format!("failed to read {path:?}"))?;
+    entries.sort();
+
+    for entry in entries {
+        if entry.is_dir() || entry.extension().is_some_and(|it| it == DENIM_EXTENSION) {
+            collect_denim_files(&entry, files)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fmt_formats_denim_files_in_directories() {
+        let dir = std::env::temp_dir().join(format!("denim-fmt-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        let main = dir.join(format!("main.{DENIM_EXTENSION}"));
+        let nested = dir.join("nested").join(format!("lib.{DENIM_EXTENSION}"));
+        let notes = dir.join("notes.txt");
+        fs::write(&main, "fn main( ) { print( 1 ) }").unwrap();
+        fs::write(&nested, "pub fn f()->int{1}").unwrap();
+        fs::write(&notes, "fn   untouched( ) {}").unwrap();
+
+        let paths = [dir.clone()];
+        assert!(!fmt(true, &paths).unwrap());
+        assert!(fmt(false, &paths).unwrap());
+        assert_eq!(fs::read_to_string(&main).unwrap(), "fn main() {\n  print(1)\n}\n");
+        assert_eq!(fs::read_to_string(&nested).unwrap(), "pub fn f() -> int {\n  1\n}\n");
+        assert_eq!(fs::read_to_string(&notes).unwrap(), "fn   untouched( ) {}");
+        assert!(fmt(true, &paths).unwrap());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,145 @@
+//! Lays out the dependencies in the `MetaSection` of a file.
+//!
+//! Dependencies are sorted by path, and grouped by where they come from:
+//! external packages first, then the repository (`~/`), and then the local
+//! directory (`./` and `../`), with an empty line in between groups. Comments
+//! that sit right above a dependency move along with it, while any other
+//! comment stays put and sorts the dependencies on either side of it
+//! separately.
+
+use std::cmp::Ordering;
+
+use syntax::{ast, AstNode, NodeOrToken, SyntaxElement, SyntaxKind::*, SyntaxNode, T};
+
+use crate::{
+    doc::Doc,
+    layout::{self, entries, Entry, ListStyle},
+};
+
+/// Where a dependency comes from, in the order that groups of dependencies
+/// are laid out in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Origin {
+    External,
+    Repo,
+    Local,
+}
+
+/// Lays out the contents of a `MetaSection`, one entry per line.
+pub(crate) fn meta_section(elements: Vec<SyntaxElement>) -> Vec<Doc> {
+    let entries = entries(elements);
+
+    let mut docs = Vec::new();
+    let mut rest = entries.as_slice();
+    while let Some(first) = rest.first() {
+        if !docs.is_empty() {
+            docs.push(if first.blank_line_before { Doc::BlankLine } else { Doc::HardLine });
+        }
+
+        let n_deps = rest.iter().take_while(|it| dep(it).is_some()).count();
+        if n_deps == 0 {
+            docs.extend(first.doc());
+            rest = &rest[1..];
+            continue;
+        }
+
+        let mut deps: Vec<(&Entry, ast::Dep)> =
+            rest[..n_deps].iter().map(|it| (it, dep(it).unwrap())).collect();
+        deps.sort_by(|(_, a), (_, b)| compare(a, b));
+        for (i, (entry, dep)) in deps.iter().enumerate() {
+            if i > 0 {
+                let is_new_group = origin(&deps[i - 1].1) != origin(dep);
+                docs.push(if is_new_group { Doc::BlankLine } else { Doc::HardLine });
+            }
+            docs.extend(entry.doc());
+        }
+        rest = &rest[n_deps..];
+    }
+    docs
+}
+
+/// Lays out the `use` or `show` list of a dependency, breaking it into one
+/// item per line if it does not fit onto the line of the dependency.
+pub(crate) fn use_(node: &SyntaxNode) -> Vec<Doc> {
+    let children: Vec<_> = node.children_with_tokens().collect();
+    let (Some(NodeOrToken::Token(keyword)), Some(NodeOrToken::Token(semicolon))) =
+        (children.first(), children.last())
+    else {
+        return layout::inline(children);
+    };
+    let use_tree_lists = children.iter().filter(|it| it.kind() == USE_TREE_LIST).count();
+    if semicolon.kind() != T![;] || use_tree_lists != 1 {
+        return layout::inline(children);
+    }
+
+    // Comments around the list of use trees are laid out as if they were in
+    // it.
+    let use_trees = layout::flatten_use_tree_lists(&children[1..children.len() - 1]);
+    let style = ListStyle {
+        padding: Doc::Line,
+        pads_close: false,
+        trailing_comma: false,
+        ..ListStyle::default()
+    };
+    vec![layout::list(Doc::text(keyword.text()), &use_trees, Doc::text(";"), style)]
+}
+
+/// Lays out a dependency path, which never contains whitespace.
+pub(crate) fn dep_path(node: &SyntaxNode) -> Vec<Doc> {
+    let mut text = String::new();
+    for token in node.descendants_with_tokens().filter_map(|it| it.into_token()) {
+        match token.kind() {
+            WHITESPACE => {}
+            COMMENT => return layout::inline(node.children_with_tokens()),
+            _ => {
+                // Keep `/ /` from turning into a comment.
+                if text.ends_with('/') && token.text().starts_with('/') {
+                    text.push(' ');
+                }
+                text.push_str(token.text());
+            }
+        }
+    }
+    vec![Doc::Text(text)]
+}
+
+fn dep(entry: &Entry) -> Option<ast::Dep> {
+    entry.element.as_node().cloned().and_then(ast::Dep::cast)
+}
+
+fn origin(dep: &ast::Dep) -> Origin {
+    match dep.dep_path() {
+        Some(ast::DepPath::RepoDepPath(_)) => Origin::Repo,
+        Some(ast::DepPath::LocalDepPath(_)) => Origin::Local,
+        Some(ast::DepPath::ExternalDepPath(_)) | None => Origin::External,
+    }
+}
+
+/// Orders dependencies by origin, then by path, ignoring case unless that is
+/// all that sets the paths apart.
+fn compare(a: &ast::Dep, b: &ast::Dep) -> Ordering {
+    let path = |dep: &ast::Dep| dep.dep_path().map(|it| path_text(it.syntax())).unwrap_or_default();
+    let (a_path, b_path) = (path(a), path(b));
+
+    origin(a)
+        .cmp(&origin(b))
+        .then_with(|| a_path.to_lowercase().cmp(&b_path.to_lowercase()))
+        .then_with(|| a_path.cmp(&b_path))
+        .then_with(|| use_or_show(a).cmp(&use_or_show(b)))
+}
+
+fn path_text(node: &SyntaxNode) -> String {
+    node.descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| !it.kind().is_trivia())
+        .map(|it| it.text().to_owned())
+        .collect()
+}
+
+/// Returns `true` if `dep` shows rather than uses, so that `use` comes before
+/// `show` for the same path.
+fn use_or_show(dep: &ast::Dep) -> bool {
+    dep.use_or_show()
+        .and_then(|it| it.syntax().first_token())
+        .is_some_and(|it| it.kind() == T![show])
+}
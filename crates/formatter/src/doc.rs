@@ -0,0 +1,217 @@
+//! See docs for `Doc`.
+
+use crate::{INDENT_WIDTH, MAX_WIDTH};
+
+/// Layout of formatted code that has yet to be fit into [MAX_WIDTH] columns.
+///
+/// This is the "pretty printer" document of Wadler's _A prettier printer_:
+/// every [Doc::Group] is printed on a single line if it fits, and with all of
+/// its line breaks taken otherwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Doc {
+    /// Text printed as is, which only contains line breaks if it comes from a
+    /// token that spans several lines (e.g. a block comment).
+    Text(String),
+    /// A space, or a line break if the enclosing group does not fit.
+    Line,
+    /// Nothing, or a line break if the enclosing group does not fit.
+    SoftLine,
+    /// A line break, which keeps every enclosing group from fitting.
+    HardLine,
+    /// An empty line, which keeps every enclosing group from fitting.
+    BlankLine,
+    /// Text that is only printed if the enclosing group does not fit, e.g. a
+    /// trailing comma.
+    IfBreak(&'static str),
+    /// Docs whose line breaks are indented one level deeper.
+    Indent(Vec<Doc>),
+    /// Docs whose line breaks are all taken, or none of them are.
+    Group {
+        docs: Vec<Doc>,
+        /// Whether the group contains a line break that is always taken.
+        breaks: bool,
+    },
+}
+
+impl Doc {
+    pub(crate) fn text(text: impl Into<String>) -> Doc {
+        Doc::Text(text.into())
+    }
+
+    pub(crate) fn group(docs: Vec<Doc>) -> Doc {
+        let breaks = docs.iter().any(Doc::breaks);
+        Doc::Group { docs, breaks }
+    }
+
+    /// Returns a group that never fits on a single line.
+    pub(crate) fn broken_group(docs: Vec<Doc>) -> Doc {
+        Doc::Group { docs, breaks: true }
+    }
+
+    /// Returns `true` if this doc ends in a `//` comment, after which nothing
+    /// but a line break can follow.
+    pub(crate) fn ends_in_line_comment(&self) -> bool {
+        match self {
+            Doc::Text(text) => text.starts_with("//"),
+            Doc::Indent(docs) | Doc::Group { docs, .. } => {
+                docs.last().is_some_and(Doc::ends_in_line_comment)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if printing this doc always takes a line break.
+    fn breaks(&self) -> bool {
+        match self {
+            Doc::Text(text) => text.contains('\n'),
+            Doc::HardLine | Doc::BlankLine => true,
+            Doc::Line | Doc::SoftLine | Doc::IfBreak(_) => false,
+            Doc::Indent(docs) => docs.iter().any(Doc::breaks),
+            Doc::Group { breaks, .. } => *breaks,
+        }
+    }
+}
+
+/// Whether the line breaks of a group are taken.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+/// Prints `docs`, fitting them into [MAX_WIDTH] columns where possible.
+///
+/// Lines never end in whitespace.
+pub(crate) fn print(docs: &[Doc]) -> String {
+    let mut printer = Printer { out: String::new(), col: 0, pending_indent: None };
+    let mut cmds: Vec<(usize, Mode, &Doc)> =
+        docs.iter().rev().map(|doc| (0, Mode::Break, doc)).collect();
+
+    while let Some((indent, mode, doc)) = cmds.pop() {
+        match doc {
+            Doc::Text(text) => printer.text(text),
+            Doc::Line => match mode {
+                Mode::Flat => printer.text(" "),
+                Mode::Break => printer.newline(indent),
+            },
+            Doc::SoftLine => {
+                if mode == Mode::Break {
+                    printer.newline(indent);
+                }
+            }
+            Doc::HardLine => printer.newline(indent),
+            Doc::BlankLine => {
+                printer.newline(indent);
+                printer.newline(indent);
+            }
+            Doc::IfBreak(text) => {
+                if mode == Mode::Break {
+                    printer.text(text);
+                }
+            }
+            Doc::Indent(docs) => {
+                cmds.extend(docs.iter().rev().map(|doc| (indent + 1, mode, doc)));
+            }
+            Doc::Group { docs, breaks } => {
+                let mode = if mode == Mode::Flat
+                    || (!breaks && fits(MAX_WIDTH.saturating_sub(printer.col), docs, &cmds))
+                {
+                    Mode::Flat
+                } else {
+                    Mode::Break
+                };
+                cmds.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+            }
+        }
+    }
+
+    printer.out
+}
+
+/// Returns `true` if `docs` fit into `width` columns when printed flat,
+/// along with whatever `rest` prints before its next line break.
+fn fits(width: usize, docs: &[Doc], rest: &[(usize, Mode, &Doc)]) -> bool {
+    let mut width = width as isize;
+    let mut stack: Vec<(Mode, &Doc)> = docs.iter().rev().map(|doc| (Mode::Flat, doc)).collect();
+    let mut rest = rest.iter().rev();
+    let mut in_rest = false;
+
+    loop {
+        let (mode, doc) = match stack.pop() {
+            Some(it) => it,
+            None => match rest.next() {
+                Some((_, mode, doc)) => {
+                    in_rest = true;
+                    (*mode, *doc)
+                }
+                None => return true,
+            },
+        };
+
+        match doc {
+            Doc::Text(text) => {
+                if let Some((first_line, _)) = text.split_once('\n') {
+                    return in_rest && first_line.chars().count() as isize <= width;
+                }
+                width -= text.chars().count() as isize;
+            }
+            Doc::Line => match mode {
+                Mode::Flat => width -= 1,
+                Mode::Break => return true,
+            },
+            Doc::SoftLine => {
+                if mode == Mode::Break {
+                    return true;
+                }
+            }
+            Doc::HardLine | Doc::BlankLine => return true,
+            Doc::IfBreak(text) => {
+                if mode == Mode::Break {
+                    width -= text.len() as isize;
+                }
+            }
+            Doc::Indent(docs) => stack.extend(docs.iter().rev().map(|doc| (mode, doc))),
+            Doc::Group { docs, breaks } => {
+                let mode = if *breaks { Mode::Break } else { mode };
+                stack.extend(docs.iter().rev().map(|doc| (mode, doc)));
+            }
+        }
+
+        if width < 0 {
+            return false;
+        }
+    }
+}
+
+struct Printer {
+    out: String,
+    /// Column that the next character is printed at.
+    col: usize,
+    /// Indentation of the current line, which is only printed along with the
+    /// first text on it so that empty lines stay empty.
+    pending_indent: Option<usize>,
+}
+
+impl Printer {
+    fn text(&mut self, text: &str) {
+        if let Some(indent) = self.pending_indent.take() {
+            let spaces = indent * INDENT_WIDTH;
+            self.out.extend(std::iter::repeat_n(' ', spaces));
+            self.col = spaces;
+        }
+
+        self.out.push_str(text);
+        match text.rsplit_once('\n') {
+            Some((_, last_line)) => self.col = last_line.chars().count(),
+            None => self.col += text.chars().count(),
+        }
+    }
+
+    fn newline(&mut self, indent: usize) {
+        let trimmed_len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(trimmed_len);
+        self.out.push('\n');
+        self.col = 0;
+        self.pending_indent = Some(indent);
+    }
+}
@@ -0,0 +1,385 @@
+use std::{fs, path::Path};
+
+use stdx::{rand::Rng, DENIM_EXTENSION};
+use syntax::{ast, AstNode, SyntaxKind, SyntaxNode, T};
+
+use crate::format;
+
+/// Builds random, but well-formed, Denim programs out of tokens that are
+/// separated by random whitespace and comments.
+struct Generator {
+    rng: Rng,
+    tokens: Vec<String>,
+    comment_count: usize,
+}
+
+impl Generator {
+    fn new(seed: u64) -> Generator {
+        Generator { rng: Rng::new(seed), tokens: Vec::new(), comment_count: 0 }
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        self.rng.below(n)
+    }
+
+    fn pick<'a>(&mut self, options: &[&'a str]) -> &'a str {
+        self.rng.pick(options)
+    }
+
+    /// Pushes the space-separated `tokens`.
+    fn push(&mut self, tokens: &str) {
+        self.tokens.extend(tokens.split(' ').map(str::to_owned));
+    }
+
+    /// Pushes `text` as a single token, even if it contains spaces.
+    fn push_token(&mut self, text: &str) {
+        self.tokens.push(text.to_owned());
+    }
+
+    /// Pushes `0..max` of whatever `f` pushes, separated by commas.
+    fn comma_separated(&mut self, max: usize, mut f: impl FnMut(&mut Generator)) {
+        let count = self.below(max);
+        for i in 0..count {
+            if i > 0 {
+                self.push(",");
+            }
+            f(self);
+        }
+        if count > 0 && self.below(4) == 0 {
+            self.push(",");
+        }
+    }
+
+    fn name(&mut self) {
+        let name = self.pick(&[
+            "a",
+            "b",
+            "value",
+            "count",
+            "some_rather_long_name",
+            "another_quite_long_identifier",
+        ]);
+        self.push(name);
+    }
+
+    fn ty(&mut self) {
+        let ty = self.pick(&["int", "str", "bool", "Node<T>", "(int, str)", "[int]"]);
+        self.push(ty);
+    }
+
+    fn expr(&mut self, depth: usize) {
+        if depth == 0 {
+            match self.below(3) {
+                0 => self.name(),
+                1 => {
+                    let literal = self.pick(&["0", "42", "\"some text\"", "true"]);
+                    self.push_token(literal);
+                }
+                _ => self.push_token("\"hello ${ a } world\""),
+            }
+            return;
+        }
+
+        match self.below(10) {
+            0 => {
+                self.name();
+                self.push("(");
+                self.comma_separated(5, |g| g.arg(depth - 1));
+                self.push(")");
+            }
+            1 => {
+                self.expr(depth - 1);
+                self.push(".");
+                self.name();
+                self.push("(");
+                self.comma_separated(4, |g| g.arg(depth - 1));
+                self.push(")");
+            }
+            2 => {
+                // Blocks and `if`s would end a match arm before the operator.
+                self.expr(0);
+                let op = self.pick(&["+", "*", "==", "and"]);
+                self.push(op);
+                self.expr(depth - 1);
+            }
+            3 => {
+                self.push("(");
+                self.expr(depth - 1);
+                self.push(")");
+            }
+            4 => {
+                self.push("[");
+                self.comma_separated(5, |g| g.expr(depth - 1));
+                self.push("]");
+            }
+            5 => {
+                // Blocks and closures would swallow the block of the `if`.
+                self.push("if");
+                self.expr(0);
+                self.block(depth - 1);
+                if self.below(2) == 0 {
+                    self.push("else");
+                    self.block(depth - 1);
+                }
+            }
+            6 => {
+                self.push("|");
+                self.comma_separated(3, Generator::name);
+                self.push("|");
+                self.expr(depth - 1);
+            }
+            7 => {
+                self.expr(depth - 1);
+                self.push(". match {");
+                self.comma_separated(4, |g| {
+                    let pat = g.pick(&["Some(y)", "None", "_", "0"]);
+                    g.push(pat);
+                    g.push("=>");
+                    g.expr(depth - 1);
+                });
+                self.push("}");
+            }
+            8 => {
+                self.push("Rec {");
+                self.comma_separated(4, |g| {
+                    g.name();
+                    g.push(":");
+                    g.expr(depth - 1);
+                });
+                self.push("}");
+            }
+            _ => self.block(depth - 1),
+        }
+    }
+
+    /// Pushes a call argument, which has to be labeled unless it is a name,
+    /// since calls can have more than one argument.
+    fn arg(&mut self, depth: usize) {
+        self.name();
+        if self.below(3) > 0 {
+            self.push(":");
+            self.expr(depth);
+        }
+    }
+
+    fn block(&mut self, depth: usize) {
+        self.push("{");
+        for _ in 0..self.below(3) {
+            match self.below(3) {
+                0 => {
+                    self.push("let");
+                    self.name();
+                    self.push("=");
+                    self.expr(depth);
+                    self.push(";");
+                }
+                1 => {
+                    self.push("return");
+                    self.expr(depth);
+                    self.push(";");
+                }
+                _ => {
+                    self.stmt_expr(depth);
+                    self.push(";");
+                }
+            }
+        }
+        if self.below(2) == 0 {
+            self.stmt_expr(depth);
+        }
+        self.push("}");
+    }
+
+    /// Pushes an expression that can start a statement, unlike e.g. `{} + 1`,
+    /// which would end the statement right after the block.
+    fn stmt_expr(&mut self, depth: usize) {
+        self.push("print (");
+        self.expr(depth);
+        self.push(")");
+    }
+
+    fn item(&mut self) {
+        match self.below(4) {
+            0 => {
+                self.push("enum E {");
+                self.comma_separated(5, |g| {
+                    let variant = g.pick(&["A", "B(int)", "C(str, [int])"]);
+                    g.push(variant);
+                });
+                self.push("}");
+            }
+            1 => {
+                self.push("type T = {");
+                self.comma_separated(5, |g| {
+                    g.name();
+                    g.push(":");
+                    g.ty();
+                });
+                self.push("} ;");
+            }
+            _ => {
+                if self.below(2) == 0 {
+                    self.push("pub");
+                }
+                self.push("fn");
+                self.name();
+                self.push("(");
+                self.comma_separated(4, |g| {
+                    g.name();
+                    g.push(":");
+                    g.ty();
+                });
+                self.push(")");
+                if self.below(2) == 0 {
+                    self.push("->");
+                    self.ty();
+                }
+                self.block(3);
+            }
+        }
+    }
+
+    fn dep(&mut self) {
+        self.push("from");
+        let path = self.pick(&[
+            "std/fmt",
+            "Std/io",
+            "cool_lib",
+            "~/util",
+            "~/app/models",
+            "./sibling",
+            "../shared/theme",
+        ]);
+        self.push(path);
+        let keyword = self.pick(&["use", "show"]);
+        self.push(keyword);
+        let count = 1 + self.below(8);
+        for i in 0..count {
+            if i > 0 {
+                self.push(",");
+            }
+            let use_tree = self.pick(&["Thing", "other_thing", "a::{b, c}", "* as all"]);
+            self.push(use_tree);
+        }
+        self.push(";");
+    }
+
+    fn program(&mut self) -> String {
+        for _ in 0..self.below(4) {
+            self.item();
+        }
+        if self.below(2) == 0 {
+            self.push("---");
+            for _ in 0..self.below(6) {
+                self.dep();
+            }
+        }
+
+        let tokens = std::mem::take(&mut self.tokens);
+        let mut text = String::new();
+        for token in &tokens {
+            text.push_str(token);
+            self.separator(&mut text);
+        }
+        text
+    }
+
+    /// Pushes whitespace, and every so often a comment, onto `text`.
+    fn separator(&mut self, text: &mut String) {
+        match self.below(40) {
+            0..=2 => text.push('\n'),
+            3 => text.push_str("\n\n  "),
+            4 => text.push_str("    "),
+            5 => {
+                self.comment_count += 1;
+                text.push_str(&format!(" // comment {}\n", self.comment_count));
+            }
+            6 => {
+                self.comment_count += 1;
+                text.push_str(&format!(" /* comment {} */ ", self.comment_count));
+            }
+            _ => text.push(' '),
+        }
+    }
+}
+
+/// Returns the kind and text of every token in `node` that `include`s kind,
+/// so that tokens that lex differently after formatting stand out.
+fn tokens(node: &SyntaxNode, include: impl Fn(SyntaxKind) -> bool) -> Vec<String> {
+    node.descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| include(it.kind()))
+        .map(|it| format!("{:?} {}", it.kind(), it.text()))
+        .collect()
+}
+
+/// Returns the non-trivia tokens in `node` other than commas, which the
+/// formatter is free to add or drop at the end of lists.
+fn significant_tokens(node: &SyntaxNode) -> Vec<String> {
+    tokens(node, |kind| !kind.is_trivia() && kind != T![,])
+}
+
+fn comments(node: &SyntaxNode) -> Vec<String> {
+    let mut comments = tokens(node, |kind| kind == SyntaxKind::COMMENT);
+    comments.sort();
+    comments
+}
+
+fn assert_formats_idempotently(text: &str) {
+    let file = ast::SourceFile::parse(text);
+    assert!(file.errors().is_empty(), "{text:?} should parse, but: {:?}", file.errors());
+    let file = file.tree();
+
+    let formatted = format(&file);
+    let reparsed = ast::SourceFile::parse(&formatted);
+    assert!(
+        reparsed.errors().is_empty(),
+        "formatting {text:?} produced {formatted:?}, which does not parse: {:?}",
+        reparsed.errors()
+    );
+    let reparsed = reparsed.tree();
+    let formatting = format!("formatting {text:?} into {formatted:?}");
+
+    // Dependencies get sorted, so only their code sections line up token by
+    // token.
+    let code = |file: &ast::SourceFile| file.code().map(|it| significant_tokens(it.syntax()));
+    assert_eq!(code(&file), code(&reparsed), "{formatting} changed its code");
+    let sorted = |file: &ast::SourceFile| {
+        let mut tokens = significant_tokens(file.syntax());
+        tokens.sort();
+        tokens
+    };
+    assert_eq!(sorted(&file), sorted(&reparsed), "{formatting} changed its tokens");
+    assert_eq!(
+        comments(file.syntax()),
+        comments(reparsed.syntax()),
+        "{formatting} changed its comments"
+    );
+
+    assert_eq!(format(&reparsed), formatted, "{formatting} is not idempotent");
+}
+
+#[test]
+fn parser_fixtures_format_idempotently() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../syntax/test_data/parser/inline/ok");
+    let fixtures = fs::read_dir(dir)
+        .unwrap()
+        .map(|it| it.unwrap().path())
+        .filter(|it| it.extension().is_some_and(|it| it == DENIM_EXTENSION));
+    for fixture in fixtures {
+        // Some fixtures only make sense to the parser, which does not know
+        // about all of Denim's rules.
+        let text = fs::read_to_string(&fixture).unwrap();
+        if ast::SourceFile::parse(&text).errors().is_empty() {
+            assert_formats_idempotently(&text);
+        }
+    }
+}
+
+#[test]
+fn random_programs_format_idempotently() {
+    for seed in 0..2_000 {
+        let text = Generator::new(seed).program();
+        assert_formats_idempotently(&text);
+    }
+}
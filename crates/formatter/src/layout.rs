@@ -0,0 +1,608 @@
+//! Lays out syntax trees as [Doc]s.
+//!
+//! Layout only ever changes the whitespace in between tokens, and adds (or
+//! drops) the trailing commas of lists that span several lines. Every other
+//! token, comments included, makes it into the [Doc] as is.
+
+use syntax::{
+    ast, AstNode, NodeOrToken, SyntaxElement,
+    SyntaxKind::{self, *},
+    SyntaxNode, SyntaxToken, T,
+};
+
+use crate::{deps, doc::Doc, spacing::space_between};
+
+/// Lays out a whole source file.
+pub(crate) fn source_file(file: &ast::SourceFile) -> Vec<Doc> {
+    let mut code = Vec::new();
+    let mut meta = Vec::new();
+    let mut secsep = None;
+    for child in file.syntax().children_with_tokens() {
+        let elements = if secsep.is_none() { &mut code } else { &mut meta };
+        match child {
+            NodeOrToken::Node(node) if matches!(node.kind(), CODE_SECTION | META_SECTION) => {
+                elements.extend(node.children_with_tokens())
+            }
+            NodeOrToken::Token(token) if token.kind() == T![---] => secsep = Some(token),
+            _ => elements.push(child),
+        }
+    }
+
+    let mut docs = lines(&entries(code));
+    if let Some(secsep) = secsep {
+        if !docs.is_empty() {
+            let blank_line_before = secsep
+                .prev_token()
+                .is_some_and(|it| it.kind() == WHITESPACE && newline_count(&it.into()) > 1);
+            docs.push(if blank_line_before { Doc::BlankLine } else { Doc::HardLine });
+        }
+        docs.push(Doc::text(secsep.text()));
+
+        let meta = deps::meta_section(meta);
+        if !meta.is_empty() {
+            docs.push(Doc::HardLine);
+            docs.extend(meta);
+        }
+    }
+    if !docs.is_empty() {
+        docs.push(Doc::HardLine);
+    }
+    docs
+}
+
+/// Something that gets a line of its own in a block: a statement, an item, a
+/// match arm or a comment.
+pub(crate) struct Entry {
+    pub(crate) element: SyntaxElement,
+    /// Whether an empty line separates this entry from the one before it.
+    pub(crate) blank_line_before: bool,
+    /// Empty statements (i.e. stray `;`s) that follow this entry.
+    semicolons: usize,
+    /// Comments that follow this entry on the same line.
+    trailing_comments: Vec<SyntaxToken>,
+    /// Number of children that this entry starts with that are comments the
+    /// parser attached to it, but that belong to the previous line instead.
+    skipped_children: usize,
+}
+
+/// Splits the contents of a block into [Entry]s.
+pub(crate) fn entries(elements: impl IntoIterator<Item = SyntaxElement>) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut newlines = 0;
+    for element in elements {
+        match element.kind() {
+            WHITESPACE => {
+                newlines += newline_count(&element);
+                continue;
+            }
+            COMMENT if newlines == 0 => match entries.last_mut() {
+                Some(last) if last.element.kind() != COMMENT => {
+                    last.trailing_comments.extend(element.into_token());
+                }
+                _ => entries.push(Entry::new(element, false)),
+            },
+            T![;] => match entries.last_mut() {
+                Some(last) if last.trailing_comments.is_empty() => last.semicolons += 1,
+                _ => entries.push(Entry::new(element, newlines > 1)),
+            },
+            _ => {
+                let mut entry = Entry::new(element, newlines > 1);
+                match entries.last_mut() {
+                    Some(last) if newlines == 0 && last.element.kind() != COMMENT => {
+                        let (comments, skipped_children) = same_line_comments(&entry.element);
+                        last.trailing_comments.extend(comments);
+                        entry.skipped_children = skipped_children;
+                    }
+                    _ => {}
+                }
+                entries.push(entry);
+            }
+        }
+        newlines = 0;
+    }
+    entries
+}
+
+impl Entry {
+    fn new(element: SyntaxElement, blank_line_before: bool) -> Entry {
+        Entry {
+            element,
+            blank_line_before,
+            semicolons: 0,
+            trailing_comments: Vec::new(),
+            skipped_children: 0,
+        }
+    }
+
+    pub(crate) fn doc(&self) -> Vec<Doc> {
+        let mut docs = match &self.element {
+            NodeOrToken::Node(node) if self.skipped_children > 0 => {
+                inline(node.children_with_tokens().skip(self.skipped_children))
+            }
+            _ => element(&self.element),
+        };
+        if self.semicolons > 0 {
+            docs.push(Doc::text(";".repeat(self.semicolons)));
+        }
+        push_trailing_comments(&mut docs, &self.trailing_comments);
+        docs
+    }
+}
+
+/// Lays out `entries` one per line, keeping the empty lines in between them.
+fn lines(entries: &[Entry]) -> Vec<Doc> {
+    let mut docs = Vec::new();
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            docs.push(if entry.blank_line_before { Doc::BlankLine } else { Doc::HardLine });
+        }
+        docs.extend(entry.doc());
+    }
+    docs
+}
+
+/// Lays out a single node or token.
+fn element(element: &SyntaxElement) -> Vec<Doc> {
+    match element {
+        NodeOrToken::Node(node) => self::node(node),
+        NodeOrToken::Token(token) if token.kind() == COMMENT => vec![comment_doc(token)],
+        NodeOrToken::Token(token) => vec![Doc::text(token.text().trim_end())],
+    }
+}
+
+fn node(node: &SyntaxNode) -> Vec<Doc> {
+    let style = ListStyle::default();
+    let curly = ListStyle { padding: Doc::Line, ..style };
+    match node.kind() {
+        STMT_LIST | ASSOC_ITEM_LIST | MATCH_ARM_LIST => block(node),
+        VARIANT_LIST => {
+            delimited(node, T!['{'], T!['}'], ListStyle { always_breaks: true, ..curly })
+        }
+        RECORD_FIELD_LIST | RECORD_EXPR_FIELD_LIST | RECORD_TYPE | RECORD_PAT => {
+            delimited(node, T!['{'], T!['}'], curly)
+        }
+        USE_TREE_EXPANSION => delimited(node, T!['{'], T!['}'], style),
+        PARAM_LIST if node.first_token().is_some_and(|it| it.kind() == T![|]) => {
+            inline(node.children_with_tokens())
+        }
+        ARG_LIST | METHOD_CALL_ARG_LIST | PARAM_LIST | TUPLE_FIELD_LIST => {
+            delimited(node, T!['('], T![')'], style)
+        }
+        TUPLE_EXPR | TUPLE_TYPE | TUPLE_PAT => {
+            delimited(node, T!['('], T![')'], ListStyle { is_tuple: true, ..style })
+        }
+        LIST_EXPR | MAP_EXPR | SET_EXPR | SLICE_PAT => delimited(node, T!['['], T![']'], style),
+        MATCH_ARM => match_arm(node),
+        USE => deps::use_(node),
+        EXTERNAL_DEP_PATH | LOCAL_DEP_PATH | REPO_DEP_PATH => deps::dep_path(node),
+        FORMAT_STRING | ERROR => vec![Doc::text(node.to_string())],
+        _ => inline(node.children_with_tokens()),
+    }
+}
+
+/// Lays out `elements` on a single line, unless comments get in the way.
+pub(crate) fn inline(elements: impl IntoIterator<Item = SyntaxElement>) -> Vec<Doc> {
+    enum Prev {
+        Token(SyntaxToken),
+        LineComment,
+        BlockComment,
+        /// An attribute of an item, which goes on a line of its own.
+        ItemAttr,
+    }
+
+    let mut docs = Vec::new();
+    let mut prev = None;
+    let mut newlines = 0;
+    for element in elements {
+        if element.kind() == WHITESPACE {
+            newlines += newline_count(&element);
+            continue;
+        }
+
+        let is_comment = element.kind() == COMMENT;
+        let separator = match &prev {
+            None => None,
+            Some(Prev::LineComment) if newlines > 1 => Some(Doc::BlankLine),
+            Some(Prev::LineComment | Prev::ItemAttr) => Some(Doc::HardLine),
+            Some(Prev::BlockComment) if newlines > 0 => Some(Doc::HardLine),
+            Some(Prev::BlockComment) => Some(Doc::text(" ")),
+            Some(Prev::Token(_)) if is_comment && newlines > 1 => Some(Doc::BlankLine),
+            Some(Prev::Token(_)) if is_comment && newlines > 0 => Some(Doc::HardLine),
+            Some(Prev::Token(_)) if is_comment => Some(Doc::text(" ")),
+            Some(Prev::Token(prev)) => first_token(&element)
+                .filter(|first| is_dep_path(element.kind()) || space_between(prev, first))
+                .map(|_| Doc::text(" ")),
+        };
+        docs.extend(separator);
+        docs.extend(self::element(&element));
+
+        prev = if is_comment {
+            let is_line_comment = element.as_token().is_some_and(|it| it.text().starts_with("//"));
+            Some(if is_line_comment { Prev::LineComment } else { Prev::BlockComment })
+        } else if element.kind() == ATTR && element.parent().is_some_and(|it| is_item(it.kind())) {
+            Some(Prev::ItemAttr)
+        } else {
+            last_token(&element).map(Prev::Token).or(prev)
+        };
+        newlines = 0;
+    }
+
+    if let Some(Prev::LineComment) = prev {
+        docs.push(Doc::HardLine);
+    }
+    docs
+}
+
+/// Lays out a `{}`-delimited block with one entry per line, or on a single
+/// line if it is made of nothing but an expression.
+fn block(node: &SyntaxNode) -> Vec<Doc> {
+    let children: Vec<_> = node.children_with_tokens().collect();
+    let (Some(T!['{']), Some(T!['}'])) =
+        (children.first().map(|it| it.kind()), children.last().map(|it| it.kind()))
+    else {
+        return inline(children);
+    };
+    let entries = entries(children[1..children.len() - 1].iter().cloned());
+
+    match entries.as_slice() {
+        [] => vec![Doc::text("{}")],
+        [entry] if is_flat_block(node, entry) => vec![Doc::group(vec![
+            Doc::text("{"),
+            Doc::Indent(vec![Doc::Line, Doc::group(entry.doc())]),
+            Doc::Line,
+            Doc::text("}"),
+        ])],
+        _ => vec![
+            Doc::text("{"),
+            Doc::Indent([vec![Doc::HardLine], lines(&entries)].concat()),
+            Doc::HardLine,
+            Doc::text("}"),
+        ],
+    }
+}
+
+/// Returns `true` if the block `node`, made up of `entry` alone, may be laid
+/// out on a single line (e.g. `if a { b } else { c }`).
+fn is_flat_block(node: &SyntaxNode, entry: &Entry) -> bool {
+    let is_fn_body = node.parent().and_then(|it| it.parent()).is_some_and(|it| it.kind() == FN);
+
+    node.kind() == STMT_LIST
+        && !is_fn_body
+        && entry.trailing_comments.is_empty()
+        && entry.semicolons == 0
+        && entry.element.as_node().is_some_and(|it| ast::Expr::can_cast(it.kind()))
+}
+
+/// Lays out a match arm, adding the comma that it needs to be on a line of its
+/// own if it is missing.
+fn match_arm(node: &SyntaxNode) -> Vec<Doc> {
+    let mut docs = inline(node.children_with_tokens());
+
+    let has_comma = node.children_with_tokens().any(|it| it.kind() == T![,]);
+    let has_block = node.children().last().is_some_and(|it| it.kind() == BLOCK_EXPR);
+    if !has_comma && !has_block {
+        docs.push(Doc::text(","));
+    }
+    docs
+}
+
+/// How to lay out a comma-separated list.
+#[derive(Clone)]
+pub(crate) struct ListStyle {
+    /// What separates the items from the delimiters when the list fits on a
+    /// single line.
+    pub(crate) padding: Doc,
+    /// Whether the padding also goes in between the last item and the closing
+    /// delimiter.
+    pub(crate) pads_close: bool,
+    /// Whether the list always spans several lines.
+    pub(crate) always_breaks: bool,
+    /// Whether the list gets a trailing comma when it spans several lines.
+    pub(crate) trailing_comma: bool,
+    /// Whether a single item still needs a trailing comma, like in `(a,)`.
+    pub(crate) is_tuple: bool,
+}
+
+impl Default for ListStyle {
+    fn default() -> ListStyle {
+        ListStyle {
+            padding: Doc::SoftLine,
+            pads_close: true,
+            always_breaks: false,
+            trailing_comma: true,
+            is_tuple: false,
+        }
+    }
+}
+
+/// Lays out a list that sits in between the `open` and `close` tokens of
+/// `node`, along with whatever comes before it in `node`.
+fn delimited(node: &SyntaxNode, open: SyntaxKind, close: SyntaxKind, style: ListStyle) -> Vec<Doc> {
+    let children: Vec<_> = node.children_with_tokens().collect();
+    let open_index = children.iter().position(|it| it.kind() == open);
+    let close_index = children.iter().rposition(|it| it.kind() == close);
+    let (Some(open_index), Some(close_index)) = (open_index, close_index) else {
+        return inline(children);
+    };
+    if close_index != children.len() - 1 || open_index > close_index {
+        return inline(children);
+    }
+
+    let prefix = &children[..open_index];
+    let mut docs = inline(prefix.iter().cloned());
+    let prev = prefix.iter().rev().find_map(last_token);
+    let (Some(open), Some(close)) =
+        (children[open_index].as_token(), children[close_index].as_token())
+    else {
+        return inline(children);
+    };
+    if prev.is_some_and(|prev| space_between(&prev, open)) {
+        docs.push(Doc::text(" "));
+    }
+
+    let items = flatten_use_tree_lists(&children[open_index + 1..close_index]);
+    docs.push(list(Doc::text(open.text()), &items, Doc::text(close.text()), style));
+    docs
+}
+
+/// An item of a comma-separated list, along with the comments around it.
+#[derive(Default)]
+struct ListItem {
+    /// Comments on the lines before the item.
+    leading_comments: Vec<SyntaxToken>,
+    elements: Vec<SyntaxElement>,
+    has_comma: bool,
+    /// Comments after the item on the same line.
+    trailing_comments: Vec<SyntaxToken>,
+    blank_line_before: bool,
+    /// See [Entry::skipped_children].
+    skipped_children: usize,
+}
+
+/// Lays out the comma-separated `elements` in between `open` and `close`,
+/// either all on one line, or one item per line if they do not fit.
+pub(crate) fn list(open: Doc, elements: &[SyntaxElement], close: Doc, style: ListStyle) -> Doc {
+    let mut items: Vec<ListItem> = Vec::new();
+    let mut item: Option<ListItem> = None;
+    let mut leading_comments = Vec::new();
+    let mut blank_line_before = false;
+    let mut newlines = 0;
+    for element in elements {
+        match element.kind() {
+            WHITESPACE => {
+                newlines += newline_count(element);
+                if let Some(item) = item.as_mut().filter(|it| !it.has_comma) {
+                    item.elements.push(element.clone());
+                }
+                continue;
+            }
+            COMMENT => match &mut item {
+                Some(it) if newlines == 0 => {
+                    it.trailing_comments.extend(element.clone().into_token())
+                }
+                Some(it) if !it.has_comma => {
+                    // The comments turned out to be in the middle of the item.
+                    it.elements.extend(it.trailing_comments.drain(..).map(Into::into));
+                    it.elements.push(element.clone());
+                }
+                _ => {
+                    items.extend(item.take());
+                    if leading_comments.is_empty() {
+                        blank_line_before = newlines > 1;
+                    }
+                    leading_comments.extend(element.clone().into_token());
+                }
+            },
+            T![,] => {
+                if let Some(item) = &mut item {
+                    item.has_comma = true;
+                }
+            }
+            _ => {
+                if item.as_ref().is_some_and(|it| it.has_comma) {
+                    items.extend(item.take());
+                }
+                match &mut item {
+                    Some(item) => {
+                        // The comments turned out to be in the middle of the item.
+                        item.elements.extend(item.trailing_comments.drain(..).map(Into::into));
+                        item.elements.push(element.clone());
+                    }
+                    None => {
+                        if leading_comments.is_empty() {
+                            blank_line_before = newlines > 1;
+                        }
+                        let mut skipped_children = 0;
+                        match items.last_mut() {
+                            Some(last) if newlines == 0 && leading_comments.is_empty() => {
+                                let comments;
+                                (comments, skipped_children) = same_line_comments(element);
+                                last.trailing_comments.extend(comments);
+                            }
+                            _ => {}
+                        }
+                        item = Some(ListItem {
+                            leading_comments: std::mem::take(&mut leading_comments),
+                            elements: vec![element.clone()],
+                            blank_line_before,
+                            skipped_children,
+                            ..ListItem::default()
+                        });
+                    }
+                }
+            }
+        }
+        newlines = 0;
+    }
+    items.extend(item);
+
+    if items.is_empty() && leading_comments.is_empty() {
+        return Doc::group(vec![open, close]);
+    }
+
+    // Unless the close is padded, the comments after the last item end up
+    // after the close, and so the list can still fit on a single line.
+    let closes_early = !style.pads_close && !items.is_empty();
+    let has_comments = (!leading_comments.is_empty() && !closes_early)
+        || items.iter().enumerate().any(|(i, it)| {
+            let is_after_close = closes_early && i == items.len() - 1;
+            !it.leading_comments.is_empty() || (!it.trailing_comments.is_empty() && !is_after_close)
+        });
+    let mut body = vec![style.padding.clone()];
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            let keeps_blank_line = style.always_breaks && item.blank_line_before;
+            body.push(if keeps_blank_line { Doc::BlankLine } else { Doc::Line });
+        }
+        for comment in &item.leading_comments {
+            body.push(comment_doc(comment));
+            body.push(Doc::HardLine);
+        }
+        match &item.elements[0] {
+            NodeOrToken::Node(node) if item.skipped_children > 0 => {
+                body.extend(inline(node.children_with_tokens().skip(item.skipped_children)));
+                body.extend(inline(item.elements[1..].iter().cloned()));
+            }
+            _ => body.extend(inline(item.elements.iter().cloned())),
+        }
+
+        let is_last = i == items.len() - 1;
+        // Trailing commas are not allowed after spreads, e.g. `{ a, ..b }`.
+        let is_spread = first_token(&item.elements[0]).is_some_and(|it| it.kind() == T![..]);
+        if !is_last || (style.is_tuple && items.len() == 1 && item.has_comma) {
+            body.push(Doc::text(","));
+        } else if closes_early {
+            // Like a comma, the close goes before the comment after the item.
+            body.push(close.clone());
+        } else if style.trailing_comma && !is_spread {
+            body.push(Doc::IfBreak(","));
+        }
+        push_trailing_comments(&mut body, &item.trailing_comments);
+    }
+    let mut docs = vec![open];
+    if !closes_early {
+        for (i, comment) in leading_comments.iter().enumerate() {
+            if i > 0 || !items.is_empty() {
+                body.push(Doc::HardLine);
+            }
+            body.push(comment_doc(comment));
+        }
+        docs.push(Doc::Indent(body));
+        if style.pads_close {
+            docs.push(style.padding);
+        }
+        docs.push(close);
+    } else {
+        docs.push(Doc::Indent(body));
+    }
+    let list = if has_comments || style.always_breaks {
+        Doc::broken_group(docs)
+    } else {
+        Doc::group(docs)
+    };
+    if !closes_early || leading_comments.is_empty() {
+        return list;
+    }
+
+    let mut docs = vec![list];
+    for comment in &leading_comments {
+        docs.push(Doc::HardLine);
+        docs.push(comment_doc(comment));
+    }
+    Doc::group(docs)
+}
+
+fn comment_doc(comment: &SyntaxToken) -> Doc {
+    let text = comment.text();
+    Doc::text(if text.starts_with("//") { text.trim_end() } else { text })
+}
+
+/// Returns `true` for the kinds of nodes that attributes go on a line of their
+/// own in front of.
+fn is_item(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        ENUM | FN | IMPL | TRAIT | TYPE_ALIAS | DEP | VARIANT | RECORD_FIELD | RECORD_TYPE_FIELD
+    )
+}
+
+pub(crate) fn is_dep_path(kind: SyntaxKind) -> bool {
+    matches!(kind, EXTERNAL_DEP_PATH | LOCAL_DEP_PATH | REPO_DEP_PATH)
+}
+
+/// Replaces the `UseTreeList`s in `elements` with their children, so that
+/// their use trees can be laid out like any other list.
+pub(crate) fn flatten_use_tree_lists(elements: &[SyntaxElement]) -> Vec<SyntaxElement> {
+    elements
+        .iter()
+        .flat_map(|it| match it {
+            NodeOrToken::Node(node) if node.kind() == USE_TREE_LIST => {
+                node.children_with_tokens().collect()
+            }
+            _ => vec![it.clone()],
+        })
+        .collect()
+}
+
+/// Returns the comments that `element` starts with on its first line, if it
+/// is a node that the parser attached them to, along with the number of
+/// children that they (and the whitespace in between them) make up.
+fn same_line_comments(element: &SyntaxElement) -> (Vec<SyntaxToken>, usize) {
+    let mut comments = Vec::new();
+    let mut n_children = 0;
+    let Some(node) = element.as_node() else {
+        return (comments, n_children);
+    };
+    for (i, child) in node.children_with_tokens().enumerate() {
+        match child.kind() {
+            COMMENT => {
+                comments.extend(child.into_token());
+                n_children = i + 1;
+            }
+            WHITESPACE if newline_count(&child) == 0 => {}
+            _ => break,
+        }
+    }
+    (comments, n_children)
+}
+
+/// Adds `comments` to the end of the line that `docs` are on.
+fn push_trailing_comments(docs: &mut Vec<Doc>, comments: &[SyntaxToken]) {
+    for comment in comments {
+        let ends_in_line_comment = docs.last().is_some_and(Doc::ends_in_line_comment);
+        docs.push(if ends_in_line_comment { Doc::HardLine } else { Doc::text(" ") });
+        docs.push(comment_doc(comment));
+    }
+}
+
+fn newline_count(whitespace: &SyntaxElement) -> usize {
+    whitespace.as_token().map_or(0, |it| it.text().matches('\n').count())
+}
+
+/// Returns the first token of `element` that is not trivia.
+fn first_token(element: &SyntaxElement) -> Option<SyntaxToken> {
+    match element {
+        NodeOrToken::Node(node) => node
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|it| !it.kind().is_trivia()),
+        NodeOrToken::Token(token) => Some(token.clone()).filter(|it| !it.kind().is_trivia()),
+    }
+}
+
+/// Returns the last token of `element` that is not trivia.
+fn last_token(element: &SyntaxElement) -> Option<SyntaxToken> {
+    match element {
+        NodeOrToken::Node(node) => {
+            let mut token = node.last_token()?;
+            while token.kind().is_trivia() {
+                token = token.prev_token()?;
+                if !node.text_range().contains_range(token.text_range()) {
+                    return None;
+                }
+            }
+            Some(token)
+        }
+        NodeOrToken::Token(token) => Some(token.clone()).filter(|it| !it.kind().is_trivia()),
+    }
+}
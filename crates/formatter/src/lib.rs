@@ -0,0 +1,43 @@
+//! Formatter for Denim source files, which lays out their syntax trees in
+//! Denim's one true style.
+//!
+//! The formatter is deliberately not configurable: code is indented with 2
+//! spaces, kept within 100 columns where possible, and lists that do not fit
+//! on a line get one item per line and a trailing comma. Comments are kept
+//! where they are, and so are single empty lines in between statements and
+//! items.
+
+mod deps;
+mod doc;
+#[cfg(test)]
+mod idempotence_test;
+mod layout;
+mod spacing;
+#[cfg(test)]
+mod tests;
+
+use syntax::{ast, AstNode};
+
+/// Number of columns that formatted code is fit into where possible.
+const MAX_WIDTH: usize = 100;
+
+/// Number of spaces that every level of indentation is made of.
+const INDENT_WIDTH: usize = 2;
+
+const BYTE_ORDER_MARK: char = '\u{FEFF}';
+
+/// Formats `file`.
+///
+/// Files with syntax errors are formatted as well as their trees allow, but
+/// the parts that failed to parse are left as they are.
+pub fn format(file: &ast::SourceFile) -> String {
+    let docs = layout::source_file(file);
+    let formatted = doc::print(&docs);
+
+    // The byte order mark is whitespace as far as the layout is concerned, but
+    // it has to stay at the very start of the file.
+    match file.syntax().text().char_at(0.into()) {
+        Some(BYTE_ORDER_MARK) => format!("{BYTE_ORDER_MARK}{formatted}"),
+        _ => formatted,
+    }
+}
@@ -0,0 +1,108 @@
+//! Decides where spaces go in between the tokens of a line.
+
+use parser::TokenizedStr;
+use syntax::{SyntaxKind, SyntaxToken, T};
+
+/// Returns `true` if a space belongs in between the adjacent tokens `left` and
+/// `right` when they are laid out on the same line.
+pub(crate) fn space_between(left: &SyntaxToken, right: &SyntaxToken) -> bool {
+    use SyntaxKind::*;
+
+    let left_parent = parent_kind(left);
+    let right_parent = parent_kind(right);
+
+    let space = match (left.kind(), right.kind()) {
+        (_, T![,] | T![;] | T![')'] | T![']']) => false,
+        (T![,], T![|]) => false,
+        (T![,], _) => true,
+        // `t.0.1` is written as a single float on purpose, but `0. // comment`
+        // would turn `0` into a float.
+        (INT_NUMBER, T![.]) => return is_followed_by_comment(right),
+        (_, T![?] | T![.]) => false,
+        (_, T![::]) => !matches!(left.kind(), IDENT | SELF_KW | SELF_TYPE_KW | T![>]),
+        (T!['('] | T!['['] | T![.] | T![::] | T![#] | T![&] | T![~], _) => false,
+        (T!['{'], T!['}']) => false,
+
+        // `T!` and `#![attr]`.
+        (_, T![!]) => false,
+        (T![!], _) => left_parent != ATTR,
+
+        // `a: b`, except for the leading colon of sets (`[:a]`).
+        (_, T![:]) => false,
+        (T![:], _) => !matches!(left_parent, SET_ENTRY | SET_TYPE),
+
+        // Ranges (`a..b`), rest patterns (`..rest`) and spreads (`..a`).
+        (T![..] | T![..=], T!['}']) => true,
+        (T![..] | T![..=], _) => false,
+        (_, T![..] | T![..=]) => !matches!(right_parent, RANGE_EXPR | RANGE_PAT),
+
+        // Generics (`Foo<T>`), as opposed to comparisons (`a < b`).
+        (_, T![<]) if is_generic_list(right_parent) => false,
+        (T![<], _) if is_generic_list(left_parent) => false,
+        (_, T![>]) if is_generic_list(right_parent) => false,
+
+        // Unary minus (`-a`).
+        (T![-], _) => !matches!(left_parent, NEG_EXPR | LITERAL_PAT),
+
+        // Closure parameters (`|a, b|`).
+        (T![|], _) if left_parent == PARAM_LIST => !is_first_pipe(left),
+        (_, T![|]) if right_parent == PARAM_LIST => is_first_pipe(right),
+
+        // Calls (`f(a)`), indexing (`a[0]`) and the like, as opposed to
+        // parenthesized expressions and list literals.
+        (_, T!['(']) => !matches!(
+            right_parent,
+            ARG_LIST
+                | METHOD_CALL_ARG_LIST
+                | PARAM_LIST
+                | ALIASED_TUPLE_PAT
+                | TUPLE_FIELD_LIST
+                | CAST_EXPR
+                | TOKEN_TREE
+        ),
+        (_, T!['[']) => !matches!(right_parent, INDEX_EXPR | ATTR),
+
+        _ => true,
+    };
+
+    // E.g. `..` and `.method` would turn into `...`, and `..` and `=>` into
+    // `..=` and `>`.
+    space
+        || left.kind().is_punct()
+            && right.kind().is_punct()
+            && lexes_differently_together(left, right)
+}
+
+/// Returns `true` if `left` and `right` written right next to each other do
+/// not lex back into the same two tokens.
+fn lexes_differently_together(left: &SyntaxToken, right: &SyntaxToken) -> bool {
+    let text = format!("{}{}", left.text(), right.text());
+    let tokenized = TokenizedStr::new_fragment(&text);
+    tokenized.len() != 2 || tokenized.text(0) != left.text()
+}
+
+fn parent_kind(token: &SyntaxToken) -> SyntaxKind {
+    token.parent().map_or(SyntaxKind::ERROR, |it| it.kind())
+}
+
+fn is_generic_list(kind: SyntaxKind) -> bool {
+    matches!(kind, SyntaxKind::GENERIC_ARG_LIST | SyntaxKind::GENERIC_PARAM_LIST)
+}
+
+/// Returns `true` if `pipe` opens the parameter list of a closure.
+fn is_first_pipe(pipe: &SyntaxToken) -> bool {
+    let first_pipe =
+        pipe.parent().and_then(|it| it.children_with_tokens().find(|it| it.kind() == T![|]));
+    first_pipe.is_some_and(|it| it.as_token() == Some(pipe))
+}
+
+fn is_followed_by_comment(token: &SyntaxToken) -> bool {
+    let mut next = token.next_token();
+    while let Some(token) = next {
+        match token.kind() {
+            SyntaxKind::WHITESPACE => next = token.next_token(),
+            kind => return kind == SyntaxKind::COMMENT,
+        }
+    }
+    false
+}
@@ -0,0 +1,282 @@
+//! Tests that format snippets of Denim source and compare the result against
+//! the expected formatting.
+
+use expect_test::{expect, Expect};
+use syntax::ast;
+
+use crate::format;
+
+fn check(text: &str, expect: Expect) {
+    let file = ast::SourceFile::parse(text);
+    assert!(file.errors().is_empty(), "{text:?} should parse, but: {:?}", file.errors());
+
+    let formatted = format(&file.tree());
+    expect.assert_eq(&formatted);
+    assert_eq!(
+        format(&ast::SourceFile::parse(&formatted).tree()),
+        formatted,
+        "formatting is not idempotent"
+    );
+}
+
+#[test]
+fn empty_file() {
+    check("", expect![[r#""#]]);
+    check("\n\n  \n", expect![[r#""#]]);
+}
+
+#[test]
+fn normalizes_indentation_and_spacing() {
+    check(
+        r#"
+pub fn add(a:int,b : int)->int{
+        let sum=a+b ;
+    sum
+}
+fn main( ) {
+    if add( a:1,b : 2 )==3 { print( "three" ) } else { print("math is broken") }
+  let f = | x | x+1 ;
+}
+"#,
+        expect![[r#"
+            pub fn add(a: int, b: int) -> int {
+              let sum = a + b;
+              sum
+            }
+            fn main() {
+              if add(a: 1, b: 2) == 3 { print("three") } else { print("math is broken") }
+              let f = |x| x + 1;
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn keeps_single_blank_lines() {
+    check(
+        r#"
+fn a() {
+  let x = 1;
+
+
+
+  let y = 2;
+}
+
+
+fn b() {}
+"#,
+        expect![[r#"
+            fn a() {
+              let x = 1;
+
+              let y = 2;
+            }
+
+            fn b() {}
+        "#]],
+    );
+}
+
+#[test]
+fn preserves_comments() {
+    check(
+        r#"
+//! The module.
+
+/// Adds `a` and `b`.
+fn add(a: int, b: int) -> int {
+  // Add them up.
+  a + b   // the sum
+}
+
+enum Color {
+  Red, // warm
+  /* cool */ Blue,
+  // not a color
+}
+"#,
+        expect![[r#"
+            //! The module.
+
+            /// Adds `a` and `b`.
+            fn add(a: int, b: int) -> int {
+              // Add them up.
+              a + b // the sum
+            }
+
+            enum Color {
+              Red, // warm
+              /* cool */ Blue,
+              // not a color
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn wraps_long_argument_lists_with_trailing_commas() {
+    check(
+        r#"
+fn main() {
+  let result = some_long_function_name(first_argument, second_argument, third_argument, fourth_argument);
+  let short = f(a, b,);
+  nested(outer_argument_number_one, inner: inner(inner_argument_one, inner_argument_two), another_argument);
+}
+"#,
+        expect![[r#"
+            fn main() {
+              let result = some_long_function_name(
+                first_argument,
+                second_argument,
+                third_argument,
+                fourth_argument,
+              );
+              let short = f(a, b);
+              nested(
+                outer_argument_number_one,
+                inner: inner(inner_argument_one, inner_argument_two),
+                another_argument,
+              );
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn lays_out_lists_and_records() {
+    check(
+        r#"
+type Point = {x: int, y: int};
+
+fn main() {
+  let p = Point {x: 1, y: 2};
+  let list = [ 1, 2, 3 ];
+  let set = [ :1, :2 ];
+  let one = ( 1, );
+  x.match { Some(y) => y, _ => { 0 } }
+}
+
+enum Direction { Up, Down }
+"#,
+        expect![[r#"
+            type Point = { x: int, y: int };
+
+            fn main() {
+              let p = Point { x: 1, y: 2 };
+              let list = [1, 2, 3];
+              let set = [:1, :2];
+              let one = (1,);
+              x.match {
+                Some(y) => y,
+                _ => { 0 }
+              }
+            }
+
+            enum Direction {
+              Up,
+              Down,
+            }
+        "#]],
+    );
+}
+
+#[test]
+fn sorts_and_groups_deps() {
+    check(
+        r#"
+fn main() {}
+---
+from ./local use thing;
+from ~/util use helper;
+from zeta use Z;
+from ../shared show theme::{dark, light};
+from alpha use A;
+from ~/app/models use User;
+"#,
+        expect![[r#"
+            fn main() {}
+            ---
+            from alpha use A;
+            from zeta use Z;
+
+            from ~/app/models use User;
+            from ~/util use helper;
+
+            from ../shared show theme::{dark, light};
+            from ./local use thing;
+        "#]],
+    );
+}
+
+#[test]
+fn moves_comments_along_with_deps() {
+    check(
+        r#"
+---
+// Needed for the zeta things.
+from zeta use Z; // trailing
+from alpha use A;
+
+// Everything below is local.
+
+from ./b use b;
+from ./a use a;
+"#,
+        expect![[r#"
+            ---
+            from alpha use A;
+            // Needed for the zeta things.
+            from zeta use Z; // trailing
+
+            // Everything below is local.
+
+            from ./a use a;
+            from ./b use b;
+        "#]],
+    );
+}
+
+#[test]
+fn wraps_long_use_lists() {
+    check(
+        r#"
+---
+from std/collections use HashMap, HashSet, BTreeMap, BTreeSet, VecDeque, BinaryHeap, LinkedList, Vec;
+from std/fmt use {Display, Debug};
+"#,
+        expect![[r#"
+            ---
+            from std/collections use
+              HashMap,
+              HashSet,
+              BTreeMap,
+              BTreeSet,
+              VecDeque,
+              BinaryHeap,
+              LinkedList,
+              Vec;
+            from std/fmt use {Display, Debug};
+        "#]],
+    );
+}
+
+#[test]
+fn keeps_byte_order_mark() {
+    check("\u{FEFF}fn main( ) {}", expect!["\u{feff}fn main() {}\n"]);
+}
+
+#[test]
+fn keeps_apart_tokens_that_would_lex_together() {
+    check(
+        "fn f() { || .. .method(); x.match { 200.. => () } }",
+        expect![[r#"
+            fn f() {
+              ||.. .method();
+              x.match {
+                200.. => (),
+              }
+            }
+        "#]],
+    );
+}
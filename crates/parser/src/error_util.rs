@@ -1,4 +1,6 @@
-use tokenizer::{EscapeError, Mode};
+use std::ops;
+
+use tokenizer::{EscapeError, IdentHygieneWarning, Mode};
 
 pub(crate) fn error_to_diagnostic_message(error: EscapeError, mode: Mode) -> &'static str {
     match error {
@@ -38,20 +40,30 @@ pub(crate) fn error_to_diagnostic_message(error: EscapeError, mode: Mode) -> &'s
     }
 }
 
-pub(crate) fn unescape_string_error_message(text: &str, mode: Mode) -> &'static str {
-    let mut error_message = "";
+/// Returns the range and the error of the first reported escape error in
+/// `text`, the contents of a string literal in `mode`.
+pub(crate) fn first_string_escape_error(
+    text: &str,
+    mode: Mode,
+) -> Option<(ops::Range<usize>, EscapeError)> {
+    let mut first_error = None;
+    let mut on_error = |range: ops::Range<usize>, error: EscapeError| {
+        if first_error.is_none() && !error_to_diagnostic_message(error, mode).is_empty() {
+            first_error = Some((range, error));
+        }
+    };
     match mode {
         Mode::CStr => {
-            tokenizer::unescape::unescape_mixed(text, mode, &mut |_, res| {
+            tokenizer::unescape::unescape_mixed(text, mode, &mut |range, res| {
                 if let Err(e) = res {
-                    error_message = error_to_diagnostic_message(e, mode);
+                    on_error(range, e);
                 }
             });
         }
-        Mode::ByteStr | Mode::Str => {
-            tokenizer::unescape::unescape_unicode(text, mode, &mut |_, res| {
+        Mode::ByteStr | Mode::Str | Mode::RawByteStr | Mode::RawCStr => {
+            tokenizer::unescape::unescape_unicode(text, mode, &mut |range, res| {
                 if let Err(e) = res {
-                    error_message = error_to_diagnostic_message(e, mode);
+                    on_error(range, e);
                 }
             });
         }
@@ -60,5 +72,19 @@ pub(crate) fn unescape_string_error_message(text: &str, mode: Mode) -> &'static
         }
     }
 
-    error_message
+    first_error
+}
+
+pub(crate) fn ident_hygiene_warning_message(warning: &IdentHygieneWarning) -> String {
+    match warning {
+        IdentHygieneWarning::Confusable { c, lookalike, .. } => {
+            format!(
+                "identifier contains `{c}` (U+{:04X}), which is easily confused with `{lookalike}`",
+                *c as u32
+            )
+        }
+        IdentHygieneWarning::MixedScript { scripts } => {
+            format!("identifier mixes characters from different scripts ({})", scripts.join(", "))
+        }
+    }
 }
@@ -18,30 +18,25 @@
 //! // fn foo() {}
 //! ```
 //!
-//! After adding a new inline-test, run `cargo test -p xtask` to
+//! After adding a new inline-test, run `cargo xtask gen parser-tests` to
 //! extract it as a standalone text-fixture into
-//! `crates/syntax/test_data/parser/`, and run `cargo test` once to
-//! create the "gold" value.
+//! `crates/syntax/test_data/parser/inline/`, and run
+//! `UPDATE_EXPECT=1 cargo test -p parser` once to create the "gold" `.rast`
+//! value.
 //!
 //! Coding convention: rules like `where_clause` always produce either a
 //! node or an error, rules like `opt_where_clause` may produce nothing.
 //! Non-opt rules typically start with `assert!(p.at(FIRST_TOKEN))`, the
 //! caller is responsible for branching on the first token.
 
-// mod attributes;
-// mod expressions;
-// mod generic_args;
-// mod generic_params;
-// mod items;
-// mod params;
-// mod paths;
-// mod patterns;
-// mod types;
-
 mod attributes;
 mod expressions;
+mod generic_args;
+mod generic_params;
 mod items;
+mod params;
 mod paths;
+mod patterns;
 mod types;
 
 use crate::{
@@ -58,64 +53,60 @@ pub(crate) mod entry {
 
         pub(crate) fn source_file(p: &mut Parser<'_>) {
             let m = p.start();
-            items::source_contents(p, false);
+            items::code_section(p);
+            if p.eat(T![---]) {
+                items::meta_section(p);
+            }
             m.complete(p, SOURCE_FILE);
         }
 
         pub(crate) fn pattern(p: &mut Parser<'_>) {
-            todo!()
-            // let m = p.start();
-            // patterns::pattern_top(p);
-            // if p.at(EOF) {
-            //     m.abandon(p);
-            //     return;
-            // }
-            // while !p.at(EOF) {
-            //     p.bump_any();
-            // }
-            // m.complete(p, ERROR);
+            let m = p.start();
+            let parsed = !p.at(EOF);
+            patterns::pattern_top(p);
+            rest_of_input(p, m, parsed);
         }
 
         pub(crate) fn type_(p: &mut Parser<'_>) {
-            todo!()
-            // let m = p.start();
-            // types::type_(p);
-            // if p.at(EOF) {
-            //     m.abandon(p);
-            //     return;
-            // }
-            // while !p.at(EOF) {
-            //     p.bump_any();
-            // }
-            // m.complete(p, ERROR);
+            let m = p.start();
+            let parsed = !p.at(EOF);
+            types::type_(p);
+            rest_of_input(p, m, parsed);
         }
 
         pub(crate) fn expr(p: &mut Parser<'_>) {
-            todo!()
-            // let m = p.start();
-            // expressions::expr(p);
-            // if p.at(EOF) {
-            //     m.abandon(p);
-            //     return;
-            // }
-            // while !p.at(EOF) {
-            //     p.bump_any();
-            // }
-            // m.complete(p, ERROR);
+            let m = p.start();
+            let parsed = expressions::expr(p).is_some();
+            rest_of_input(p, m, parsed);
         }
 
         pub(crate) fn meta_item(p: &mut Parser<'_>) {
-            todo!()
-            // let m = p.start();
-            // attributes::meta(p);
-            // if p.at(EOF) {
-            //     m.abandon(p);
-            //     return;
-            // }
-            // while !p.at(EOF) {
-            //     p.bump_any();
-            // }
-            // m.complete(p, ERROR);
+            let m = p.start();
+            let parsed = paths::is_use_path_start(p);
+            if parsed {
+                attributes::meta(p);
+            } else {
+                p.error("expected a meta item");
+            }
+            rest_of_input(p, m, parsed);
+        }
+
+        /// Makes sure that the tree has exactly one root: if the entry point
+        /// `parsed` a single node out of all of the input, `m` is not needed,
+        /// otherwise whatever is left over gets wrapped in an `ERROR` node along
+        /// with everything that was parsed.
+        fn rest_of_input(p: &mut Parser<'_>, m: Marker, parsed: bool) {
+            if parsed && p.at(EOF) {
+                m.abandon(p);
+                return;
+            }
+            if !p.at(EOF) {
+                p.error("expected end of input");
+            }
+            while !p.at(EOF) {
+                p.bump_any();
+            }
+            m.complete(p, ERROR);
         }
     }
 }
@@ -125,24 +116,22 @@ pub(crate) fn reparser(
     first_child: Option<SyntaxKind>,
     parent: Option<SyntaxKind>,
 ) -> Option<fn(&mut Parser<'_>)> {
-    todo!()
-    // let res = match node {
-    //     BLOCK_EXPR => expressions::block_expr,
-    //     RECORD_FIELD_LIST => items::record_field_list,
-    //     RECORD_EXPR_FIELD_LIST => items::record_expr_field_list,
-    //     VARIANT_LIST => items::variant_list,
-    //     MATCH_ARM_LIST => items::match_arm_list,
-    //     USE_TREE_LIST => items::use_tree_list,
-    //     EXTERN_ITEM_LIST => items::extern_item_list,
-    //     TOKEN_TREE if first_child? == T!['{'] => items::token_tree,
-    //     ASSOC_ITEM_LIST => match parent? {
-    //         IMPL | TRAIT => items::assoc_item_list,
-    //         _ => return None,
-    //     },
-    //     ITEM_LIST => items::item_list,
-    //     _ => return None,
-    // };
-    // Some(res)
+    let res = match node {
+        BLOCK_EXPR if first_child? == STMT_LIST => {
+            expressions::block_expr_or_record_expr_field_list
+        }
+        RECORD_EXPR_FIELD_LIST => expressions::block_expr_or_record_expr_field_list,
+        RECORD_FIELD_LIST => items::record_field_list,
+        VARIANT_LIST => items::variant_list,
+        MATCH_ARM_LIST => expressions::match_arm_list,
+        USE_TREE_EXPANSION => items::use_tree_expansion,
+        ASSOC_ITEM_LIST => match parent? {
+            IMPL | TRAIT => items::assoc_item_list,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(res)
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -163,7 +152,7 @@ impl BlockLike {
 
 const VISIBILITY_FIRST: TokenSet = TokenSet::new(&[T![pub]]);
 
-fn opt_visibility(p: &mut Parser<'_>, in_tuple_field: bool) -> bool {
+fn opt_visibility(p: &mut Parser<'_>) -> bool {
     if !p.at(T![pub]) {
         return false;
     }
@@ -190,8 +179,7 @@ fn opt_ret_type(p: &mut Parser<'_>) -> bool {
     if p.at(T![->]) {
         let m = p.start();
         p.bump(T![->]);
-        todo!();
-        // types::type_no_bounds(p);
+        types::type_(p);
         m.complete(p, RET_TYPE);
         true
     } else {
@@ -235,8 +223,7 @@ fn error_block(p: &mut Parser<'_>, message: &str) {
     let m = p.start();
     p.error(message);
     p.bump(T!['{']);
-    todo!();
-    // expressions::expr_block_contents(p);
+    expressions::expr_block_contents(p);
     p.eat(T!['}']);
     m.complete(p, ERROR);
 }
@@ -247,8 +234,7 @@ fn error_let_stmt(p: &mut Parser<'_>, message: &str) {
     assert!(p.at(T![let]));
     let m = p.start();
     p.error(message);
-    todo!();
-    // expressions::let_stmt(p, expressions::Semicolon::Optional);
+    expressions::let_stmt(p, expressions::Semicolon::Optional);
     m.complete(p, ERROR);
 }
 
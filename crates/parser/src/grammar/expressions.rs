@@ -1,12 +1,681 @@
+mod atom;
+
 use super::*;
 
+pub(crate) use atom::match_arm_list;
+use atom::{is_anonymous_record_start, record_expr_field_list};
+pub(super) use atom::{literal, LITERAL_FIRST};
+
 #[derive(PartialEq, Eq)]
 pub(super) enum Semicolon {
     Required,
     Optional,
-    Forbidden,
 }
 
+const EXPR_FIRST: TokenSet = LHS_FIRST;
+
 pub(super) fn expr(p: &mut Parser<'_>) -> Option<CompletedMarker> {
-    todo!()
+    let r = Restrictions { forbid_structs: false, prefer_stmt: false };
+    expr_bp(p, None, r, 1).map(|(m, _)| m)
+}
+
+pub(super) fn expr_stmt(
+    p: &mut Parser<'_>,
+    m: Option<Marker>,
+) -> Option<(CompletedMarker, BlockLike)> {
+    let r = Restrictions { forbid_structs: false, prefer_stmt: true };
+    expr_bp(p, m, r, 1)
+}
+
+fn expr_no_struct(p: &mut Parser<'_>) {
+    let r = Restrictions { forbid_structs: true, prefer_stmt: false };
+    expr_bp(p, None, r, 1);
+}
+
+// test block_expr
+// fn a() {}
+// fn b() { fn c() {} }
+// fn d() { ;; }
+pub(crate) fn block_expr(p: &mut Parser<'_>) {
+    if !p.at(T!['{']) {
+        p.error("expected a block");
+        return;
+    }
+    let m = p.start();
+    stmt_list(p);
+    m.complete(p, BLOCK_EXPR);
+}
+
+/// Reparses a braced node that was either a block or the field list of a
+/// record, deciding between the two the same way an expression would. Should an
+/// edit turn one into the other, the kind of the reparsed node changes, which
+/// tells the caller that reparsing just this node is not enough.
+pub(crate) fn block_expr_or_record_expr_field_list(p: &mut Parser<'_>) {
+    if is_anonymous_record_start(p) {
+        record_expr_field_list(p);
+    } else {
+        block_expr(p);
+    }
+}
+
+fn stmt_list(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    expr_block_contents(p);
+    p.expect(T!['}']);
+    m.complete(p, STMT_LIST)
+}
+
+pub(super) fn expr_block_contents(p: &mut Parser<'_>) {
+    attributes::inner_attrs(p);
+
+    // test_err unclosed_block_before_meta_section
+    // fn foo() {
+    // ---
+    // from bar use baz;
+    while !p.at(EOF) && !p.at(T!['}']) && !p.at(T![---]) {
+        // test nocontentexpr
+        // fn foo() { ;;;some_expr();;;;{;;;};;;;Ok(()) }
+        stmt(p, Semicolon::Required);
+    }
+}
+
+pub(super) fn stmt(p: &mut Parser<'_>, semicolon: Semicolon) {
+    if p.eat(T![;]) {
+        return;
+    }
+
+    let m = p.start();
+    attributes::outer_attrs(p);
+
+    if p.at(T![let]) {
+        let_stmt(p, semicolon);
+        m.complete(p, LET_STMT);
+        return;
+    }
+
+    // test block_items
+    // fn a() { fn b() {} }
+    let m = match items::opt_item(p, m) {
+        Ok(()) => return,
+        Err(m) => m,
+    };
+
+    if !p.at_ts(EXPR_FIRST) {
+        p.err_and_bump("expected expression, item or let statement");
+        m.abandon(p);
+        return;
+    }
+
+    if let Some((cm, blocklike)) = expr_stmt(p, Some(m)) {
+        if !(p.at(T!['}']) || (semicolon != Semicolon::Required && p.at(EOF))) {
+            // test no_semi_after_block
+            // fn foo() {
+            //     if true {}
+            //     loop {}
+            //     match () {}
+            //     while true {}
+            //     for _ in () {}
+            //     {}
+            //     x.match {}
+            // }
+            let m = cm.precede(p);
+            match semicolon {
+                Semicolon::Required => {
+                    if blocklike.is_block() {
+                        p.eat(T![;]);
+                    } else {
+                        p.expect(T![;]);
+                    }
+                }
+                Semicolon::Optional => {
+                    p.eat(T![;]);
+                }
+            }
+            m.complete(p, EXPR_STMT);
+        }
+    }
+}
+
+// test let_stmt
+// fn f() { let x = 92; let y: i32 = x; }
+pub(super) fn let_stmt(p: &mut Parser<'_>, with_semi: Semicolon) {
+    p.bump(T![let]);
+    patterns::pattern(p);
+    if p.at(T![:]) {
+        types::ascription(p);
+    }
+
+    if p.eat(T![=]) {
+        expr(p);
+    } else {
+        p.error("expected `=`");
+    }
+
+    match with_semi {
+        Semicolon::Optional => {
+            p.eat(T![;]);
+        }
+        Semicolon::Required => {
+            p.expect(T![;]);
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Restrictions {
+    forbid_structs: bool,
+    prefer_stmt: bool,
+}
+
+enum Associativity {
+    Left,
+    Right,
+}
+
+/// Binding powers of operators for a Pratt parser.
+///
+/// See <https://matklad.github.io/2020/04/13/simple-but-powerful-pratt-parsing.html>
+///
+/// Denim has no `>`, `>=`, `%`, bitwise or compound assignment operators. They
+/// are parsed anyway so that later stages can point at them with a helpful
+/// message instead of the parser giving up on the rest of the expression.
+fn current_op(p: &Parser<'_>) -> (u8, &'static [SyntaxKind], Associativity) {
+    use Associativity::*;
+    const NOT_AN_OP: (u8, &[SyntaxKind], Associativity) = (0, &[], Left);
+    match p.current() {
+        T![=] => (1, &[T![=]], Right),
+        T![+] if p.at_composite2(0, T![+], T![=]) => (1, &[T![+], T![=]], Right),
+        T![-] if p.at_composite2(0, T![-], T![=]) => (1, &[T![-], T![=]], Right),
+        T![*] if p.at_composite2(0, T![*], T![=]) => (1, &[T![*], T![=]], Right),
+        T![/] if p.at_composite2(0, T![/], T![=]) => (1, &[T![/], T![=]], Right),
+        T![%] if p.at_composite2(0, T![%], T![=]) => (1, &[T![%], T![=]], Right),
+        T![|] if p.at_composite2(0, T![|], T![=]) => (1, &[T![|], T![=]], Right),
+        T![&] if p.at_composite2(0, T![&], T![=]) => (1, &[T![&], T![=]], Right),
+        T![<] if p.at_composite2(0, T![<], T![<=]) => (1, &[T![<], T![<=]], Right),
+        T![>] if p.at_composite3(0, T![>], T![>], T![=]) => (1, &[T![>], T![>], T![=]], Right),
+        T![..=] => (2, &[T![..=]], Left),
+        T![..] => (2, &[T![..]], Left),
+        T![or] => (3, &[T![or]], Left),
+        T![and] => (4, &[T![and]], Left),
+        T![==] => (5, &[T![==]], Left),
+        T![!=] => (5, &[T![!=]], Left),
+        T![===] => (5, &[T![===]], Left),
+        T![!==] => (5, &[T![!==]], Left),
+        T![<=] => (5, &[T![<=]], Left),
+        T![<] if p.at_composite2(0, T![<], T![<]) => (9, &[T![<], T![<]], Left),
+        T![<] => (5, &[T![<]], Left),
+        T![>] if p.at_composite2(0, T![>], T![=]) => (5, &[T![>], T![=]], Left),
+        T![>] if p.at_composite2(0, T![>], T![>]) => (9, &[T![>], T![>]], Left),
+        T![>] => (5, &[T![>]], Left),
+        T![|] => (6, &[T![|]], Left),
+        T![&] => (8, &[T![&]], Left),
+        T![+] => (10, &[T![+]], Left),
+        T![-] => (10, &[T![-]], Left),
+        T![*] => (11, &[T![*]], Left),
+        T![/] => (11, &[T![/]], Left),
+        T![%] => (11, &[T![%]], Left),
+        _ => NOT_AN_OP,
+    }
+}
+
+// Parses expression with binding power of at least bp.
+fn expr_bp(
+    p: &mut Parser<'_>,
+    m: Option<Marker>,
+    r: Restrictions,
+    bp: u8,
+) -> Option<(CompletedMarker, BlockLike)> {
+    let m = m.unwrap_or_else(|| {
+        let m = p.start();
+        attributes::outer_attrs(p);
+        m
+    });
+
+    if !p.at_ts(EXPR_FIRST) {
+        p.err_recover("expected expression", atom::EXPR_RECOVERY_SET);
+        m.abandon(p);
+        return None;
+    }
+
+    let (mut lhs, mut blocklike) = match lhs(p, r) {
+        Some((lhs, blocklike)) => {
+            let lhs = lhs.extend_to(p, m);
+            if r.prefer_stmt && blocklike.is_block() {
+                // test stmt_bin_expr_ambiguity
+                // fn f() {
+                //     let _ = {1} - 2;
+                //     {1} - 2;
+                // }
+                return Some((lhs, BlockLike::Block));
+            }
+            (lhs, blocklike)
+        }
+        None => {
+            m.abandon(p);
+            return None;
+        }
+    };
+
+    loop {
+        let is_range = p.at(T![..]) || p.at(T![..=]);
+        let (op_bp, ops, associativity) = current_op(p);
+        if op_bp < bp {
+            break;
+        }
+
+        // test binop_resets_statementness
+        // fn f() { v = {1}-2; }
+        blocklike = BlockLike::NotBlock;
+
+        let m = lhs.precede(p);
+        for &op in ops {
+            p.bump(op);
+        }
+
+        if is_range {
+            // test postfix_range
+            // fn foo() {
+            //     let x = 1..;
+            //     match 1.. { _ => () };
+            //     match a.b()..S { _ => () };
+            // }
+            let has_trailing_expression =
+                p.at_ts(EXPR_FIRST) && !(r.forbid_structs && p.at(T!['{']));
+            if !has_trailing_expression {
+                // no RHS
+                lhs = m.complete(p, RANGE_EXPR);
+                break;
+            }
+        }
+
+        let op_bp = match associativity {
+            Associativity::Left => op_bp + 1,
+            Associativity::Right => op_bp,
+        };
+        expr_bp(p, None, Restrictions { prefer_stmt: false, ..r }, op_bp);
+        lhs = m.complete(p, if is_range { RANGE_EXPR } else { BIN_EXPR });
+    }
+    Some((lhs, blocklike))
+}
+
+const LHS_FIRST: TokenSet =
+    atom::ATOM_EXPR_FIRST.union(TokenSet::new(&[T![-], T![not], T![..], T![..=]]));
+
+fn lhs(p: &mut Parser<'_>, r: Restrictions) -> Option<(CompletedMarker, BlockLike)> {
+    let m;
+    let (kind, bp) = match p.current() {
+        // test prefix_expr
+        // fn f() { -1; not true; -x.y; }
+        T![-] => {
+            m = p.start();
+            p.bump(T![-]);
+            (NEG_EXPR, 255)
+        }
+        // test not_binds_looser_than_comparison
+        // fn f() { not a == b and c; }
+        T![not] => {
+            m = p.start();
+            p.bump(T![not]);
+            (NOT_EXPR, 5)
+        }
+        _ => {
+            // test full_range_expr
+            // fn foo() { xs[..]; }
+            for op in [T![..=], T![..]] {
+                if p.at(op) {
+                    m = p.start();
+                    p.bump(op);
+
+                    // test closure_range_method_call
+                    // fn foo() {
+                    //     || .. .method();
+                    //     || .. .field;
+                    // }
+                    let has_access_after = p.at(T![.]) && p.nth_at(1, IDENT);
+                    let struct_forbidden = r.forbid_structs && p.at(T!['{']);
+                    if p.at_ts(EXPR_FIRST) && !has_access_after && !struct_forbidden {
+                        expr_bp(p, None, r, 2);
+                    }
+                    let cm = m.complete(p, RANGE_EXPR);
+                    return Some((cm, BlockLike::NotBlock));
+                }
+            }
+
+            // test expression_after_block
+            // fn foo() {
+            //    let a = {1}.b;
+            // }
+            let (lhs, blocklike) = atom::atom_expr(p, r)?;
+            let (cm, block_like) =
+                postfix_expr(p, lhs, blocklike, !(r.prefer_stmt && blocklike.is_block()));
+            return Some((cm, block_like));
+        }
+    };
+    expr_bp(p, None, r, bp);
+    let cm = m.complete(p, kind);
+    Some((cm, BlockLike::NotBlock))
+}
+
+fn postfix_expr(
+    p: &mut Parser<'_>,
+    mut lhs: CompletedMarker,
+    // Calls are disallowed if the type is a block and we prefer statements because the call cannot be disambiguated from a tuple
+    // E.g. `while true {break}();` is parsed as
+    // `while true {break}; ();`
+    mut block_like: BlockLike,
+    mut allow_calls: bool,
+) -> (CompletedMarker, BlockLike) {
+    loop {
+        lhs = match p.current() {
+            // test stmt_postfix_expr_ambiguity
+            // fn foo() {
+            //     match x {}
+            //     (1, 2);
+            //     if true {}
+            //     [1];
+            // }
+            T!['('] if allow_calls => call_expr(p, lhs),
+            T!['['] if allow_calls => index_expr(p, lhs),
+            T![.] => match postfix_dot_expr::<false>(p, lhs) {
+                Ok(it) => it,
+                Err(it) => {
+                    lhs = it;
+                    break;
+                }
+            },
+            _ => break,
+        };
+        allow_calls = true;
+        // A trailing `.match { ... }` ends in a block just like its prefix
+        // counterpart, so it does not need a `;` to become a statement.
+        block_like = if lhs.kind() == MATCH_EXPR { BlockLike::Block } else { BlockLike::NotBlock };
+    }
+    (lhs, block_like)
+}
+
+fn postfix_dot_expr<const FLOAT_RECOVERY: bool>(
+    p: &mut Parser<'_>,
+    lhs: CompletedMarker,
+) -> Result<CompletedMarker, CompletedMarker> {
+    if !FLOAT_RECOVERY {
+        assert!(p.at(T![.]));
+    }
+    let nth1 = if FLOAT_RECOVERY { p.nth(0) } else { p.nth(1) };
+    let nth2 = if FLOAT_RECOVERY { p.nth(1) } else { p.nth(2) };
+
+    if nth1 == IDENT && (nth2 == T!['('] || nth2 == T![::]) {
+        return Ok(method_call_expr::<FLOAT_RECOVERY>(p, lhs));
+    }
+
+    let kind = match nth1 {
+        // test try_expr
+        // fn foo() { x.try; x.y.try.z; }
+        T![try] => TRY_EXPR,
+        // test mut_expr
+        // fn foo() { x.mut; }
+        T![mut] => MUT_EXPR,
+        // test fn_ref_expr
+        // fn foo() { x.fn; }
+        T![fn] => FN_REF_EXPR,
+        // test suffix_not_expr
+        // fn foo() { x.is_empty().not; }
+        T![not] => NOT_EXPR,
+        // test async_expr
+        // fn foo() { fetch().async; }
+        T![async] => ASYNC_EXPR,
+        // test await_expr
+        // fn foo() { fetch().await; x.0.await; }
+        T![await] => AWAIT_EXPR,
+        T![as] => return Ok(cast_expr::<FLOAT_RECOVERY>(p, lhs)),
+        T![match] => return Ok(suffix_match_expr::<FLOAT_RECOVERY>(p, lhs)),
+        _ => return field_expr::<FLOAT_RECOVERY>(p, lhs),
+    };
+
+    let m = lhs.precede(p);
+    if !FLOAT_RECOVERY {
+        p.bump(T![.]);
+    }
+    p.bump_any();
+    Ok(m.complete(p, kind))
+}
+
+// test call_expr
+// fn foo() {
+//     let _ = f();
+//     let _ = f()(1)(1, 2,);
+//     f(g(x), y);
+// }
+fn call_expr(p: &mut Parser<'_>, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T!['(']));
+    let m = lhs.precede(p);
+    arg_list(p);
+    m.complete(p, CALL_EXPR)
+}
+
+// test index_expr
+// fn foo() {
+//     x[1][2];
+// }
+fn index_expr(p: &mut Parser<'_>, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(T!['[']));
+    let m = lhs.precede(p);
+    p.bump(T!['[']);
+    expr(p);
+    p.expect(T![']']);
+    m.complete(p, INDEX_EXPR)
+}
+
+// test method_call_expr
+// fn foo() {
+//     x.foo();
+//     y.bar::<T>(1, 2,);
+//     x.0.0.call();
+//     x.0. call();
+// }
+fn method_call_expr<const FLOAT_RECOVERY: bool>(
+    p: &mut Parser<'_>,
+    lhs: CompletedMarker,
+) -> CompletedMarker {
+    if FLOAT_RECOVERY {
+        assert!(p.at(IDENT) && (p.nth(1) == T![::] || p.nth(1) == T!['(']));
+    } else {
+        assert!(p.at(T![.]) && p.nth(1) == IDENT && (p.nth(2) == T![::] || p.nth(2) == T!['(']));
+    }
+    let m = lhs.precede(p);
+    if !FLOAT_RECOVERY {
+        p.bump(T![.]);
+    }
+    name_ref(p);
+    generic_args::opt_generic_arg_list(p, true);
+    if p.at(T!['(']) {
+        method_call_arg_list(p);
+    } else {
+        p.error("expected method call arguments");
+    }
+    m.complete(p, METHOD_CALL_EXPR)
+}
+
+// test field_expr
+// fn foo() {
+//     x.foo;
+//     x.0.bar;
+//     x.0.1;
+//     x.0. bar;
+//     x.0();
+// }
+
+// test_err field_expr_bad_float
+// fn foo() {
+//     g(x.0.);
+//     x.1e3;
+//     x.1e;
+// }
+fn field_expr<const FLOAT_RECOVERY: bool>(
+    p: &mut Parser<'_>,
+    lhs: CompletedMarker,
+) -> Result<CompletedMarker, CompletedMarker> {
+    if !FLOAT_RECOVERY {
+        assert!(p.at(T![.]));
+    }
+    let m = lhs.precede(p);
+    if !FLOAT_RECOVERY {
+        p.bump(T![.]);
+    }
+    if p.at(IDENT) || p.at(INT_NUMBER) {
+        name_ref_or_index(p);
+    } else if p.at_splittable_float() {
+        return match p.split_float(m) {
+            (true, m) => {
+                let lhs = m.complete(p, FIELD_EXPR);
+                postfix_dot_expr::<true>(p, lhs)
+            }
+            (false, m) => Ok(m.complete(p, FIELD_EXPR)),
+        };
+    } else if p.at(FLOAT_NUMBER) {
+        p.err_and_bump("expected field name");
+    } else {
+        p.error("expected field name, number or suffix keyword");
+    }
+    Ok(m.complete(p, FIELD_EXPR))
+}
+
+// test cast_expr
+// fn foo() {
+//     82.as(u32);
+//     x.y.as(Foo).z;
+// }
+fn cast_expr<const FLOAT_RECOVERY: bool>(
+    p: &mut Parser<'_>,
+    lhs: CompletedMarker,
+) -> CompletedMarker {
+    let m = lhs.precede(p);
+    if !FLOAT_RECOVERY {
+        p.bump(T![.]);
+    }
+    p.bump(T![as]);
+    if p.expect(T!['(']) {
+        types::type_(p);
+        p.expect(T![')']);
+    }
+    m.complete(p, CAST_EXPR)
+}
+
+// test suffix_match_expr
+// fn foo() {
+//     x.match { _ => () };
+//     a.b().match { x => x }.c();
+// }
+fn suffix_match_expr<const FLOAT_RECOVERY: bool>(
+    p: &mut Parser<'_>,
+    lhs: CompletedMarker,
+) -> CompletedMarker {
+    let m = lhs.precede(p);
+    if !FLOAT_RECOVERY {
+        p.bump(T![.]);
+    }
+    p.bump(T![match]);
+    if p.at(T!['{']) {
+        match_arm_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, MATCH_EXPR)
+}
+
+const ARG_FIRST: TokenSet = EXPR_FIRST.union(attributes::ATTRIBUTE_FIRST);
+
+// test arg_list
+// fn foo() {
+//     f(1, label: 2);
+//     f(a: x, b: y,);
+// }
+fn arg_list(p: &mut Parser<'_>) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    delimited(
+        p,
+        T!['('],
+        T![')'],
+        T![,],
+        || "expected argument".into(),
+        ARG_FIRST.union(TokenSet::new(&[T![&]])),
+        |p| {
+            if !p.at(T![&]) {
+                return arg(p);
+            }
+
+            // test_err call_selfified_arg
+            // fn foo() { f(&x); }
+            let m = p.start();
+            p.error("only method calls can have a selfified argument");
+            p.bump(T![&]);
+            name(p);
+            m.complete(p, ERROR);
+            true
+        },
+    );
+    m.complete(p, ARG_LIST);
+}
+
+// test method_call_arg_list
+// fn foo() {
+//     40.add(&other, 2);
+//     x.foo(label: 1, 2);
+// }
+
+// test_err method_call_selfified_arg_not_first
+// fn foo() {
+//     x.foo(a, &b);
+//     x.foo(&);
+// }
+fn method_call_arg_list(p: &mut Parser<'_>) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    let mut is_first = true;
+    delimited(
+        p,
+        T!['('],
+        T![')'],
+        T![,],
+        || "expected argument".into(),
+        ARG_FIRST.union(TokenSet::new(&[T![&]])),
+        |p| {
+            let is_first = std::mem::replace(&mut is_first, false);
+            if !is_first && !p.at(T![&]) {
+                return arg(p);
+            }
+
+            let m = p.start();
+            if p.at(T![&]) {
+                if !is_first {
+                    p.error("only the first argument of a method call can be selfified");
+                }
+                p.bump(T![&]);
+                name(p);
+            } else if !arg(p) {
+                m.abandon(p);
+                return false;
+            }
+            m.complete(p, MAYBE_SELFIFIED_ARG);
+            true
+        },
+    );
+    m.complete(p, METHOD_CALL_ARG_LIST);
+}
+
+fn arg(p: &mut Parser<'_>) -> bool {
+    let m = p.start();
+    let has_label = p.at(IDENT) && p.nth_at(1, T![:]);
+    if has_label {
+        name(p);
+        p.bump(T![:]);
+    }
+    if expr(p).is_none() && !has_label {
+        m.abandon(p);
+        return false;
+    }
+    m.complete(p, ARG);
+    true
 }
@@ -0,0 +1,712 @@
+use super::*;
+
+// test expr_literals
+// fn foo() {
+//     let _ = true;
+//     let _ = false;
+//     let _ = 1;
+//     let _ = 2.0;
+//     let _ = 'c';
+//     let _ = "s";
+//     let _ = r"r";
+//     let _ = b'b';
+//     let _ = b"bs";
+//     let _ = br"rbs";
+//     let _ = c"cs";
+//     let _ = cr"rcs";
+// }
+pub(crate) const LITERAL_FIRST: TokenSet = TokenSet::new(&[
+    T![true],
+    T![false],
+    INT_NUMBER,
+    FLOAT_NUMBER,
+    BYTE,
+    BYTE_STRING,
+    C_STRING,
+    CHAR,
+    STRING,
+    RAW_STRING,
+]);
+
+pub(crate) fn literal(p: &mut Parser<'_>) -> Option<CompletedMarker> {
+    if !p.at_ts(LITERAL_FIRST) {
+        return None;
+    }
+    let m = p.start();
+    if p.at(STRING) {
+        // Adjacent string literals are joined into one.
+        while p.at(STRING) {
+            p.bump(STRING);
+        }
+    } else {
+        p.bump_any();
+    }
+    Some(m.complete(p, LITERAL))
+}
+
+// test adjacent_string_literals
+// fn foo() {
+//     let _ = "123""xyz";
+//     let _ = "this is a string " "and here comes one more"
+//         """ - one more for the road""";
+//     let _ = "A ${self.model} owned "
+//         "by ${self.owner.name}" r" is driving";
+// }
+fn string_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(STRING) || p.at(STRING_START));
+    let m = p.start();
+    let mut is_format_string = false;
+    loop {
+        match p.current() {
+            STRING => p.bump(STRING),
+            STRING_START => {
+                is_format_string = true;
+                format_string_contents(p);
+            }
+            _ => break,
+        }
+    }
+    m.complete(p, if is_format_string { FORMAT_STRING } else { LITERAL })
+}
+
+// test format_string
+// fn foo() {
+//     let _ = "$greeting, $planet!";
+//     let _ = "${short_story(name)} and I am $age";
+//     let _ = "outer ${ "inner ${ {a: 1}.a }" } \$5";
+// }
+fn format_string_contents(p: &mut Parser<'_>) {
+    assert!(p.at(STRING_START));
+    p.bump(STRING_START);
+    loop {
+        match p.current() {
+            STRING_FRAGMENT => p.bump(STRING_FRAGMENT),
+            T![$] | INTERPOLATION_START => interpolation(p),
+            STRING_END => {
+                p.bump(STRING_END);
+                break;
+            }
+            // test_err unterminated_format_string
+            // fn foo() { "a ${b
+            _ => {
+                p.error("Missing trailing `\"` symbol to terminate the string literal");
+                break;
+            }
+        }
+    }
+}
+
+// test_err interpolation_recovery
+// fn foo() { "${} ${a b "${c}"} ${d +} $if"; }
+fn interpolation(p: &mut Parser<'_>) {
+    let m = p.start();
+    if p.eat(T![$]) {
+        if paths::is_path_start(p) {
+            let path = p.start();
+            paths::expr_path(p);
+            path.complete(p, PATH_EXPR);
+        } else {
+            p.err_and_bump("expected an identifier");
+        }
+        m.complete(p, INTERPOLATION);
+        return;
+    }
+
+    p.bump(INTERPOLATION_START);
+    if p.at(INTERPOLATION_END) {
+        p.error("expected expression");
+    } else {
+        expressions::expr(p);
+    }
+    if !p.at(INTERPOLATION_END) && !p.at(EOF) {
+        let e = p.start();
+        p.error("expected `}`");
+        // Skip over the rest of the interpolation, including any nested ones.
+        let mut depth = 0usize;
+        while !(p.at(EOF) || depth == 0 && p.at(INTERPOLATION_END)) {
+            match p.current() {
+                INTERPOLATION_START => depth += 1,
+                INTERPOLATION_END => depth -= 1,
+                _ => (),
+            }
+            p.bump_any();
+        }
+        e.complete(p, ERROR);
+    }
+    if !p.eat(INTERPOLATION_END) {
+        p.error("expected `}`");
+    }
+    m.complete(p, INTERPOLATION);
+}
+
+// E.g. for after the break in `if break {}`, this should not match
+pub(super) const ATOM_EXPR_FIRST: TokenSet =
+    LITERAL_FIRST.union(paths::PATH_FIRST).union(TokenSet::new(&[
+        STRING_START,
+        T!['('],
+        T!['{'],
+        T!['['],
+        T![|],
+        T![break],
+        T![continue],
+        T![for],
+        T![if],
+        T![let],
+        T![loop],
+        T![match],
+        T![return],
+        T![while],
+        T![_],
+    ]));
+
+pub(super) const EXPR_RECOVERY_SET: TokenSet =
+    TokenSet::new(&[T![')'], T![']'], T![,], T![;], T![let], INTERPOLATION_END]);
+
+pub(super) fn atom_expr(
+    p: &mut Parser<'_>,
+    r: Restrictions,
+) -> Option<(CompletedMarker, BlockLike)> {
+    if p.at(STRING) || p.at(STRING_START) {
+        return Some((string_expr(p), BlockLike::NotBlock));
+    }
+    if let Some(m) = literal(p) {
+        return Some((m, BlockLike::NotBlock));
+    }
+    if paths::is_path_start(p) {
+        return Some(path_expr(p, r));
+    }
+    let done = match p.current() {
+        T!['('] => tuple_expr(p),
+        T!['['] => collection_expr(p),
+        T![|] => closure_expr(p),
+        T![if] => if_expr(p),
+        T![let] => let_expr(p),
+        T![loop] => loop_expr(p),
+        T![while] => while_expr(p),
+        T![for] => for_expr(p),
+        T![match] => match_expr(p),
+        T![return] => return_expr(p),
+        T![break] => break_expr(p),
+        T![continue] => continue_expr(p),
+        T![_] => {
+            // test destructuring_assignment_wildcard_pat
+            // fn foo() {
+            //     _ = 1;
+            // }
+            let m = p.start();
+            p.bump(T![_]);
+            m.complete(p, UNDERSCORE_EXPR)
+        }
+        // test anonymous_record_expr
+        // fn foo() {
+        //     let a = { x: 1, y };
+        //     let b = { ..a, y: 2 };
+        //     let c = { y };
+        // }
+        T!['{'] if is_anonymous_record_start(p) => {
+            let m = p.start();
+            record_expr_field_list(p);
+            m.complete(p, RECORD_EXPR)
+        }
+        T!['{'] => {
+            let m = p.start();
+            stmt_list(p);
+            m.complete(p, BLOCK_EXPR)
+        }
+        _ => {
+            p.err_recover("expected expression", EXPR_RECOVERY_SET);
+            return None;
+        }
+    };
+    let blocklike =
+        if BlockLike::is_blocklike(done.kind()) { BlockLike::Block } else { BlockLike::NotBlock };
+    Some((done, blocklike))
+}
+
+/// A `{` only opens an anonymous record (rather than a block) if its contents
+/// could not possibly be a statement: a labeled field or a spread.
+pub(super) fn is_anonymous_record_start(p: &Parser<'_>) -> bool {
+    assert!(p.at(T!['{']));
+    (p.nth_at(1, IDENT) && p.nth_at(2, T![:])) || p.nth_at(1, T![..])
+}
+
+// test path_expr
+// fn foo() {
+//     let _ = a;
+//     let _ = a::b;
+//     let _ = ::a::<b>;
+// }
+fn path_expr(p: &mut Parser<'_>, r: Restrictions) -> (CompletedMarker, BlockLike) {
+    assert!(paths::is_path_start(p));
+    let m = p.start();
+    paths::expr_path(p);
+    match p.current() {
+        // test record_expr
+        // fn foo() {
+        //     S {};
+        //     S { x, y: 32, };
+        //     S { x, y: 32, ..Default::default() };
+        //     TupleStruct { 0: 1 };
+        // }
+        T!['{'] if !r.forbid_structs => {
+            record_expr_field_list(p);
+            (m.complete(p, RECORD_EXPR), BlockLike::NotBlock)
+        }
+        _ => (m.complete(p, PATH_EXPR), BlockLike::NotBlock),
+    }
+}
+
+pub(super) fn record_expr_field_list(p: &mut Parser<'_>) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        let m = p.start();
+        // test record_literal_field_with_attr
+        // fn main() {
+        //     S { #[cfg(test)] field: 1 }
+        // }
+        attributes::outer_attrs(p);
+
+        match p.current() {
+            IDENT | INT_NUMBER => {
+                if p.nth_at(1, T![:]) {
+                    name_ref_or_index(p);
+                    p.expect(T![:]);
+                }
+                expr(p);
+                m.complete(p, RECORD_EXPR_FIELD);
+            }
+            T![..] => {
+                m.abandon(p);
+                p.bump(T![..]);
+                if !p.at(T!['}']) {
+                    expr(p);
+                }
+            }
+            T!['{'] => {
+                error_block(p, "expected a field");
+                m.abandon(p);
+            }
+            _ => {
+                p.err_and_bump("expected identifier");
+                m.abandon(p);
+            }
+        }
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, RECORD_EXPR_FIELD_LIST);
+}
+
+// test tuple_expr
+// fn foo() {
+//     ();
+//     (1);
+//     (1,);
+//     (1, 2);
+// }
+fn tuple_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.expect(T!['(']);
+
+    let mut saw_comma = false;
+    let mut saw_expr = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        saw_expr = true;
+
+        if expr(p).is_none() {
+            break;
+        }
+
+        if !p.at(T![')']) {
+            saw_comma = true;
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![')']);
+    m.complete(p, if saw_expr && !saw_comma { PAREN_EXPR } else { TUPLE_EXPR })
+}
+
+// test collection_expr
+// fn foo() {
+//     [];
+//     [1];
+//     [1, 2,];
+//     [:];
+//     ["a": 1, "b": 2,];
+//     [:1, :2];
+//     [..xs, 3];
+//     [..m, "c": 3];
+//     [..s, :4];
+//     [..xs, ..ys];
+//     [..=5];
+// }
+fn collection_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['[']));
+    let m = p.start();
+    p.bump(T!['[']);
+
+    // `[:]` is the empty map, just as `[]` is the empty list.
+    if p.at(T![:]) && p.nth_at(1, T![']']) {
+        p.bump(T![:]);
+        p.bump(T![']']);
+        return m.complete(p, MAP_EXPR);
+    }
+
+    let mut kind = None;
+    let mut untyped_spreads = Vec::new();
+    while !p.at(EOF) && !p.at(T![']']) {
+        if !collection_entry(p, &mut kind, &mut untyped_spreads) {
+            break;
+        }
+        if !p.at(T![']']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![']']);
+
+    // Spreads say nothing about the kind of the collection, so any that came
+    // before the first regular entry only learn their kind now.
+    let kind = kind.unwrap_or(CollectionKind::List);
+    for mut spread in untyped_spreads {
+        spread.change_kind(p, kind.entry_kind());
+    }
+    m.complete(p, kind.expr_kind())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CollectionKind {
+    List,
+    Map,
+    Set,
+}
+
+impl CollectionKind {
+    fn expr_kind(self) -> SyntaxKind {
+        match self {
+            CollectionKind::List => LIST_EXPR,
+            CollectionKind::Map => MAP_EXPR,
+            CollectionKind::Set => SET_EXPR,
+        }
+    }
+
+    fn entry_kind(self) -> SyntaxKind {
+        match self {
+            CollectionKind::List => LIST_ENTRY,
+            CollectionKind::Map => MAP_ENTRY,
+            CollectionKind::Set => SET_ENTRY,
+        }
+    }
+
+    fn entry_name(self) -> &'static str {
+        match self {
+            CollectionKind::List => "list entry `value`",
+            CollectionKind::Map => "map entry `key: value`",
+            CollectionKind::Set => "set entry `:value`",
+        }
+    }
+}
+
+/// Parses one entry of a collection literal. The first regular entry decides
+/// the `kind` of the whole literal, and entries that disagree with it are
+/// wrapped in an `ERROR` node.
+///
+/// Returns `false` if no entry could be parsed at all.
+// test_err collection_expr_mixed_entries
+// fn foo() {
+//     [1, "a": 2];
+//     ["a": 1, :2];
+//     [:1, 2];
+// }
+fn collection_entry(
+    p: &mut Parser<'_>,
+    kind: &mut Option<CollectionKind>,
+    untyped_spreads: &mut Vec<CompletedMarker>,
+) -> bool {
+    let m = p.start();
+    attributes::outer_attrs(p);
+
+    if p.at(T![..]) {
+        p.bump(T![..]);
+        expr(p);
+        let spread = m.complete(p, kind.map_or(LIST_ENTRY, CollectionKind::entry_kind));
+        if kind.is_none() {
+            untyped_spreads.push(spread);
+        }
+        return true;
+    }
+
+    let found = if p.eat(T![:]) {
+        expr(p);
+        CollectionKind::Set
+    } else {
+        if expr(p).is_none() {
+            m.abandon(p);
+            return false;
+        }
+        if p.eat(T![:]) {
+            expr(p);
+            CollectionKind::Map
+        } else {
+            CollectionKind::List
+        }
+    };
+
+    let expected = *kind.get_or_insert(found);
+    if found == expected {
+        m.complete(p, found.entry_kind());
+    } else {
+        p.error(format!(
+            "expected a {}, found a {}: collection literals cannot mix kinds of entries",
+            expected.entry_name(),
+            found.entry_name(),
+        ));
+        m.complete(p, ERROR);
+    }
+    true
+}
+
+// test closure_expr
+// fn foo() {
+//     || ();
+//     || -> i32 { 92 };
+//     |x| x;
+//     |x: i32,| x;
+//     |a, b| a + b;
+// }
+fn closure_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![|]));
+    let m = p.start();
+    params::param_list_closure(p);
+    if opt_ret_type(p) {
+        // test closure_body_underscore_assignment
+        // fn foo() { || -> i32 { _ = 0; }; }
+        block_expr(p);
+    } else if p.at_ts(EXPR_FIRST) {
+        expr(p);
+    } else {
+        p.error("expected expression");
+    }
+    m.complete(p, CLOSURE_EXPR)
+}
+
+// test if_expr
+// fn foo() {
+//     if true {};
+//     if true {} else {};
+//     if true {} else if false {} else {};
+//     if S {};
+//     if { true } { } else { };
+// }
+fn if_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![if]));
+    let m = p.start();
+    p.bump(T![if]);
+    expr_no_struct(p);
+    block_expr(p);
+    if p.at(T![else]) {
+        p.bump(T![else]);
+        if p.at(T![if]) {
+            if_expr(p);
+        } else {
+            block_expr(p);
+        }
+    }
+    m.complete(p, IF_EXPR)
+}
+
+// test let_expr
+// fn foo() {
+//     if let x = y and x == 1 {}
+//     while let x = y {}
+// }
+fn let_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![let]);
+    patterns::pattern_top(p);
+    p.expect(T![=]);
+    // The scrutinee binds tighter than `and` and `or`, so that let chains
+    // like `if let a = b and c {}` work.
+    expr_bp(p, None, Restrictions { forbid_structs: true, prefer_stmt: false }, 5);
+    m.complete(p, LET_EXPR)
+}
+
+// test loop_expr
+// fn foo() {
+//     loop {};
+// }
+fn loop_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![loop]));
+    let m = p.start();
+    p.bump(T![loop]);
+    block_expr(p);
+    m.complete(p, LOOP_EXPR)
+}
+
+// test while_expr
+// fn foo() {
+//     while true {};
+//     while { true } {};
+// }
+fn while_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![while]));
+    let m = p.start();
+    p.bump(T![while]);
+    expr_no_struct(p);
+    block_expr(p);
+    m.complete(p, WHILE_EXPR)
+}
+
+// test for_expr
+// fn foo() {
+//     for x in [] {};
+//     for i in 0..10 {};
+// }
+fn for_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![for]));
+    let m = p.start();
+    p.bump(T![for]);
+    patterns::pattern(p);
+    p.expect(T![in]);
+    expr_no_struct(p);
+    block_expr(p);
+    m.complete(p, FOR_EXPR)
+}
+
+// test match_expr
+// fn foo() {
+//     match () { };
+//     match S {};
+//     match { } { _ => () };
+//     match { S {} } {};
+// }
+fn match_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![match]));
+    let m = p.start();
+    p.bump(T![match]);
+    expr_no_struct(p);
+    if p.at(T!['{']) {
+        match_arm_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, MATCH_EXPR)
+}
+
+pub(crate) fn match_arm_list(p: &mut Parser<'_>) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.eat(T!['{']);
+
+    // test match_arms_inner_attribute
+    // fn foo() {
+    //     match () {
+    //         #![doc("Inner attribute")]
+    //         _ => (),
+    //     }
+    // }
+    attributes::inner_attrs(p);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if p.at(T!['{']) {
+            error_block(p, "expected match arm");
+            continue;
+        }
+        match_arm(p);
+    }
+    p.expect(T!['}']);
+    m.complete(p, MATCH_ARM_LIST);
+}
+
+// test match_arm
+// fn foo() {
+//     match () {
+//         _ => (),
+//         _ if x => (),
+//         x => {}
+//         _ => 1,
+//     };
+// }
+fn match_arm(p: &mut Parser<'_>) {
+    let m = p.start();
+    // test match_arms_outer_attributes
+    // fn foo() {
+    //     match () {
+    //         #[cfg(feature = "some")]
+    //         _ => (),
+    //     }
+    // }
+    attributes::outer_attrs(p);
+
+    patterns::pattern_top_r(p, TokenSet::EMPTY);
+    if p.at(T![if]) {
+        match_guard(p);
+    }
+    p.expect(T![=>]);
+    let blocklike = match expr_stmt(p, None) {
+        Some((_, blocklike)) => blocklike,
+        None => BlockLike::NotBlock,
+    };
+
+    // test match_arms_commas
+    // fn foo() {
+    //     match () {
+    //         _ => (),
+    //         _ => {}
+    //         _ => ()
+    //     }
+    // }
+    if !p.eat(T![,]) && !blocklike.is_block() && !p.at(T!['}']) {
+        p.error("expected `,`");
+    }
+    m.complete(p, MATCH_ARM);
+}
+
+fn match_guard(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![if]));
+    let m = p.start();
+    p.bump(T![if]);
+    expr(p);
+    m.complete(p, MATCH_GUARD)
+}
+
+// test return_expr
+// fn foo() {
+//     return;
+//     return 92;
+// }
+fn return_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![return]));
+    let m = p.start();
+    p.bump(T![return]);
+    if p.at_ts(EXPR_FIRST) {
+        expr(p);
+    }
+    m.complete(p, RETURN_EXPR)
+}
+
+// test break_expr
+// fn foo() {
+//     loop { break; }
+// }
+fn break_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![break]));
+    let m = p.start();
+    p.bump(T![break]);
+    m.complete(p, BREAK_EXPR)
+}
+
+// test continue_expr
+// fn foo() {
+//     loop { continue; }
+// }
+fn continue_expr(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![continue]));
+    let m = p.start();
+    p.bump(T![continue]);
+    m.complete(p, CONTINUE_EXPR)
+}
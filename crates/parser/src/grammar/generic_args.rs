@@ -0,0 +1,88 @@
+use super::*;
+
+// test generic_arg_list
+// type T = S<i32, Item = str, U: Clone>;
+pub(super) fn opt_generic_arg_list(p: &mut Parser<'_>, colon_colon_required: bool) {
+    let m;
+    if p.at(T![::]) && p.nth_at(1, T![<]) {
+        m = p.start();
+        p.bump(T![::]);
+    } else if !colon_colon_required && p.at(T![<]) {
+        m = p.start();
+    } else {
+        return;
+    }
+
+    delimited(
+        p,
+        T![<],
+        T![>],
+        T![,],
+        || "expected generic argument".into(),
+        GENERIC_ARG_FIRST,
+        generic_arg,
+    );
+    m.complete(p, GENERIC_ARG_LIST);
+}
+
+const GENERIC_ARG_FIRST: TokenSet =
+    types::TYPE_FIRST.union(expressions::LITERAL_FIRST).union(TokenSet::new(&[T!['{'], T![-]]));
+
+fn generic_arg(p: &mut Parser<'_>) -> bool {
+    match p.current() {
+        // test assoc_type_eq
+        // type T = StreamingIterator<Item = Foo>;
+        IDENT if p.nth_at(1, T![=]) => {
+            let m = p.start();
+            name_ref(p);
+            p.bump(T![=]);
+            types::type_(p);
+            m.complete(p, ASSOC_TYPE_ARG);
+        }
+
+        // test assoc_type_bound
+        // type T = StreamingIterator<Item: Clone>;
+        IDENT if p.nth_at(1, T![:]) => {
+            let m = p.start();
+            name_ref(p);
+            generic_params::bounds(p);
+            m.complete(p, ASSOC_TYPE_ARG);
+        }
+        // test record_type_arg
+        // type T = S<{ x: i32 }>;
+        T!['{'] if p.nth_at(1, IDENT) && p.nth_at(2, T![:]) => type_arg(p),
+
+        // test const_arg
+        // type T = S<92, -1, {N + 1}, true>;
+        T!['{'] | T![-] => const_arg(p),
+        _ if p.at_ts(expressions::LITERAL_FIRST) => const_arg(p),
+        _ if p.at_ts(types::TYPE_FIRST) => type_arg(p),
+        _ => return false,
+    }
+    true
+}
+
+fn type_arg(p: &mut Parser<'_>) {
+    let m = p.start();
+    types::type_(p);
+    m.complete(p, TYPE_ARG);
+}
+
+fn const_arg(p: &mut Parser<'_>) {
+    let m = p.start();
+    match p.current() {
+        T!['{'] => {
+            expressions::block_expr(p);
+        }
+        T![-] => {
+            let lm = p.start();
+            p.bump(T![-]);
+            expressions::literal(p);
+            lm.complete(p, NEG_EXPR);
+        }
+        _ => {
+            expressions::literal(p);
+        }
+    }
+    m.complete(p, CONST_ARG);
+}
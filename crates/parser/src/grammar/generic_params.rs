@@ -0,0 +1,146 @@
+use super::*;
+
+pub(super) fn opt_generic_param_list(p: &mut Parser<'_>) {
+    if p.at(T![<]) {
+        generic_param_list(p);
+    }
+}
+
+// test generic_param_list
+// fn f<T: Clone, U = i32,>() {}
+fn generic_param_list(p: &mut Parser<'_>) {
+    assert!(p.at(T![<]));
+    let m = p.start();
+    delimited(
+        p,
+        T![<],
+        T![>],
+        T![,],
+        || "expected generic parameter".into(),
+        GENERIC_PARAM_FIRST.union(attributes::ATTRIBUTE_FIRST),
+        |p| {
+            // test generic_param_attribute
+            // fn foo<#[attr] T>() {}
+            let m = p.start();
+            attributes::outer_attrs(p);
+            generic_param(p, m)
+        },
+    );
+
+    m.complete(p, GENERIC_PARAM_LIST);
+}
+
+const GENERIC_PARAM_FIRST: TokenSet = TokenSet::new(&[IDENT]);
+
+fn generic_param(p: &mut Parser<'_>, m: Marker) -> bool {
+    match p.current() {
+        IDENT => type_param(p, m),
+        _ => {
+            m.abandon(p);
+            p.err_and_bump("expected generic parameter");
+            return false;
+        }
+    }
+    true
+}
+
+// test type_param
+// fn f<T: Clone>() {}
+fn type_param(p: &mut Parser<'_>, m: Marker) {
+    assert!(p.at(IDENT));
+    name(p);
+    if p.at(T![:]) {
+        bounds(p);
+    }
+
+    // test type_param_default
+    // enum E<T = i32> {}
+    if p.eat(T![=]) {
+        types::type_(p);
+    }
+    m.complete(p, TYPE_PARAM);
+}
+
+// test type_param_bounds
+// enum E<T: A + B + C + > {}
+pub(super) fn bounds(p: &mut Parser<'_>) {
+    assert!(p.at(T![:]));
+    p.bump(T![:]);
+    bounds_without_colon(p);
+}
+
+pub(super) fn bounds_without_colon(p: &mut Parser<'_>) {
+    let m = p.start();
+    while type_bound(p) {
+        if !p.eat(T![+]) {
+            break;
+        }
+    }
+    m.complete(p, TYPE_BOUND_LIST);
+}
+
+fn type_bound(p: &mut Parser<'_>) -> bool {
+    if !p.at_ts(types::TYPE_FIRST) {
+        return false;
+    }
+    let m = p.start();
+    types::type_(p);
+    m.complete(p, TYPE_BOUND);
+    true
+}
+
+// test where_clause
+// fn foo<T, U>()
+// where
+//    T: Clone + Copy,
+//    U: Display,
+// {}
+pub(super) fn opt_where_clause(p: &mut Parser<'_>) {
+    if !p.at(T![where]) {
+        return;
+    }
+    let m = p.start();
+    p.bump(T![where]);
+
+    while is_where_predicate(p) {
+        where_predicate(p);
+
+        let comma = p.eat(T![,]);
+
+        match p.current() {
+            T!['{'] | T![;] | T![=] => break,
+            _ => (),
+        }
+
+        if !comma {
+            p.error("expected comma");
+        }
+    }
+
+    m.complete(p, WHERE_CLAUSE);
+
+    fn is_where_predicate(p: &mut Parser<'_>) -> bool {
+        p.at(T![for]) || (p.at_ts(types::TYPE_FIRST) && !p.at(T!['{']))
+    }
+}
+
+fn where_predicate(p: &mut Parser<'_>) {
+    let m = p.start();
+    if p.at(T![for]) {
+        // test where_pred_for
+        // fn f<F>() where for<T> F: Fn(T) {}
+        p.bump(T![for]);
+        if p.at(T![<]) {
+            generic_param_list(p);
+        } else {
+            p.error("expected `<`");
+        }
+    }
+    types::type_(p);
+    if p.at(T![:]) {
+        bounds(p);
+    } else {
+        p.error("expected colon");
+    }
+    m.complete(p, WHERE_PRED);
+}
@@ -1,18 +1,54 @@
+mod adt;
+mod deps;
+mod traits;
+
 use super::*;
 
+pub(crate) use self::{
+    adt::{record_field_list, variant_list},
+    deps::use_tree_expansion,
+    traits::assoc_item_list,
+};
+
 // test source_contents
 // fn foo() {}
 // type Bar = { b: i32 };
 // impl Bar { fn bar(self) -> i32 { self.b } }
 pub(super) fn source_contents(p: &mut Parser<'_>, stop_on_r_curly: bool) {
     attributes::inner_attrs(p);
-    while !(p.at(EOF) || (p.at(T!['}']) && stop_on_r_curly)) {
+    while !(p.at(EOF) || p.at(T![---]) || (p.at(T!['}']) && stop_on_r_curly)) {
         item(p, stop_on_r_curly);
     }
 }
 
-pub(super) const ITEM_RECOVERY_SET: TokenSet =
-    TokenSet::new(&[T![fn], T![enum], T![impl], T![trait], T![let], T![pub], T![use], T![;]]);
+/// Parses everything before the `---` separator, if there is anything.
+pub(super) fn code_section(p: &mut Parser<'_>) {
+    if p.at(EOF) || p.at(T![---]) {
+        return;
+    }
+    let m = p.start();
+    source_contents(p, false);
+    m.complete(p, CODE_SECTION);
+}
+
+/// Parses everything after the `---` separator.
+pub(super) fn meta_section(p: &mut Parser<'_>) {
+    let m = p.start();
+    deps::meta_contents(p);
+    m.complete(p, META_SECTION);
+}
+
+pub(super) const ITEM_RECOVERY_SET: TokenSet = TokenSet::new(&[
+    T![fn],
+    T![enum],
+    T![impl],
+    T![trait],
+    T![let],
+    T![pub],
+    T![use],
+    T![;],
+    T![---],
+]);
 
 pub(super) fn item(p: &mut Parser<'_>, stop_on_r_curly: bool) {
     let m = p.start();
@@ -48,7 +84,92 @@ pub(super) fn item(p: &mut Parser<'_>, stop_on_r_curly: bool) {
 
 /// Try to parse an item, completing `m` in case of success.
 pub(super) fn opt_item(p: &mut Parser<'_>, m: Marker) -> Result<(), Marker> {
-    todo!()
+    let has_visibility = opt_visibility(p);
+
+    match p.current() {
+        T![fn] => fn_(p, m),
+        T![enum] => adt::enum_(p, m),
+        T![impl] => traits::impl_(p, m),
+        T![trait] => traits::trait_(p, m),
+        T![type] => type_alias(p, m),
+
+        // test_err pub_without_item
+        // pub 92;
+        _ if has_visibility => {
+            p.error("expected an item after visibility");
+            m.complete(p, ERROR);
+        }
+        _ => return Err(m),
+    }
+
+    Ok(())
+}
+
+// test fn_item
+// fn foo() {}
+// pub fn bar<T>(a: T, b: i32) -> T where T: Clone {}
+// fn baz();
+fn fn_(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![fn]);
+
+    name_r(p, ITEM_RECOVERY_SET);
+
+    // test function_type_params
+    // fn foo<T: Clone + Copy>() {}
+    generic_params::opt_generic_param_list(p);
+
+    if p.at(T!['(']) {
+        params::param_list_fn_def(p);
+    } else {
+        // test_err fn_no_params
+        // fn foo {}
+        p.error("expected function arguments");
+    }
+
+    // test function_ret_type
+    // fn foo() -> i32 {}
+    opt_ret_type(p);
+
+    // test function_where_clause
+    // fn foo<T>() where T: Copy {}
+    generic_params::opt_where_clause(p);
+
+    if p.at(T![;]) {
+        // test fn_decl
+        // trait T { fn foo(); }
+        p.bump(T![;]);
+    } else {
+        expressions::block_expr(p);
+    }
+    m.complete(p, FN);
+}
+
+// test type_alias
+// type Foo = Bar;
+// pub type Baz<T> = Qux<T>;
+// type Abstract: Bound;
+fn type_alias(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![type]);
+
+    name(p);
+
+    // test type_item_type_params
+    // type Result<T> = Outcome<T, Error>;
+    generic_params::opt_generic_param_list(p);
+
+    if p.at(T![:]) {
+        generic_params::bounds(p);
+    }
+
+    // test type_item_where_clause
+    // type Foo<T> = Bar<T> where T: Copy;
+    generic_params::opt_where_clause(p);
+    if p.eat(T![=]) {
+        types::type_(p);
+    }
+    generic_params::opt_where_clause(p);
+    p.expect(T![;]);
+    m.complete(p, TYPE_ALIAS);
 }
 
 pub(crate) fn token_tree(p: &mut Parser<'_>) {
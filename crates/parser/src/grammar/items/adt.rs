@@ -0,0 +1,129 @@
+use super::*;
+
+// test enum_item
+// enum E {}
+// pub enum Shape<T> { Point, Circle(T), Rect { w: T, h: T }, }
+pub(super) fn enum_(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![enum]);
+    name_r(p, ITEM_RECOVERY_SET);
+    generic_params::opt_generic_param_list(p);
+    generic_params::opt_where_clause(p);
+    if p.at(T!['{']) {
+        variant_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, ENUM);
+}
+
+pub(crate) fn variant_list(p: &mut Parser<'_>) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if p.at(T!['{']) {
+            error_block(p, "expected enum variant");
+            continue;
+        }
+        variant(p);
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, VARIANT_LIST);
+
+    fn variant(p: &mut Parser<'_>) {
+        let m = p.start();
+        attributes::outer_attrs(p);
+        opt_visibility(p);
+        if p.at(IDENT) {
+            name(p);
+            match p.current() {
+                T!['{'] => record_field_list(p),
+                T!['('] => tuple_field_list(p),
+                _ => (),
+            }
+
+            // test variant_discriminant
+            // enum E { X(i32) = 10 }
+            if p.eat(T![=]) {
+                expressions::expr(p);
+            }
+            m.complete(p, VARIANT);
+        } else {
+            // test_err enum_variant_recovery
+            // enum E { 92, A }
+            m.abandon(p);
+            p.err_and_bump("expected enum variant");
+        }
+    }
+}
+
+// test record_field_list
+// enum E { A { a: i32, #[attr] b: str, } }
+pub(crate) fn record_field_list(p: &mut Parser<'_>) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    while !p.at(T!['}']) && !p.at(EOF) {
+        if p.at(T!['{']) {
+            error_block(p, "expected field");
+            continue;
+        }
+        record_field(p);
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+    m.complete(p, RECORD_FIELD_LIST);
+
+    fn record_field(p: &mut Parser<'_>) {
+        let m = p.start();
+        attributes::outer_attrs(p);
+        if p.at(IDENT) {
+            name(p);
+            p.expect(T![:]);
+            types::type_(p);
+            m.complete(p, RECORD_FIELD);
+        } else {
+            m.abandon(p);
+            p.err_and_bump("expected field declaration");
+        }
+    }
+}
+
+// test tuple_field_list
+// enum E { A(i32, pub str,) }
+fn tuple_field_list(p: &mut Parser<'_>) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    delimited(
+        p,
+        T!['('],
+        T![')'],
+        T![,],
+        || "expected tuple field".into(),
+        types::TYPE_FIRST.union(attributes::ATTRIBUTE_FIRST).union(VISIBILITY_FIRST),
+        |p| {
+            let m = p.start();
+            attributes::outer_attrs(p);
+            let has_vis = opt_visibility(p);
+            if !p.at_ts(types::TYPE_FIRST) {
+                p.error("expected a type");
+                if has_vis {
+                    m.complete(p, ERROR);
+                } else {
+                    m.abandon(p);
+                }
+                return false;
+            }
+            types::type_(p);
+            m.complete(p, TUPLE_FIELD);
+            true
+        },
+    );
+
+    m.complete(p, TUPLE_FIELD_LIST);
+}
@@ -0,0 +1,252 @@
+use super::*;
+
+const DEP_RECOVERY_SET: TokenSet = TokenSet::new(&[T![from], T![use], T![show], T![;]]);
+
+const CODE_ITEM_FIRST: TokenSet =
+    TokenSet::new(&[T![fn], T![enum], T![impl], T![trait], T![type], T![pub], T![let]]);
+
+// test meta_section
+// fn main() {}
+//
+// ---
+// from cool_lib use CoolThing;
+// from cooler_lib use * as cooler;
+//
+// from ~/repo_relative/dir/a_file use SomeType::ATraitImpl;
+//
+// from relative/sub_dir/some_file use
+//   SomeType,
+//   some_func,
+//   SomeEnum::SomeVariant;
+pub(super) fn meta_contents(p: &mut Parser<'_>) {
+    attributes::inner_attrs(p);
+    while !p.at(EOF) {
+        meta_item(p);
+    }
+}
+
+// test_err code_item_in_meta_section
+// ---
+// from foo use bar;
+// fn baz() {}
+// use qux;
+// ---
+fn meta_item(p: &mut Parser<'_>) {
+    let e = p.start();
+    let m = p.start();
+    attributes::outer_attrs(p);
+
+    if p.at(T![from]) {
+        dep(p, m);
+        e.abandon(p);
+        return;
+    }
+
+    match p.current() {
+        // Code items are parsed as if they were in the right place, so that
+        // validation can point at the whole item rather than at its first
+        // token.
+        _ if p.at_ts(CODE_ITEM_FIRST) => {
+            e.abandon(p);
+            if p.at(T![let]) {
+                expressions::let_stmt(p, expressions::Semicolon::Optional);
+                m.complete(p, LET_STMT);
+            } else if let Err(m) = opt_item(p, m) {
+                m.abandon(p);
+            }
+            return;
+        }
+        T![use] | T![show] => {
+            m.abandon(p);
+            p.error("expected `from` followed by the path of a dependency");
+            use_(p);
+        }
+        T![---] => {
+            m.abandon(p);
+            p.error("a source file can only have one `---`");
+            p.bump(T![---]);
+        }
+        T!['{'] => {
+            m.abandon(p);
+            error_block(p, "expected a dependency");
+            e.abandon(p);
+            return;
+        }
+        T!['}'] => {
+            m.abandon(p);
+            p.error("unmatched `}`");
+            p.bump(T!['}']);
+        }
+        EOF => {
+            m.abandon(p);
+            e.abandon(p);
+            p.error("expected a dependency");
+            return;
+        }
+        _ => {
+            m.abandon(p);
+            e.abandon(p);
+            p.err_and_bump("expected a dependency");
+            return;
+        }
+    }
+    e.complete(p, ERROR);
+}
+
+fn dep(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![from]);
+    dep_path(p);
+    if p.at(T![use]) || p.at(T![show]) {
+        use_(p);
+    } else {
+        p.error("expected `use` or `show`");
+    }
+    m.complete(p, DEP);
+}
+
+// test dep_path
+// ---
+// from std use fmt;
+// from a/b/c use d;
+// from ~/repo/dir use e;
+// from ./sibling use f;
+// from ../../cousin/file use g;
+fn dep_path(p: &mut Parser<'_>) {
+    match p.current() {
+        T![~] => {
+            let m = p.start();
+            p.bump(T![~]);
+            p.expect(T![/]);
+            external_dep_path(p);
+            m.complete(p, REPO_DEP_PATH);
+        }
+        T![.] => {
+            let m = p.start();
+            p.bump(T![.]);
+            p.expect(T![/]);
+            external_dep_path(p);
+            m.complete(p, LOCAL_DEP_PATH);
+        }
+        T![..] => {
+            let m = p.start();
+            while p.at(T![..]) {
+                let segment = p.start();
+                p.bump(T![..]);
+                p.expect(T![/]);
+                segment.complete(p, PARENT_DIR_DEP_PATH_SEGMENT);
+            }
+            external_dep_path(p);
+            m.complete(p, LOCAL_DEP_PATH);
+        }
+        IDENT => external_dep_path(p),
+        _ => p.err_recover("expected the path of a dependency", DEP_RECOVERY_SET),
+    }
+}
+
+fn external_dep_path(p: &mut Parser<'_>) {
+    let path = p.start();
+    dep_path_segment(p);
+    let mut qual = path.complete(p, EXTERNAL_DEP_PATH);
+    while p.at(T![/]) {
+        let path = qual.precede(p);
+        p.bump(T![/]);
+        dep_path_segment(p);
+        qual = path.complete(p, EXTERNAL_DEP_PATH);
+    }
+}
+
+fn dep_path_segment(p: &mut Parser<'_>) {
+    if !p.at(IDENT) {
+        p.err_recover("expected a path segment", DEP_RECOVERY_SET);
+        return;
+    }
+    let m = p.start();
+    name_ref(p);
+    m.complete(p, DEP_PATH_SEGMENT);
+}
+
+// test show_dep
+// ---
+// from ./widgets show Button, Slider as Range, theme::{dark, light};
+fn use_(p: &mut Parser<'_>) {
+    assert!(p.at(T![use]) || p.at(T![show]));
+    let m = p.start();
+    p.bump_any();
+    if is_use_tree_start(p) {
+        use_tree_list(p, T![;]);
+    } else {
+        p.error("expected a use tree");
+    }
+    p.expect(T![;]);
+    m.complete(p, USE);
+}
+
+fn is_use_tree_start(p: &Parser<'_>) -> bool {
+    p.at(T![*]) || p.at(T!['{']) || paths::is_use_path_start(p)
+}
+
+fn use_tree_list(p: &mut Parser<'_>, ket: SyntaxKind) {
+    let m = p.start();
+    while !p.at(EOF) && !p.at(ket) {
+        use_tree(p);
+        if !p.eat(T![,]) {
+            if is_use_tree_start(p) {
+                p.error("expected `,`");
+            } else {
+                break;
+            }
+        }
+    }
+    m.complete(p, USE_TREE_LIST);
+}
+
+// test use_tree
+// ---
+// from a use *;
+// from a use * as b;
+// from a use B::*;
+// from a use B::{c, D::e as f, g::*};
+// from a use {b, c};
+// from a use b as _;
+fn use_tree(p: &mut Parser<'_>) {
+    let m = p.start();
+    match p.current() {
+        T![*] => {
+            p.bump(T![*]);
+            opt_rename(p);
+        }
+        T!['{'] => use_tree_expansion(p),
+        _ if paths::is_use_path_start(p) => {
+            paths::use_path(p);
+            if p.eat(T![::]) {
+                match p.current() {
+                    T![*] => {
+                        p.bump(T![*]);
+                        opt_rename(p);
+                    }
+                    T!['{'] => use_tree_expansion(p),
+                    _ => p.error("expected `{` or `*`"),
+                }
+            } else {
+                opt_rename(p);
+            }
+        }
+        _ => {
+            m.abandon(p);
+            p.err_recover("expected a use tree", DEP_RECOVERY_SET.union(TokenSet::new(&[T![,]])));
+            return;
+        }
+    }
+    m.complete(p, USE_TREE);
+}
+
+pub(crate) fn use_tree_expansion(p: &mut Parser<'_>) {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    p.bump(T!['{']);
+    if !p.at(T!['}']) {
+        use_tree_list(p, T!['}']);
+    }
+    p.expect(T!['}']);
+    m.complete(p, USE_TREE_EXPANSION);
+}
@@ -0,0 +1,87 @@
+use super::*;
+
+// test trait_item
+// trait T {}
+// pub trait Shape<U>: Display where U: Clone { fn area(self) -> U; }
+pub(super) fn trait_(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![trait]);
+    name_r(p, ITEM_RECOVERY_SET);
+
+    // test trait_item_generic_params
+    // trait X<U: Debug + Display> {}
+    generic_params::opt_generic_param_list(p);
+
+    // test trait_item_bounds
+    // trait T: Hash + Clone {}
+    if p.at(T![:]) {
+        generic_params::bounds(p);
+    }
+
+    // test trait_item_where_clause
+    // trait T where Self: Copy {}
+    generic_params::opt_where_clause(p);
+
+    if p.at(T!['{']) {
+        assoc_item_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, TRAIT);
+}
+
+// test impl_item
+// impl S {}
+// impl<T> Display for Wrapper<T> where T: Display {}
+pub(super) fn impl_(p: &mut Parser<'_>, m: Marker) {
+    p.bump(T![impl]);
+    generic_params::opt_generic_param_list(p);
+
+    // test impl_item_for
+    // impl Hash for S {}
+    impl_type(p);
+    if p.eat(T![for]) {
+        impl_type(p);
+    }
+    generic_params::opt_where_clause(p);
+    if p.at(T!['{']) {
+        assoc_item_list(p);
+    } else {
+        p.error("expected `{`");
+    }
+    m.complete(p, IMPL);
+}
+
+// test assoc_item_list
+// impl F {
+//     fn a() {}
+//     pub fn b(self) {}
+// }
+pub(crate) fn assoc_item_list(p: &mut Parser<'_>) {
+    assert!(p.at(T!['{']));
+
+    let m = p.start();
+    p.bump(T!['{']);
+    // test assoc_item_list_inner_attrs
+    // impl S { #![attr] }
+    attributes::inner_attrs(p);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if p.at(T!['{']) {
+            error_block(p, "expected an item");
+            continue;
+        }
+        item(p, true);
+    }
+    p.expect(T!['}']);
+    m.complete(p, ASSOC_ITEM_LIST);
+}
+
+fn impl_type(p: &mut Parser<'_>) {
+    if p.at(T![impl]) {
+        // test_err impl_type_is_impl
+        // impl impl S {}
+        p.error("expected trait or type");
+        return;
+    }
+    types::type_(p);
+}
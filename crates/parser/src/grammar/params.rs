@@ -0,0 +1,165 @@
+use super::*;
+
+// test param_list
+// fn a() {}
+// fn b(x: i32) {}
+// fn c(x: i32, ) {}
+// fn d(x: i32, y: str) {}
+pub(super) fn param_list_fn_def(p: &mut Parser<'_>) {
+    list_(p, Flavor::FnDef);
+}
+
+// test param_list_fn_ptr
+// type F = fn(i32, label: str) -> str;
+pub(super) fn param_list_fn_ptr(p: &mut Parser<'_>) {
+    list_(p, Flavor::FnPointer);
+}
+
+pub(super) fn param_list_closure(p: &mut Parser<'_>) {
+    list_(p, Flavor::Closure);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flavor {
+    FnDef,
+    FnPointer,
+    Closure,
+}
+
+fn list_(p: &mut Parser<'_>, flavor: Flavor) {
+    use Flavor::*;
+
+    let (bra, ket) = match flavor {
+        Closure => (T![|], T![|]),
+        FnDef | FnPointer => (T!['('], T![')']),
+    };
+
+    let param_first = match flavor {
+        FnPointer => FN_PTR_PARAM_FIRST,
+        FnDef | Closure => PARAM_FIRST,
+    }
+    .union(attributes::ATTRIBUTE_FIRST);
+
+    let list_marker = p.start();
+    p.bump(bra);
+
+    let mut param_marker = None;
+    if let FnDef = flavor {
+        // test self_param
+        // impl S {
+        //     fn a(self) {}
+        //     fn b(mut self,) {}
+        //     fn c(#[attr] self, x: i32) {}
+        // }
+        let m = p.start();
+        attributes::outer_attrs(p);
+        match opt_self_param(p, m) {
+            Ok(()) => {}
+            Err(m) => param_marker = Some(m),
+        }
+    }
+
+    while !p.at(EOF) && !p.at(ket) {
+        // test param_outer_arg
+        // fn f(#[attr1] pat: Type) {}
+        let m = match param_marker.take() {
+            Some(m) => m,
+            None => {
+                let m = p.start();
+                attributes::outer_attrs(p);
+                m
+            }
+        };
+
+        // test_err fn_param_list_missing_r_paren
+        // fn f( {}
+        if !p.at_ts(param_first) || (flavor == FnDef && at_fn_body_start(p)) {
+            p.error("expected value parameter");
+            m.abandon(p);
+            break;
+        }
+        param(p, m, flavor);
+        if !p.eat(T![,]) {
+            if p.at_ts(param_first) {
+                p.error("expected `,`");
+            } else {
+                break;
+            }
+        }
+    }
+
+    if let Some(m) = param_marker {
+        m.abandon(p);
+    }
+
+    p.expect(ket);
+    list_marker.complete(p, PARAM_LIST);
+}
+
+/// In a function definition, a `{` that does not look like the start of a
+/// record pattern is far more likely to be the body of a function whose
+/// parameter list is missing its `)`.
+fn at_fn_body_start(p: &Parser<'_>) -> bool {
+    p.at(T!['{'])
+        && !(p.nth_at(1, T![..])
+            || (p.nth_at(1, IDENT) && matches!(p.nth(2), T![,] | T![:] | T!['}'])))
+}
+
+const PARAM_FIRST: TokenSet =
+    patterns::PATTERN_FIRST.union(TokenSet::new(&[T![mut], T![..], T![...]]));
+
+const FN_PTR_PARAM_FIRST: TokenSet = PARAM_FIRST.union(types::TYPE_FIRST);
+
+fn param(p: &mut Parser<'_>, m: Marker, flavor: Flavor) {
+    match flavor {
+        // test param_list_vararg
+        // fn printf(format: str, ...) {}
+        _ if p.at(T![...]) => p.bump(T![...]),
+
+        // test fn_ptr_unnamed_param
+        // type F = fn(i32, str);
+        Flavor::FnPointer if !(p.at(IDENT) || p.at(T![_])) || !p.nth_at(1, T![:]) => {
+            types::type_(p);
+        }
+
+        // test closure_param_type
+        // fn f() { |a: i32?, b: [str]| a; }
+        Flavor::Closure => {
+            p.eat(T![mut]);
+            patterns::pattern_single(p);
+            if p.eat(T![:]) {
+                types::type_no_union(p);
+            }
+        }
+        // test param_without_type
+        // fn f(a, b: i32) {}
+
+        // test param_mut
+        // fn f(mut a: i32) {}
+        _ => {
+            p.eat(T![mut]);
+            patterns::pattern(p);
+            if p.at(T![:]) {
+                types::ascription(p);
+            }
+        }
+    }
+    m.complete(p, PARAM);
+}
+
+fn opt_self_param(p: &mut Parser<'_>, m: Marker) -> Result<(), Marker> {
+    if !(p.at(T![self]) || (p.at(T![mut]) && p.nth_at(1, T![self]))) {
+        return Err(m);
+    }
+
+    p.eat(T![mut]);
+    let name = p.start();
+    p.bump(T![self]);
+    name.complete(p, NAME);
+    m.complete(p, SELF_PARAM);
+
+    if !p.at(T![')']) {
+        p.expect(T![,]);
+    }
+    Ok(())
+}
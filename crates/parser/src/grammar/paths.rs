@@ -1,17 +1,13 @@
 use super::*;
 
-pub(super) const PATH_FIRST: TokenSet = TokenSet::new(&[IDENT, T![self], T![Self], T![:], T![<]]);
+pub(super) const PATH_FIRST: TokenSet = TokenSet::new(&[IDENT, T![self], T![Self], T![::]]);
 
 pub(super) fn is_path_start(p: &Parser<'_>) -> bool {
-    is_use_path_start(p) || p.at(T![<]) || p.at(T![Self])
+    is_use_path_start(p) || p.at(T![Self])
 }
 
 pub(super) fn is_use_path_start(p: &Parser<'_>) -> bool {
-    match p.current() {
-        IDENT | T![self] => true,
-        T![:] if p.at(T![::]) => true,
-        _ => false,
-    }
+    matches!(p.current(), IDENT | T![self] | T![::])
 }
 
 pub(super) fn use_path(p: &mut Parser<'_>) {
@@ -26,13 +22,6 @@ pub(super) fn expr_path(p: &mut Parser<'_>) {
     path(p, Mode::Expr);
 }
 
-pub(crate) fn type_path_for_qualifier(
-    p: &mut Parser<'_>,
-    qual: CompletedMarker,
-) -> CompletedMarker {
-    path_for_qualifier(p, Mode::Type, qual)
-}
-
 #[derive(Clone, Copy, Eq, PartialEq)]
 enum Mode {
     Use,
@@ -53,7 +42,7 @@ fn path_for_qualifier(
     mut qual: CompletedMarker,
 ) -> CompletedMarker {
     loop {
-        let use_tree = mode == Mode::Use && matches!(p.nth(2), T![*] | T!['{']);
+        let use_tree = mode == Mode::Use && matches!(p.nth(1), T![*] | T!['{']);
         if p.at(T![::]) && !use_tree {
             let path = qual.precede(p);
             p.bump(T![::]);
@@ -80,14 +69,19 @@ fn path_segment(p: &mut Parser<'_>, mode: Mode, first: bool) {
     };
 
     match p.current() {
-        IDENT => name_ref(p),
+        IDENT => {
+            name_ref(p);
+            opt_path_type_args(p, mode);
+        }
 
         // test self_path
-        // use self::foo;
+        // ---
+        // from foo use self::bar;
         T![self] | T![Self] => {
             let m = p.start();
             p.bump_any();
             m.complete(p, NAME_REF);
+            opt_path_type_args(p, mode);
         }
         _ => {
             let recover_set = match mode {
@@ -98,7 +92,8 @@ fn path_segment(p: &mut Parser<'_>, mode: Mode, first: bool) {
             p.err_recover("expected identifier", recover_set);
             if empty {
                 // test_err empty_segment
-                // use crate::;
+                // ---
+                // from foo use crate::;
                 m.abandon(p);
                 return;
             }
@@ -106,3 +101,20 @@ fn path_segment(p: &mut Parser<'_>, mode: Mode, first: bool) {
     };
     m.complete(p, PATH_SEGMENT);
 }
+
+fn opt_path_type_args(p: &mut Parser<'_>, mode: Mode) {
+    match mode {
+        Mode::Use => {}
+        Mode::Type => {
+            // test path_fn_trait_args
+            // type F = Box<Fn(i32) -> i32>;
+            if p.at(T!['(']) {
+                params::param_list_fn_ptr(p);
+                opt_ret_type(p);
+            } else {
+                generic_args::opt_generic_arg_list(p, false);
+            }
+        }
+        Mode::Expr => generic_args::opt_generic_arg_list(p, true),
+    }
+}
@@ -0,0 +1,370 @@
+use super::*;
+
+pub(super) const PATTERN_FIRST: TokenSet = expressions::LITERAL_FIRST
+    .union(paths::PATH_FIRST)
+    .union(TokenSet::new(&[T![-], T!['('], T!['['], T!['{'], T![_], T![..], T![..=]]));
+
+const PAT_TOP_FIRST: TokenSet = PATTERN_FIRST.union(TokenSet::new(&[T![|]]));
+
+/// Set of possible tokens at the start of a range pattern's end bound.
+const RANGE_PAT_END_FIRST: TokenSet =
+    expressions::LITERAL_FIRST.union(paths::PATH_FIRST).union(TokenSet::new(&[T![-]]));
+
+const PAT_RECOVERY_SET: TokenSet =
+    TokenSet::new(&[T![let], T![if], T![while], T![loop], T![match], T![')'], T![,], T![=]]);
+
+pub(crate) fn pattern(p: &mut Parser<'_>) {
+    pattern_r(p, PAT_RECOVERY_SET);
+}
+
+/// Parses a pattern list separated by pipes `|`.
+pub(super) fn pattern_top(p: &mut Parser<'_>) {
+    pattern_top_r(p, PAT_RECOVERY_SET);
+}
+
+pub(crate) fn pattern_single(p: &mut Parser<'_>) {
+    pattern_single_r(p, PAT_RECOVERY_SET);
+}
+
+/// Parses a pattern list separated by pipes `|`
+/// using the given `recovery_set`.
+pub(super) fn pattern_top_r(p: &mut Parser<'_>, recovery_set: TokenSet) {
+    let m = p.start();
+    let has_leading_pipe = p.eat(T![|]);
+    pattern_single_r(p, recovery_set);
+
+    if !p.at(T![|]) && !has_leading_pipe {
+        m.abandon(p);
+        return;
+    }
+    while p.eat(T![|]) {
+        pattern_single_r(p, recovery_set);
+    }
+    m.complete(p, OR_PAT);
+}
+
+/// Parses a pattern list separated by pipes `|`, with no leading `|`, using the
+/// given `recovery_set`.
+// test or_pattern
+// fn main() {
+//     match () {
+//         (_ | _) => (),
+//         [_ | _,] => (),
+//         | 1 | 2 => (),
+//     }
+// }
+fn pattern_r(p: &mut Parser<'_>, recovery_set: TokenSet) {
+    let m = p.start();
+    pattern_single_r(p, recovery_set);
+
+    if !p.at(T![|]) {
+        m.abandon(p);
+        return;
+    }
+    while p.eat(T![|]) {
+        pattern_single_r(p, recovery_set);
+    }
+    m.complete(p, OR_PAT);
+}
+
+fn pattern_single_r(p: &mut Parser<'_>, recovery_set: TokenSet) {
+    // test range_pat
+    // fn main() {
+    //     match 92 {
+    //         0..=100 => (),
+    //         101..200 => (),
+    //         200.. => (),
+    //         ..=0 => (),
+    //         ..-10 => (),
+    //     }
+    // }
+    if p.at(T![..=])
+        || (p.at(T![..]) && p.nth_at(1, T![-]))
+        || (p.at(T![..]) && expressions::LITERAL_FIRST.contains(p.nth(1)))
+    {
+        let m = p.start();
+        if !p.eat(T![..=]) {
+            p.bump(T![..]);
+        }
+        atom_pat(p, recovery_set);
+        m.complete(p, RANGE_PAT);
+        return;
+    }
+
+    let Some(lhs) = atom_pat(p, recovery_set) else {
+        return;
+    };
+    if !matches!(lhs.kind(), LITERAL_PAT | PATH_PAT | IDENT_PAT) {
+        return;
+    }
+    for range_op in [T![..=], T![..]] {
+        if p.at(range_op) {
+            let m = lhs.precede(p);
+            p.bump(range_op);
+            if p.at_ts(RANGE_PAT_END_FIRST) {
+                atom_pat(p, recovery_set);
+            }
+            m.complete(p, RANGE_PAT);
+            return;
+        }
+    }
+}
+
+fn atom_pat(p: &mut Parser<'_>, recovery_set: TokenSet) -> Option<CompletedMarker> {
+    let m = match p.current() {
+        T![_] => wildcard_pat(p),
+        T![..] => rest_pat(p),
+        T!['('] => tuple_pat(p),
+        T!['['] => slice_pat(p),
+        T!['{'] => record_pat(p),
+        _ if is_literal_pat_start(p) => literal_pat(p),
+        _ if paths::is_path_start(p) => path_or_ident_pat(p),
+        _ => {
+            p.err_recover("expected pattern", recovery_set);
+            return None;
+        }
+    };
+    Some(m)
+}
+
+fn is_literal_pat_start(p: &Parser<'_>) -> bool {
+    p.at(T![-]) && (p.nth(1) == INT_NUMBER || p.nth(1) == FLOAT_NUMBER)
+        || p.at_ts(expressions::LITERAL_FIRST)
+}
+
+// test literal_pattern
+// fn main() {
+//     match () {
+//         -1 => (),
+//         92 => (),
+//         'c' => (),
+//         "hello" => (),
+//         true => (),
+//     }
+// }
+fn literal_pat(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(is_literal_pat_start(p));
+    let m = p.start();
+    p.eat(T![-]);
+    expressions::literal(p);
+    m.complete(p, LITERAL_PAT)
+}
+
+// test path_pat
+// fn main() {
+//     let foo::Bar = ();
+//     let ::Bar = ();
+//     let Bar { .. } = ();
+//     let Bar(..) = ();
+// }
+fn path_or_ident_pat(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(paths::is_path_start(p));
+    if p.at(IDENT) && !matches!(p.nth(1), T![::] | T!['('] | T!['{']) {
+        return ident_pat(p);
+    }
+
+    let m = p.start();
+    paths::expr_path(p);
+    let kind = match p.current() {
+        T!['('] => {
+            tuple_pat_fields(p);
+            ALIASED_TUPLE_PAT
+        }
+        T!['{'] => {
+            record_pat_fields(p);
+            RECORD_PAT
+        }
+        _ => PATH_PAT,
+    };
+    m.complete(p, kind)
+}
+
+// test ident_pat
+// fn f(a, b: i32) {
+//     let c @ 1..=9 = b;
+// }
+fn ident_pat(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(IDENT));
+    let m = p.start();
+    name(p);
+    if p.eat(T![@]) {
+        pattern_single(p);
+    }
+    m.complete(p, IDENT_PAT)
+}
+
+// test wildcard_pat
+// fn f(_: i32) {}
+fn wildcard_pat(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![_]));
+    let m = p.start();
+    p.bump(T![_]);
+    m.complete(p, WILDCARD_PAT)
+}
+
+// test rest_pat
+// fn main() {
+//     let (..a_and_b, c) = t;
+//     let [first, ..] = xs;
+// }
+fn rest_pat(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![..]));
+    let m = p.start();
+    p.bump(T![..]);
+    if p.at(IDENT) {
+        name(p);
+    }
+    m.complete(p, REST_PAT)
+}
+
+// test tuple_pat
+// fn main() {
+//     let (a, b, ..) = ();
+//     let (a,) = ();
+//     let (..) = ();
+//     let () = ();
+//     let (a) = ();
+// }
+fn tuple_pat(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+    let mut has_comma = false;
+    let mut has_pat = false;
+    let mut has_rest = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        has_pat = true;
+        if !p.at_ts(PAT_TOP_FIRST) {
+            p.error("expected a pattern");
+            break;
+        }
+        has_rest |= p.at(T![..]);
+
+        pattern_top(p);
+        if !p.at(T![')']) {
+            has_comma = true;
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T![')']);
+
+    m.complete(p, if !has_comma && !has_rest && has_pat { PAREN_PAT } else { TUPLE_PAT })
+}
+
+// test aliased_tuple_pat
+// fn foo() {
+//     let S() = ();
+//     let S(_) = ();
+//     let S(_,) = ();
+//     let S(_, .. , x) = ();
+// }
+fn tuple_pat_fields(p: &mut Parser<'_>) {
+    assert!(p.at(T!['(']));
+    p.bump(T!['(']);
+    pat_list(p, T![')'], pattern_top);
+    p.expect(T![')']);
+}
+
+// test slice_pat
+// fn main() {
+//     let [a, b, ..] = [];
+//     let ["i": i, -1: j, ..rest] = map;
+// }
+fn slice_pat(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['[']));
+    let m = p.start();
+    p.bump(T!['[']);
+    pat_list(p, T![']'], |p| {
+        if is_map_entry_pat_start(p) {
+            map_entry_pat(p);
+        } else {
+            pattern_top(p);
+        }
+    });
+    p.expect(T![']']);
+    m.complete(p, SLICE_PAT)
+}
+
+fn is_map_entry_pat_start(p: &Parser<'_>) -> bool {
+    if p.at(T![-]) {
+        expressions::LITERAL_FIRST.contains(p.nth(1)) && p.nth_at(2, T![:])
+    } else {
+        p.at_ts(expressions::LITERAL_FIRST) && p.nth_at(1, T![:])
+    }
+}
+
+fn map_entry_pat(p: &mut Parser<'_>) {
+    assert!(is_map_entry_pat_start(p));
+    let m = p.start();
+    p.eat(T![-]);
+    expressions::literal(p);
+    p.bump(T![:]);
+    pattern_top(p);
+    m.complete(p, MAP_ENTRY_PAT);
+}
+
+fn pat_list(p: &mut Parser<'_>, ket: SyntaxKind, mut element: impl FnMut(&mut Parser<'_>)) {
+    while !p.at(EOF) && !p.at(ket) {
+        element(p);
+        if !p.eat(T![,]) {
+            if p.at_ts(PAT_TOP_FIRST) {
+                p.error(format!("expected {:?}, got {:?}", T![,], p.current()));
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+// test record_pat
+// fn main() {
+//     let S { a, b: 1, ..rest } = s;
+//     let { ..rest, z } = s;
+//     let E::Bools { m } = e;
+//     let T { 0: x } = t;
+// }
+fn record_pat(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    record_pat_fields(p);
+    m.complete(p, RECORD_PAT)
+}
+
+fn record_pat_fields(p: &mut Parser<'_>) {
+    assert!(p.at(T!['{']));
+    p.bump(T!['{']);
+    while !p.at(EOF) && !p.at(T!['}']) {
+        let m = p.start();
+        attributes::outer_attrs(p);
+
+        match p.current() {
+            IDENT | INT_NUMBER if p.nth_at(1, T![:]) => {
+                name_ref_or_index(p);
+                p.bump(T![:]);
+                pattern(p);
+            }
+            T![..] => {
+                rest_pat(p);
+            }
+            IDENT => {
+                ident_pat(p);
+            }
+            T!['{'] => {
+                error_block(p, "expected ident");
+                m.abandon(p);
+                continue;
+            }
+            _ => {
+                p.err_and_bump("expected identifier");
+                m.abandon(p);
+                continue;
+            }
+        }
+        m.complete(p, RECORD_PAT_FIELD);
+
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+    p.expect(T!['}']);
+}
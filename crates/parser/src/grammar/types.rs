@@ -3,26 +3,243 @@ use super::*;
 pub(super) const TYPE_FIRST: TokenSet = paths::PATH_FIRST.union(TokenSet::new(&[
     T!['('],
     T!['['],
-    T![<],
-    T![!],
+    T!['{'],
     T![*],
-    T![&],
     T![_],
     T![fn],
-    T![for],
-    T![impl],
-    T![Self],
+    T![mut],
+    T![never],
 ]));
 
 pub(super) const TYPE_RECOVERY_SET: TokenSet = TokenSet::new(&[
     T![')'],
     T![>],
     T![,],
-    // test_err struct_field_recover
-    // struct S { f pub g: () }
+    // test_err enum_field_recover
+    // enum E { A { f pub g: i32 } }
     T![pub],
 ]);
 
 pub(crate) fn type_(p: &mut Parser<'_>) {
-    todo!()
+    type_with_unions_cond(p, true);
+}
+
+/// Parses a type that cannot be a bare union, which is needed wherever a `|`
+/// following the type means something else, e.g. in closure parameters.
+pub(super) fn type_no_union(p: &mut Parser<'_>) {
+    type_with_unions_cond(p, false);
+}
+
+// test union_type
+// type A = i32 | str;
+// type B = Foo? | Bar! | [i32];
+fn type_with_unions_cond(p: &mut Parser<'_>, allow_unions: bool) {
+    let Some(first) = postfix_type(p) else {
+        return;
+    };
+    if allow_unions && p.at(T![|]) {
+        let m = first.precede(p);
+        while p.eat(T![|]) {
+            postfix_type(p);
+        }
+        m.complete(p, UNION_TYPE);
+    }
+}
+
+// test postfix_type
+// type A = i32?;
+// type B = i32!;
+// type C = i32!?;
+fn postfix_type(p: &mut Parser<'_>) -> Option<CompletedMarker> {
+    let mut lhs = atom_type(p)?;
+    loop {
+        let kind = match p.current() {
+            T![?] => OPTION_TYPE,
+            T![!] => RESULT_TYPE,
+            _ => break,
+        };
+        let m = lhs.precede(p);
+        p.bump_any();
+        lhs = m.complete(p, kind);
+    }
+    Some(lhs)
+}
+
+fn atom_type(p: &mut Parser<'_>) -> Option<CompletedMarker> {
+    let m = match p.current() {
+        T!['('] => paren_or_tuple_type(p),
+        T!['['] => collection_type(p),
+        T!['{'] => record_type(p),
+        T![*] => unknown_type(p),
+        T![_] => infer_type(p),
+        T![fn] => fn_ptr_type(p),
+        T![mut] => mut_type(p),
+        T![never] => never_type(p),
+        _ if paths::is_path_start(p) => path_type(p),
+        _ => {
+            p.err_recover("expected type", TYPE_RECOVERY_SET);
+            return None;
+        }
+    };
+    Some(m)
+}
+
+pub(super) fn ascription(p: &mut Parser<'_>) {
+    assert!(p.at(T![:]));
+    p.bump(T![:]);
+    type_(p);
+}
+
+// test paren_or_tuple_type
+// type A = ();
+// type B = (i32);
+// type C = (i32,);
+// type D = (i32, str);
+fn paren_or_tuple_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+    let mut n_types: u32 = 0;
+    let mut trailing_comma: bool = false;
+    while !p.at(EOF) && !p.at(T![')']) {
+        n_types += 1;
+        type_(p);
+        if p.eat(T![,]) {
+            trailing_comma = true;
+        } else {
+            trailing_comma = false;
+            break;
+        }
+    }
+    p.expect(T![')']);
+
+    let kind = if n_types == 1 && !trailing_comma { PAREN_TYPE } else { TUPLE_TYPE };
+    m.complete(p, kind)
+}
+
+// test collection_type
+// type L = [i32];
+// type M = [str: i32];
+// type S = [:str];
+// type N = [[:i32]: [str]];
+fn collection_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['[']));
+    let m = p.start();
+    p.bump(T!['[']);
+    let kind = if p.eat(T![:]) {
+        type_(p);
+        SET_TYPE
+    } else {
+        type_(p);
+        if p.eat(T![:]) {
+            type_(p);
+            MAP_TYPE
+        } else {
+            LIST_TYPE
+        }
+    };
+    p.expect(T![']']);
+    m.complete(p, kind)
+}
+
+// test record_type
+// type P = { x: i32, y: i32 };
+// type E = {};
+fn record_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T!['{']));
+    let m = p.start();
+    delimited(
+        p,
+        T!['{'],
+        T!['}'],
+        T![,],
+        || "expected field".into(),
+        TokenSet::new(&[IDENT]).union(attributes::ATTRIBUTE_FIRST),
+        |p| {
+            if !p.at_ts(TokenSet::new(&[IDENT]).union(attributes::ATTRIBUTE_FIRST)) {
+                p.err_recover("expected field", TokenSet::new(&[T!['}'], T![,]]));
+                return false;
+            }
+            let m = p.start();
+            attributes::outer_attrs(p);
+            name(p);
+            if p.at(T![:]) {
+                ascription(p);
+            } else {
+                p.error("expected `:`");
+            }
+            m.complete(p, RECORD_TYPE_FIELD);
+            true
+        },
+    );
+    m.complete(p, RECORD_TYPE)
+}
+
+// test unknown_type
+// type A = *;
+fn unknown_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![*]));
+    let m = p.start();
+    p.bump(T![*]);
+    m.complete(p, UNKNOWN_TYPE)
+}
+
+// test infer_type
+// type A = _;
+fn infer_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![_]));
+    let m = p.start();
+    p.bump(T![_]);
+    m.complete(p, INFER_TYPE)
+}
+
+// test never_type
+// fn f() -> never {}
+fn never_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![never]));
+    let m = p.start();
+    p.bump(T![never]);
+    m.complete(p, NEVER_TYPE)
+}
+
+// test mut_type
+// type A = mut i32;
+// type B = mut [i32]?;
+fn mut_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![mut]));
+    let m = p.start();
+    p.bump(T![mut]);
+    postfix_type(p);
+    m.complete(p, MUT_TYPE)
+}
+
+// test fn_ptr_type
+// type A = fn();
+// type B = fn(i32) -> str;
+// type C = fn(x: i32, ...) -> i32 | str;
+fn fn_ptr_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(p.at(T![fn]));
+    let m = p.start();
+    p.bump(T![fn]);
+    if p.at(T!['(']) {
+        params::param_list_fn_ptr(p);
+    } else {
+        p.error("expected parameters");
+    }
+    // test fn_ptr_with_ret_type
+    // type F = fn() -> i32;
+    opt_ret_type(p);
+    m.complete(p, FN_PTR_TYPE)
+}
+
+// test path_type
+// type A = Foo;
+// type B = ::Foo;
+// type C = self::Foo;
+// type D = Foo::Bar<i32>;
+pub(super) fn path_type(p: &mut Parser<'_>) -> CompletedMarker {
+    assert!(paths::is_path_start(p));
+    let m = p.start();
+    paths::type_path(p);
+    m.complete(p, PATH_TYPE)
 }
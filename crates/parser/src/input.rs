@@ -16,18 +16,19 @@ type bits = u64;
 pub struct Input {
     kind: Vec<SyntaxKind>,
     joint: Vec<bits>,
-    contextual_kind: Vec<SyntaxKind>,
+    dotless_float: Vec<bits>,
 }
 
 /// `pub` impl used by callers to create `Tokens`.
 impl Input {
     #[inline]
     pub fn push(&mut self, kind: SyntaxKind) {
-        self.push_impl(kind, SyntaxKind::EOF)
-    }
-    #[inline]
-    pub fn push_ident(&mut self, contextual_kind: SyntaxKind) {
-        self.push_impl(SyntaxKind::IDENT, contextual_kind)
+        let idx = self.len();
+        if idx.is_multiple_of(bits::BITS as usize) {
+            self.joint.push(0);
+            self.dotless_float.push(0);
+        }
+        self.kind.push(kind);
     }
     /// Sets jointness for the last token we've pushed.
     ///
@@ -51,14 +52,13 @@ impl Input {
         let (idx, b_idx) = self.bit_index(n);
         self.joint[idx] |= 1 << b_idx;
     }
+    /// Marks the last token we've pushed as a float literal without a `.`
+    /// (e.g. `1e3`), which cannot be split into two field accesses.
     #[inline]
-    fn push_impl(&mut self, kind: SyntaxKind, contextual_kind: SyntaxKind) {
-        let idx = self.len();
-        if idx % (bits::BITS as usize) == 0 {
-            self.joint.push(0);
-        }
-        self.kind.push(kind);
-        self.contextual_kind.push(contextual_kind);
+    pub fn was_dotless_float(&mut self) {
+        let n = self.len() - 1;
+        let (idx, b_idx) = self.bit_index(n);
+        self.dotless_float[idx] |= 1 << b_idx;
     }
 }
 
@@ -67,13 +67,14 @@ impl Input {
     pub(crate) fn kind(&self, idx: usize) -> SyntaxKind {
         self.kind.get(idx).copied().unwrap_or(SyntaxKind::EOF)
     }
-    pub(crate) fn contextual_kind(&self, idx: usize) -> SyntaxKind {
-        self.contextual_kind.get(idx).copied().unwrap_or(SyntaxKind::EOF)
-    }
     pub(crate) fn is_joint(&self, n: usize) -> bool {
         let (idx, b_idx) = self.bit_index(n);
         self.joint[idx] & 1 << b_idx != 0
     }
+    pub(crate) fn is_dotless_float(&self, n: usize) -> bool {
+        let (idx, b_idx) = self.bit_index(n);
+        self.dotless_float[idx] & 1 << b_idx != 0
+    }
 }
 
 impl Input {
@@ -5,13 +5,26 @@ mod input;
 mod output;
 mod parser;
 mod reparser;
+mod shortcuts;
 mod syntax_kind;
 mod token_converter;
 mod token_set;
 mod tokenized_str;
-mod tokenizer_error;
+mod tokenizer_diagnostic;
 mod top_entry_point;
 
+#[cfg(test)]
+mod tests;
+
 pub(crate) use token_set::TokenSet;
 
-pub use crate::syntax_kind::{SyntaxKind, LAST_SYNTAX_KIND_TOKEN};
+pub use crate::{
+    input::Input,
+    output::{Output, Step},
+    reparser::Reparser,
+    shortcuts::StrStep,
+    syntax_kind::{SyntaxKind, LAST_SYNTAX_KIND_TOKEN},
+    tokenized_str::TokenizedStr,
+    tokenizer_diagnostic::{Severity, SuggestedFix, TokenizerDiagnostic, TokenizerDiagnosticCode},
+    top_entry_point::TopEntryPoint,
+};
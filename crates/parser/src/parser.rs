@@ -64,23 +64,7 @@ impl<'t> Parser<'t> {
     }
 
     pub(crate) fn nth_at(&self, n: usize, kind: SyntaxKind) -> bool {
-        match kind {
-            T![..] => self.at_composite2(n, T![.], T![.]),
-            T![::] => self.at_composite2(n, T![:], T![:]),
-            T![==] => self.at_composite2(n, T![=], T![=]),
-            T![=>] => self.at_composite2(n, T![=], T![>]),
-            T![<=] => self.at_composite2(n, T![<], T![=]),
-            T![!=] => self.at_composite2(n, T![!], T![=]),
-            T![->] => self.at_composite2(n, T![-], T![>]),
-
-            T![...] => self.at_composite3(n, T![.], T![.], T![.]),
-            T![..=] => self.at_composite3(n, T![.], T![.], T![=]),
-            T![===] => self.at_composite3(n, T![=], T![=], T![=]),
-            T![!==] => self.at_composite3(n, T![!], T![=], T![=]),
-            T![---] => self.at_composite3(n, T![-], T![-], T![-]),
-
-            _ => self.inp.kind(self.pos + n) == kind,
-        }
+        self.inp.kind(self.pos + n) == kind
     }
 
     /// Consume the next token if `kind` matches.
@@ -88,34 +72,28 @@ impl<'t> Parser<'t> {
         if !self.at(kind) {
             return false;
         }
-        let n_raw_tokens = match kind {
-            T![..] | T![::] | T![==] | T![=>] | T![<=] | T![!=] | T![->] => 2,
-            T![...] | T![..=] | T![===] | T![!==] | T![---] => 3,
-            _ => 1,
-        };
-        self.do_bump(kind, n_raw_tokens);
-        true
-    }
-
-    pub(crate) fn eat_contextual_kw(&mut self, kind: SyntaxKind) -> bool {
-        if !self.at_contextual_kw(kind) {
-            return false;
-        }
-        self.bump_remap(kind);
+        self.do_bump(kind, 1);
         true
     }
 
-    fn at_composite2(&self, n: usize, k1: SyntaxKind, k2: SyntaxKind) -> bool {
+    /// Checks if the nth token is `k1` immediately followed by `k2`, with no
+    /// trivia in between.
+    pub(crate) fn at_composite2(&self, n: usize, k1: SyntaxKind, k2: SyntaxKind) -> bool {
         self.inp.kind(self.pos + n) == k1
             && self.inp.kind(self.pos + n + 1) == k2
             && self.inp.is_joint(self.pos + n)
     }
 
-    fn at_composite3(&self, n: usize, k1: SyntaxKind, k2: SyntaxKind, k3: SyntaxKind) -> bool {
-        self.inp.kind(self.pos + n) == k1
-            && self.inp.kind(self.pos + n + 1) == k2
+    /// Like [Parser::at_composite2], but for three tokens, e.g. `>>=`.
+    pub(crate) fn at_composite3(
+        &self,
+        n: usize,
+        k1: SyntaxKind,
+        k2: SyntaxKind,
+        k3: SyntaxKind,
+    ) -> bool {
+        self.at_composite2(n, k1, k2)
             && self.inp.kind(self.pos + n + 2) == k3
-            && self.inp.is_joint(self.pos + n)
             && self.inp.is_joint(self.pos + n + 1)
     }
 
@@ -124,16 +102,6 @@ impl<'t> Parser<'t> {
         kinds.contains(self.current())
     }
 
-    /// Checks if the current token is contextual keyword `kw`.
-    pub(crate) fn at_contextual_kw(&self, kw: SyntaxKind) -> bool {
-        self.inp.contextual_kind(self.pos) == kw
-    }
-
-    /// Checks if the nth token is contextual keyword `kw`.
-    pub(crate) fn nth_at_contextual_kw(&self, n: usize, kw: SyntaxKind) -> bool {
-        self.inp.contextual_kind(self.pos + n) == kw
-    }
-
     /// Starts a new node in the syntax tree. All nodes and tokens
     /// consumed between the `start` and the corresponding `Marker::complete`
     /// belong to the same node.
@@ -157,9 +125,15 @@ impl<'t> Parser<'t> {
         self.do_bump(kind, 1);
     }
 
+    /// Checks if the current token is a float literal with a `.` in it, which
+    /// [`Parser::split_float`] can split into field accesses.
+    pub(crate) fn at_splittable_float(&self) -> bool {
+        self.at(SyntaxKind::FLOAT_NUMBER) && !self.inp.is_dotless_float(self.pos)
+    }
+
     /// Advances the parser by one token
     pub(crate) fn split_float(&mut self, mut marker: Marker) -> (bool, Marker) {
-        assert!(self.at(SyntaxKind::FLOAT_NUMBER));
+        assert!(self.at_splittable_float());
         // we have parse `<something>.`
         // `<something>`.0.1
         // here we need to insert an extra event
@@ -186,20 +160,6 @@ impl<'t> Parser<'t> {
         (ends_in_dot, marker)
     }
 
-    /// Advances the parser by one token, remapping its kind.
-    /// This is useful to create contextual keywords from
-    /// identifiers. For example, the lexer creates a `union`
-    /// *identifier* token, but the parser remaps it to the
-    /// `union` keyword, and keyword is what ends up in the
-    /// final tree.
-    pub(crate) fn bump_remap(&mut self, kind: SyntaxKind) {
-        if self.nth(0) == EOF {
-            // FIXME: panic!?
-            return;
-        }
-        self.do_bump(kind, 1);
-    }
-
     /// Emit error with the `message`
     /// FIXME: this should be much more fancy and support
     /// structured errors with spans and notes, like rustc
@@ -342,6 +302,19 @@ impl CompletedMarker {
         self
     }
 
+    /// Retags the already completed node with `kind`, for when the kind of a
+    /// node can only be decided once its siblings have been parsed.
+    pub(crate) fn change_kind(&mut self, p: &mut Parser<'_>, kind: SyntaxKind) {
+        let idx = self.pos as usize;
+        match &mut p.events[idx] {
+            Event::Start { kind: slot, .. } => {
+                *slot = kind;
+            }
+            _ => unreachable!(),
+        }
+        self.kind = kind;
+    }
+
     pub(crate) fn kind(&self) -> SyntaxKind {
         self.kind
     }
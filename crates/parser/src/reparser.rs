@@ -1,4 +1,4 @@
-use crate::{event, input::Input, output::Output, parser, SyntaxKind};
+use crate::{event, grammar, input::Input, output::Output, parser, SyntaxKind};
 
 /// A parsing function for a specific braced-block.
 pub struct Reparser(fn(&mut parser::Parser<'_>));
@@ -10,8 +10,7 @@ impl Reparser {
         first_child: Option<SyntaxKind>,
         parent: Option<SyntaxKind>,
     ) -> Option<Reparser> {
-        todo!()
-        // grammar::reparser(node, first_child, parent).map(Reparser)
+        grammar::reparser(node, first_child, parent).map(Reparser)
     }
 
     /// Re-parse given tokens using this `Reparser`.
@@ -0,0 +1,292 @@
+//! Shortcuts that span the tokenizer and the parser, or the parser and the
+//! syntax tree builder.
+//!
+//! The parser itself never sees trivia, so [`TokenizedStr::to_input`] strips
+//! whitespace and comments out of the token stream while recording which tokens
+//! were glued together. [`TokenizedStr::intersperse_trivia`] then does the
+//! opposite: it walks the parser's [`Output`] and splices the trivia back in,
+//! taking care to attach leading doc comments to the node they document.
+
+use std::mem;
+
+use crate::{
+    input::Input,
+    output::{Output, Step},
+    tokenized_str::TokenizedStr,
+    SyntaxKind::{self, *},
+};
+
+/// A single step of the syntax tree reconstructed from a [`TokenizedStr`] and
+/// the parser's [`Output`].
+#[derive(Debug)]
+pub enum StrStep<'a> {
+    Token { kind: SyntaxKind, text: &'a str },
+    Enter { kind: SyntaxKind },
+    Exit,
+    Error { msg: &'a str, pos: usize },
+}
+
+impl TokenizedStr<'_> {
+    /// Converts this tokenized string into parser [`Input`], dropping trivia
+    /// and recording which tokens are joint with the next one.
+    pub fn to_input(&self) -> Input {
+        let _p = tracing::info_span!("TokenizedStr::to_input").entered();
+        let mut res = Input::default();
+        let mut was_joint = false;
+        for i in 0..self.len() {
+            let kind = self.kind(i);
+            if kind.is_trivia() {
+                was_joint = false
+            } else {
+                if kind == IDENT {
+                    res.push(IDENT);
+                } else {
+                    if was_joint {
+                        res.was_joint();
+                    }
+                    res.push(kind);
+                    // Tag the token as joint if it is float with a fractional
+                    // part; we use this jointness to inform the parser about
+                    // what token split event to emit when we encounter a float
+                    // literal in a field access. A float that ends in `.` must
+                    // never be joint, not even with the token after it.
+                    if kind == FLOAT_NUMBER {
+                        let text = self.text(i);
+                        if !text.contains('.') {
+                            res.was_dotless_float();
+                        } else if !text.ends_with('.') {
+                            res.was_joint();
+                        } else {
+                            was_joint = false;
+                            continue;
+                        }
+                    }
+                }
+
+                was_joint = true;
+            }
+        }
+        res
+    }
+
+    /// Feeds the steps of the syntax tree described by `output` into `sink`,
+    /// re-inserting the trivia that [`TokenizedStr::to_input`] dropped.
+    ///
+    /// Returns `true` if the whole of the tokenized string was consumed.
+    pub fn intersperse_trivia(&self, output: &Output, sink: &mut dyn FnMut(StrStep<'_>)) -> bool {
+        let mut builder = Builder { tokenized: self, pos: 0, state: State::PendingEnter, sink };
+
+        for event in output.iter() {
+            match event {
+                Step::Token { kind, n_input_tokens: n_raw_tokens } => {
+                    builder.token(kind, n_raw_tokens)
+                }
+                Step::FloatSplit { ends_in_dot: has_pseudo_dot } => {
+                    builder.float_split(has_pseudo_dot)
+                }
+                Step::Enter { kind } => builder.enter(kind),
+                Step::Exit => builder.exit(),
+                Step::Error { msg } => {
+                    let text_pos = builder.tokenized.text_start(builder.pos);
+                    (builder.sink)(StrStep::Error { msg, pos: text_pos });
+                }
+            }
+        }
+
+        match mem::replace(&mut builder.state, State::Normal) {
+            State::PendingExit => {
+                builder.eat_trivias();
+                (builder.sink)(StrStep::Exit);
+            }
+            State::PendingEnter | State::Normal => unreachable!(),
+        }
+
+        // is_eof?
+        builder.pos == builder.tokenized.len()
+    }
+}
+
+struct Builder<'a, 'b> {
+    tokenized: &'a TokenizedStr<'a>,
+    pos: usize,
+    state: State,
+    sink: &'b mut dyn FnMut(StrStep<'_>),
+}
+
+enum State {
+    PendingEnter,
+    Normal,
+    PendingExit,
+}
+
+impl Builder<'_, '_> {
+    fn token(&mut self, kind: SyntaxKind, n_tokens: u8) {
+        match mem::replace(&mut self.state, State::Normal) {
+            State::PendingEnter => unreachable!(),
+            State::PendingExit => (self.sink)(StrStep::Exit),
+            State::Normal => (),
+        }
+        self.eat_trivias();
+        self.do_token(kind, n_tokens as usize);
+    }
+
+    fn float_split(&mut self, has_pseudo_dot: bool) {
+        match mem::replace(&mut self.state, State::Normal) {
+            State::PendingEnter => unreachable!(),
+            State::PendingExit => (self.sink)(StrStep::Exit),
+            State::Normal => (),
+        }
+        self.eat_trivias();
+        self.do_float_split(has_pseudo_dot);
+    }
+
+    fn enter(&mut self, kind: SyntaxKind) {
+        match mem::replace(&mut self.state, State::Normal) {
+            State::PendingEnter => {
+                (self.sink)(StrStep::Enter { kind });
+                // No need to attach trivias to previous node: there is no
+                // previous node.
+                return;
+            }
+            State::PendingExit => (self.sink)(StrStep::Exit),
+            State::Normal => (),
+        }
+
+        // Sections only group items, so any trivia in front of them is left
+        // for their first item to claim.
+        if matches!(kind, CODE_SECTION | META_SECTION) {
+            (self.sink)(StrStep::Enter { kind });
+            return;
+        }
+
+        let n_trivias = (self.pos..self.tokenized.len())
+            .take_while(|&it| self.tokenized.kind(it).is_trivia())
+            .count();
+        let leading_trivias = self.pos..self.pos + n_trivias;
+        let n_attached_trivias = n_attached_trivias(
+            kind,
+            leading_trivias.rev().map(|it| (self.tokenized.kind(it), self.tokenized.text(it))),
+        );
+        self.eat_n_trivias(n_trivias - n_attached_trivias);
+        (self.sink)(StrStep::Enter { kind });
+        self.eat_n_trivias(n_attached_trivias);
+    }
+
+    fn exit(&mut self) {
+        match mem::replace(&mut self.state, State::PendingExit) {
+            State::PendingEnter => unreachable!(),
+            State::PendingExit => (self.sink)(StrStep::Exit),
+            State::Normal => (),
+        }
+    }
+
+    fn eat_trivias(&mut self) {
+        while self.pos < self.tokenized.len() {
+            let kind = self.tokenized.kind(self.pos);
+            if !kind.is_trivia() {
+                break;
+            }
+            self.do_token(kind, 1);
+        }
+    }
+
+    fn eat_n_trivias(&mut self, n: usize) {
+        for _ in 0..n {
+            let kind = self.tokenized.kind(self.pos);
+            assert!(kind.is_trivia());
+            self.do_token(kind, 1);
+        }
+    }
+
+    fn do_token(&mut self, kind: SyntaxKind, n_tokens: usize) {
+        let text = &self.tokenized.range_text(self.pos..self.pos + n_tokens);
+        self.pos += n_tokens;
+        (self.sink)(StrStep::Token { kind, text });
+    }
+
+    fn do_float_split(&mut self, has_pseudo_dot: bool) {
+        let text = &self.tokenized.range_text(self.pos..self.pos + 1);
+
+        match text.split_once('.') {
+            Some((left, right)) => {
+                assert!(!left.is_empty());
+                (self.sink)(StrStep::Enter { kind: SyntaxKind::NAME_REF });
+                (self.sink)(StrStep::Token { kind: SyntaxKind::INT_NUMBER, text: left });
+                (self.sink)(StrStep::Exit);
+
+                // here we move the exit up, the original exit has been deleted in process
+                (self.sink)(StrStep::Exit);
+
+                (self.sink)(StrStep::Token { kind: SyntaxKind::DOT, text: "." });
+
+                if has_pseudo_dot {
+                    assert!(right.is_empty(), "{left}.{right}");
+                    self.state = State::Normal;
+                } else {
+                    assert!(!right.is_empty(), "{left}.{right}");
+                    (self.sink)(StrStep::Enter { kind: SyntaxKind::NAME_REF });
+                    (self.sink)(StrStep::Token { kind: SyntaxKind::INT_NUMBER, text: right });
+                    (self.sink)(StrStep::Exit);
+
+                    // the parser creates an unbalanced start node, we are required to close it here
+                    self.state = State::PendingExit;
+                }
+            }
+            None => unreachable!(),
+        }
+        self.pos += 1;
+    }
+}
+
+/// Returns the number of trivia tokens directly preceding a node of the given
+/// `kind` that should be attached to that node rather than its parent.
+///
+/// Comments that sit right above an item (with no blank line in between) are
+/// considered to be its documentation, so they belong to the item.
+fn n_attached_trivias<'a>(
+    kind: SyntaxKind,
+    trivias: impl Iterator<Item = (SyntaxKind, &'a str)>,
+) -> usize {
+    match kind {
+        ENUM | FN | IMPL | RECORD_FIELD | RECORD_TYPE_FIELD | TRAIT | TUPLE_FIELD | TYPE_ALIAS
+        | VARIANT | DEP => {
+            let mut res = 0;
+            let mut trivias = trivias.enumerate().peekable();
+
+            while let Some((i, (kind, text))) = trivias.next() {
+                match kind {
+                    WHITESPACE if text.contains("\n\n") => {
+                        // we check whether the next token is a doc-comment
+                        // and skip the whitespace in this case
+                        if let Some((COMMENT, peek_text)) = trivias.peek().map(|(_, pair)| pair) {
+                            if is_outer(peek_text) {
+                                continue;
+                            }
+                        }
+                        break;
+                    }
+                    COMMENT => {
+                        if is_inner(text) {
+                            break;
+                        }
+                        res = i + 1;
+                    }
+                    _ => (),
+                }
+            }
+            res
+        }
+        _ => 0,
+    }
+}
+
+fn is_outer(text: &str) -> bool {
+    if text.starts_with("////") || text.starts_with("/***") {
+        return false;
+    }
+    text.starts_with("///") || text.starts_with("/**")
+}
+
+fn is_inner(text: &str) -> bool {
+    text.starts_with("//!") || text.starts_with("/*!")
+}
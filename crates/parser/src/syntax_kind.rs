@@ -24,7 +24,7 @@ impl From<SyntaxKind> for u16 {
 impl SyntaxKind {
     #[inline]
     pub fn is_trivia(self) -> bool {
-        matches!(self, SyntaxKind::WHITESPACE | SyntaxKind::COMMENT)
+        matches!(self, SyntaxKind::WHITESPACE | SyntaxKind::COMMENT | SyntaxKind::SHEBANG)
     }
 
     /// Returns true if this is an identifier or a keyword.
@@ -0,0 +1,1974 @@
+//! Tests that run the parser over snippets of Denim source and compare the
+//! resulting concrete syntax tree against an indented dump.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use expect_test::{expect, expect_file, Expect};
+
+use crate::{StrStep, SuggestedFix, TokenizedStr, TokenizerDiagnostic, TopEntryPoint};
+
+/// Parses `text` as `entry` and dumps the resulting tree and errors.
+fn parse(entry: TopEntryPoint, text: &str) -> String {
+    let tokenized = match entry {
+        TopEntryPoint::SourceFile => TokenizedStr::new(text),
+        _ => TokenizedStr::new_fragment(text),
+    };
+    let input = tokenized.to_input();
+    let output = entry.parse(&input);
+
+    let mut buf = String::new();
+    let mut errors = Vec::new();
+    let mut indent = String::new();
+    tokenized.intersperse_trivia(&output, &mut |step| match step {
+        StrStep::Token { kind, text } => {
+            writeln!(buf, "{indent}{kind:?} {text:?}").unwrap();
+        }
+        StrStep::Enter { kind } => {
+            writeln!(buf, "{indent}{kind:?}").unwrap();
+            indent.push_str("  ");
+        }
+        StrStep::Exit => {
+            indent.pop();
+            indent.pop();
+        }
+        StrStep::Error { msg, pos } => errors.push(format!("error {pos}: {msg}\n")),
+    });
+
+    for err in tokenized.errors() {
+        errors.push(format!("error {}: {}\n", err.range.start, err.message));
+    }
+
+    for warning in tokenized.warnings() {
+        errors.push(format!("warning {}: {}\n", warning.range.start, warning.message));
+    }
+
+    for error in errors {
+        buf.push_str(&error);
+    }
+    buf
+}
+
+fn check(entry: TopEntryPoint, text: &str, expect: Expect) {
+    expect.assert_eq(&parse(entry, text));
+}
+
+/// Parses every fixture that `cargo xtask gen parser-tests` extracted from the
+/// inline tests of the grammar, comparing each dump against the `.rast` file
+/// next to the fixture.
+fn check_inline_tests(dir: &str, expect_errors: bool) {
+    let dir =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../syntax/test_data/parser/inline").join(dir);
+    let mut fixtures = fs::read_dir(&dir)
+        .unwrap()
+        .map(|it| it.unwrap().path())
+        .filter(|it| it.extension().is_some_and(|it| it == stdx::DENIM_EXTENSION))
+        .collect::<Vec<PathBuf>>();
+    fixtures.sort();
+    assert!(!fixtures.is_empty(), "no fixtures in {}", dir.display());
+
+    for fixture in fixtures {
+        let text = fs::read_to_string(&fixture).unwrap();
+        let dump = parse(TopEntryPoint::SourceFile, &text);
+        let has_errors = dump.lines().any(|it| it.starts_with("error "));
+        assert_eq!(
+            has_errors,
+            expect_errors,
+            "{} {}:\n{dump}",
+            fixture.display(),
+            if expect_errors { "should have errors" } else { "should not have errors" },
+        );
+        expect_file![fixture.with_extension("rast")].assert_eq(&dump);
+    }
+}
+
+#[test]
+fn inline_tests_ok() {
+    check_inline_tests("ok", false);
+}
+
+#[test]
+fn inline_tests_err() {
+    check_inline_tests("err", true);
+}
+
+#[test]
+fn source_file_items() {
+    check(
+        TopEntryPoint::SourceFile,
+        r#"
+/// Documented.
+pub fn area<T>(self, scale: T) -> T where T: Num {}
+
+enum Shape { Circle(f64), Rect { w: f64, h: f64 } }
+
+trait Draw: Display { fn draw(self); }
+
+impl Draw for Shape {}
+
+type Point = Pair<f64>;
+"#,
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                WHITESPACE "\n"
+                FN
+                  COMMENT "/// Documented."
+                  WHITESPACE "\n"
+                  VISIBILITY
+                    PUB_KW "pub"
+                  WHITESPACE " "
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "area"
+                  GENERIC_PARAM_LIST
+                    L_ANGLE "<"
+                    TYPE_PARAM
+                      NAME
+                        IDENT "T"
+                    R_ANGLE ">"
+                  PARAM_LIST
+                    L_PAREN "("
+                    SELF_PARAM
+                      NAME
+                        SELF_KW "self"
+                    COMMA ","
+                    WHITESPACE " "
+                    PARAM
+                      IDENT_PAT
+                        NAME
+                          IDENT "scale"
+                      COLON ":"
+                      WHITESPACE " "
+                      PATH_TYPE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "T"
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  RET_TYPE
+                    THIN_ARROW "->"
+                    WHITESPACE " "
+                    PATH_TYPE
+                      PATH
+                        PATH_SEGMENT
+                          NAME_REF
+                            IDENT "T"
+                  WHITESPACE " "
+                  WHERE_CLAUSE
+                    WHERE_KW "where"
+                    WHITESPACE " "
+                    WHERE_PRED
+                      PATH_TYPE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "T"
+                      COLON ":"
+                      WHITESPACE " "
+                      TYPE_BOUND_LIST
+                        TYPE_BOUND
+                          PATH_TYPE
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "Num"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      R_CURLY "}"
+                WHITESPACE "\n\n"
+                ENUM
+                  ENUM_KW "enum"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "Shape"
+                  WHITESPACE " "
+                  VARIANT_LIST
+                    L_CURLY "{"
+                    WHITESPACE " "
+                    VARIANT
+                      NAME
+                        IDENT "Circle"
+                      TUPLE_FIELD_LIST
+                        L_PAREN "("
+                        TUPLE_FIELD
+                          PATH_TYPE
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "f64"
+                        R_PAREN ")"
+                    COMMA ","
+                    WHITESPACE " "
+                    VARIANT
+                      NAME
+                        IDENT "Rect"
+                      WHITESPACE " "
+                      RECORD_FIELD_LIST
+                        L_CURLY "{"
+                        WHITESPACE " "
+                        RECORD_FIELD
+                          NAME
+                            IDENT "w"
+                          COLON ":"
+                          WHITESPACE " "
+                          PATH_TYPE
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "f64"
+                        COMMA ","
+                        WHITESPACE " "
+                        RECORD_FIELD
+                          NAME
+                            IDENT "h"
+                          COLON ":"
+                          WHITESPACE " "
+                          PATH_TYPE
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "f64"
+                        WHITESPACE " "
+                        R_CURLY "}"
+                    WHITESPACE " "
+                    R_CURLY "}"
+                WHITESPACE "\n\n"
+                TRAIT
+                  TRAIT_KW "trait"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "Draw"
+                  COLON ":"
+                  WHITESPACE " "
+                  TYPE_BOUND_LIST
+                    TYPE_BOUND
+                      PATH_TYPE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "Display"
+                  WHITESPACE " "
+                  ASSOC_ITEM_LIST
+                    L_CURLY "{"
+                    WHITESPACE " "
+                    FN
+                      FN_KW "fn"
+                      WHITESPACE " "
+                      NAME
+                        IDENT "draw"
+                      PARAM_LIST
+                        L_PAREN "("
+                        SELF_PARAM
+                          NAME
+                            SELF_KW "self"
+                        R_PAREN ")"
+                      SEMICOLON ";"
+                    WHITESPACE " "
+                    R_CURLY "}"
+                WHITESPACE "\n\n"
+                IMPL
+                  IMPL_KW "impl"
+                  WHITESPACE " "
+                  PATH_TYPE
+                    PATH
+                      PATH_SEGMENT
+                        NAME_REF
+                          IDENT "Draw"
+                  WHITESPACE " "
+                  FOR_KW "for"
+                  WHITESPACE " "
+                  PATH_TYPE
+                    PATH
+                      PATH_SEGMENT
+                        NAME_REF
+                          IDENT "Shape"
+                  WHITESPACE " "
+                  ASSOC_ITEM_LIST
+                    L_CURLY "{"
+                    R_CURLY "}"
+                WHITESPACE "\n\n"
+                TYPE_ALIAS
+                  TYPE_KW "type"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "Point"
+                  WHITESPACE " "
+                  EQ "="
+                  WHITESPACE " "
+                  PATH_TYPE
+                    PATH
+                      PATH_SEGMENT
+                        NAME_REF
+                          IDENT "Pair"
+                        GENERIC_ARG_LIST
+                          L_ANGLE "<"
+                          TYPE_ARG
+                            PATH_TYPE
+                              PATH
+                                PATH_SEGMENT
+                                  NAME_REF
+                                    IDENT "f64"
+                          R_ANGLE ">"
+                  SEMICOLON ";"
+              WHITESPACE "\n"
+        "#]],
+    );
+}
+
+#[test]
+fn item_recovery() {
+    check(
+        TopEntryPoint::SourceFile,
+        "fn f( {} 92 enum E { 1 } pub",
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "f"
+                  PARAM_LIST
+                    L_PAREN "("
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      R_CURLY "}"
+                WHITESPACE " "
+                ERROR
+                  INT_NUMBER "92"
+                WHITESPACE " "
+                ENUM
+                  ENUM_KW "enum"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "E"
+                  WHITESPACE " "
+                  VARIANT_LIST
+                    L_CURLY "{"
+                    WHITESPACE " "
+                    ERROR
+                      INT_NUMBER "1"
+                    WHITESPACE " "
+                    R_CURLY "}"
+                WHITESPACE " "
+                ERROR
+                  VISIBILITY
+                    PUB_KW "pub"
+            error 5: expected value parameter
+            error 5: expected R_PAREN
+            error 9: expected an item
+            error 21: expected enum variant
+            error 28: expected an item after visibility
+        "#]],
+    );
+}
+
+#[test]
+fn expressions() {
+    check(
+        TopEntryPoint::SourceFile,
+        r#"
+fn main() {
+    let x = 1 + 2 * 3 == 7 and not done or y <= 10;
+    greet(name: "denim", 42).await.try;
+    40.add(&other, 2).as(u64);
+    fetch().async.match { _ => 0 }
+}
+"#,
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                WHITESPACE "\n"
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "main"
+                  PARAM_LIST
+                    L_PAREN "("
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      WHITESPACE "\n    "
+                      LET_STMT
+                        LET_KW "let"
+                        WHITESPACE " "
+                        IDENT_PAT
+                          NAME
+                            IDENT "x"
+                        WHITESPACE " "
+                        EQ "="
+                        WHITESPACE " "
+                        BIN_EXPR
+                          BIN_EXPR
+                            BIN_EXPR
+                              BIN_EXPR
+                                LITERAL
+                                  INT_NUMBER "1"
+                                WHITESPACE " "
+                                PLUS "+"
+                                WHITESPACE " "
+                                BIN_EXPR
+                                  LITERAL
+                                    INT_NUMBER "2"
+                                  WHITESPACE " "
+                                  STAR "*"
+                                  WHITESPACE " "
+                                  LITERAL
+                                    INT_NUMBER "3"
+                              WHITESPACE " "
+                              EQEQ "=="
+                              WHITESPACE " "
+                              LITERAL
+                                INT_NUMBER "7"
+                            WHITESPACE " "
+                            AND_KW "and"
+                            WHITESPACE " "
+                            NOT_EXPR
+                              NOT_KW "not"
+                              WHITESPACE " "
+                              PATH_EXPR
+                                PATH
+                                  PATH_SEGMENT
+                                    NAME_REF
+                                      IDENT "done"
+                          WHITESPACE " "
+                          OR_KW "or"
+                          WHITESPACE " "
+                          BIN_EXPR
+                            PATH_EXPR
+                              PATH
+                                PATH_SEGMENT
+                                  NAME_REF
+                                    IDENT "y"
+                            WHITESPACE " "
+                            LTEQ "<="
+                            WHITESPACE " "
+                            LITERAL
+                              INT_NUMBER "10"
+                        SEMICOLON ";"
+                      WHITESPACE "\n    "
+                      EXPR_STMT
+                        TRY_EXPR
+                          AWAIT_EXPR
+                            CALL_EXPR
+                              PATH_EXPR
+                                PATH
+                                  PATH_SEGMENT
+                                    NAME_REF
+                                      IDENT "greet"
+                              ARG_LIST
+                                L_PAREN "("
+                                ARG
+                                  NAME
+                                    IDENT "name"
+                                  COLON ":"
+                                  WHITESPACE " "
+                                  LITERAL
+                                    STRING "\"denim\""
+                                COMMA ","
+                                WHITESPACE " "
+                                ARG
+                                  LITERAL
+                                    INT_NUMBER "42"
+                                R_PAREN ")"
+                            DOT "."
+                            AWAIT_KW "await"
+                          DOT "."
+                          TRY_KW "try"
+                        SEMICOLON ";"
+                      WHITESPACE "\n    "
+                      EXPR_STMT
+                        CAST_EXPR
+                          METHOD_CALL_EXPR
+                            LITERAL
+                              INT_NUMBER "40"
+                            DOT "."
+                            NAME_REF
+                              IDENT "add"
+                            METHOD_CALL_ARG_LIST
+                              L_PAREN "("
+                              MAYBE_SELFIFIED_ARG
+                                AMP "&"
+                                NAME
+                                  IDENT "other"
+                              COMMA ","
+                              WHITESPACE " "
+                              ARG
+                                LITERAL
+                                  INT_NUMBER "2"
+                              R_PAREN ")"
+                          DOT "."
+                          AS_KW "as"
+                          L_PAREN "("
+                          PATH_TYPE
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "u64"
+                          R_PAREN ")"
+                        SEMICOLON ";"
+                      WHITESPACE "\n    "
+                      MATCH_EXPR
+                        ASYNC_EXPR
+                          CALL_EXPR
+                            PATH_EXPR
+                              PATH
+                                PATH_SEGMENT
+                                  NAME_REF
+                                    IDENT "fetch"
+                            ARG_LIST
+                              L_PAREN "("
+                              R_PAREN ")"
+                          DOT "."
+                          ASYNC_KW "async"
+                        DOT "."
+                        MATCH_KW "match"
+                        WHITESPACE " "
+                        MATCH_ARM_LIST
+                          L_CURLY "{"
+                          WHITESPACE " "
+                          MATCH_ARM
+                            WILDCARD_PAT
+                              UNDERSCORE "_"
+                            WHITESPACE " "
+                            FAT_ARROW "=>"
+                            WHITESPACE " "
+                            LITERAL
+                              INT_NUMBER "0"
+                          WHITESPACE " "
+                          R_CURLY "}"
+                      WHITESPACE "\n"
+                      R_CURLY "}"
+              WHITESPACE "\n"
+        "#]],
+    );
+}
+
+#[test]
+fn expression_recovery() {
+    check(
+        TopEntryPoint::SourceFile,
+        r#"
+fn main() {
+    let x = 1 + ;
+    foo(a, &b);
+    x.;
+}
+"#,
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                WHITESPACE "\n"
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "main"
+                  PARAM_LIST
+                    L_PAREN "("
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      WHITESPACE "\n    "
+                      LET_STMT
+                        LET_KW "let"
+                        WHITESPACE " "
+                        IDENT_PAT
+                          NAME
+                            IDENT "x"
+                        WHITESPACE " "
+                        EQ "="
+                        WHITESPACE " "
+                        BIN_EXPR
+                          LITERAL
+                            INT_NUMBER "1"
+                          WHITESPACE " "
+                          PLUS "+"
+                        WHITESPACE " "
+                        SEMICOLON ";"
+                      WHITESPACE "\n    "
+                      EXPR_STMT
+                        CALL_EXPR
+                          PATH_EXPR
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "foo"
+                          ARG_LIST
+                            L_PAREN "("
+                            ARG
+                              PATH_EXPR
+                                PATH
+                                  PATH_SEGMENT
+                                    NAME_REF
+                                      IDENT "a"
+                            COMMA ","
+                            WHITESPACE " "
+                            ERROR
+                              AMP "&"
+                              NAME
+                                IDENT "b"
+                            R_PAREN ")"
+                        SEMICOLON ";"
+                      WHITESPACE "\n    "
+                      EXPR_STMT
+                        FIELD_EXPR
+                          PATH_EXPR
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "x"
+                          DOT "."
+                        SEMICOLON ";"
+                      WHITESPACE "\n"
+                      R_CURLY "}"
+              WHITESPACE "\n"
+            error 28: expected expression
+            error 42: only method calls can have a selfified argument
+            error 53: expected field name, number or suffix keyword
+        "#]],
+    );
+}
+
+#[test]
+fn excluded_operators() {
+    // These are parsed so that validation can suggest what to use instead.
+    check(
+        TopEntryPoint::Expr,
+        "a >= b << c | d % e",
+        expect![[r#"
+        BIN_EXPR
+          PATH_EXPR
+            PATH
+              PATH_SEGMENT
+                NAME_REF
+                  IDENT "a"
+          WHITESPACE " "
+          R_ANGLE ">"
+          EQ "="
+          WHITESPACE " "
+          BIN_EXPR
+            BIN_EXPR
+              PATH_EXPR
+                PATH
+                  PATH_SEGMENT
+                    NAME_REF
+                      IDENT "b"
+              WHITESPACE " "
+              L_ANGLE "<"
+              L_ANGLE "<"
+              WHITESPACE " "
+              PATH_EXPR
+                PATH
+                  PATH_SEGMENT
+                    NAME_REF
+                      IDENT "c"
+            WHITESPACE " "
+            PIPE "|"
+            WHITESPACE " "
+            BIN_EXPR
+              PATH_EXPR
+                PATH
+                  PATH_SEGMENT
+                    NAME_REF
+                      IDENT "d"
+              WHITESPACE " "
+              PERCENT "%"
+              WHITESPACE " "
+              PATH_EXPR
+                PATH
+                  PATH_SEGMENT
+                    NAME_REF
+                      IDENT "e"
+    "#]],
+    );
+    check(
+        TopEntryPoint::Expr,
+        "a += b >> 1 &= c",
+        expect![[r#"
+        BIN_EXPR
+          PATH_EXPR
+            PATH
+              PATH_SEGMENT
+                NAME_REF
+                  IDENT "a"
+          WHITESPACE " "
+          PLUS "+"
+          EQ "="
+          WHITESPACE " "
+          BIN_EXPR
+            BIN_EXPR
+              PATH_EXPR
+                PATH
+                  PATH_SEGMENT
+                    NAME_REF
+                      IDENT "b"
+              WHITESPACE " "
+              R_ANGLE ">"
+              R_ANGLE ">"
+              WHITESPACE " "
+              LITERAL
+                INT_NUMBER "1"
+            WHITESPACE " "
+            AMP "&"
+            EQ "="
+            WHITESPACE " "
+            PATH_EXPR
+              PATH
+                PATH_SEGMENT
+                  NAME_REF
+                    IDENT "c"
+    "#]],
+    );
+}
+
+#[test]
+fn mut_params() {
+    check(
+        TopEntryPoint::SourceFile,
+        "fn f(mut a: i32) { |mut b| b; }",
+        expect![[r#"
+        SOURCE_FILE
+          CODE_SECTION
+            FN
+              FN_KW "fn"
+              WHITESPACE " "
+              NAME
+                IDENT "f"
+              PARAM_LIST
+                L_PAREN "("
+                PARAM
+                  MUT_KW "mut"
+                  WHITESPACE " "
+                  IDENT_PAT
+                    NAME
+                      IDENT "a"
+                  COLON ":"
+                  WHITESPACE " "
+                  PATH_TYPE
+                    PATH
+                      PATH_SEGMENT
+                        NAME_REF
+                          IDENT "i32"
+                R_PAREN ")"
+              WHITESPACE " "
+              BLOCK_EXPR
+                STMT_LIST
+                  L_CURLY "{"
+                  WHITESPACE " "
+                  EXPR_STMT
+                    CLOSURE_EXPR
+                      PARAM_LIST
+                        PIPE "|"
+                        PARAM
+                          MUT_KW "mut"
+                          WHITESPACE " "
+                          IDENT_PAT
+                            NAME
+                              IDENT "b"
+                        PIPE "|"
+                      WHITESPACE " "
+                      PATH_EXPR
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "b"
+                    SEMICOLON ";"
+                  WHITESPACE " "
+                  R_CURLY "}"
+    "#]],
+    );
+}
+
+#[test]
+fn types() {
+    check(
+        TopEntryPoint::SourceFile,
+        r#"
+fn lookup(keys: [:str], scores: [str: i32]?) -> Score! | never {}
+type Handler = fn(mut { id: i32 }, *) -> (i32, _);
+"#,
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                WHITESPACE "\n"
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "lookup"
+                  PARAM_LIST
+                    L_PAREN "("
+                    PARAM
+                      IDENT_PAT
+                        NAME
+                          IDENT "keys"
+                      COLON ":"
+                      WHITESPACE " "
+                      SET_TYPE
+                        L_BRACK "["
+                        COLON ":"
+                        PATH_TYPE
+                          PATH
+                            PATH_SEGMENT
+                              NAME_REF
+                                IDENT "str"
+                        R_BRACK "]"
+                    COMMA ","
+                    WHITESPACE " "
+                    PARAM
+                      IDENT_PAT
+                        NAME
+                          IDENT "scores"
+                      COLON ":"
+                      WHITESPACE " "
+                      OPTION_TYPE
+                        MAP_TYPE
+                          L_BRACK "["
+                          PATH_TYPE
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "str"
+                          COLON ":"
+                          WHITESPACE " "
+                          PATH_TYPE
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "i32"
+                          R_BRACK "]"
+                        QUESTION "?"
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  RET_TYPE
+                    THIN_ARROW "->"
+                    WHITESPACE " "
+                    UNION_TYPE
+                      RESULT_TYPE
+                        PATH_TYPE
+                          PATH
+                            PATH_SEGMENT
+                              NAME_REF
+                                IDENT "Score"
+                        BANG "!"
+                      WHITESPACE " "
+                      PIPE "|"
+                      WHITESPACE " "
+                      NEVER_TYPE
+                        NEVER_KW "never"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      R_CURLY "}"
+                WHITESPACE "\n"
+                TYPE_ALIAS
+                  TYPE_KW "type"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "Handler"
+                  WHITESPACE " "
+                  EQ "="
+                  WHITESPACE " "
+                  FN_PTR_TYPE
+                    FN_KW "fn"
+                    PARAM_LIST
+                      L_PAREN "("
+                      PARAM
+                        MUT_TYPE
+                          MUT_KW "mut"
+                          WHITESPACE " "
+                          RECORD_TYPE
+                            L_CURLY "{"
+                            WHITESPACE " "
+                            RECORD_TYPE_FIELD
+                              NAME
+                                IDENT "id"
+                              COLON ":"
+                              WHITESPACE " "
+                              PATH_TYPE
+                                PATH
+                                  PATH_SEGMENT
+                                    NAME_REF
+                                      IDENT "i32"
+                            WHITESPACE " "
+                            R_CURLY "}"
+                      COMMA ","
+                      WHITESPACE " "
+                      PARAM
+                        UNKNOWN_TYPE
+                          STAR "*"
+                      R_PAREN ")"
+                    WHITESPACE " "
+                    RET_TYPE
+                      THIN_ARROW "->"
+                      WHITESPACE " "
+                      TUPLE_TYPE
+                        L_PAREN "("
+                        PATH_TYPE
+                          PATH
+                            PATH_SEGMENT
+                              NAME_REF
+                                IDENT "i32"
+                        COMMA ","
+                        WHITESPACE " "
+                        INFER_TYPE
+                          UNDERSCORE "_"
+                        R_PAREN ")"
+                  SEMICOLON ";"
+              WHITESPACE "\n"
+        "#]],
+    );
+}
+
+#[test]
+fn pattern_entry_point() {
+    check(
+        TopEntryPoint::Pattern,
+        r#"| Some(["i": i, ..rest]) | (..a_and_b, 1..=9)"#,
+        expect![[r#"
+        OR_PAT
+          PIPE "|"
+          WHITESPACE " "
+          ALIASED_TUPLE_PAT
+            PATH
+              PATH_SEGMENT
+                NAME_REF
+                  IDENT "Some"
+            L_PAREN "("
+            SLICE_PAT
+              L_BRACK "["
+              MAP_ENTRY_PAT
+                LITERAL
+                  STRING "\"i\""
+                COLON ":"
+                WHITESPACE " "
+                IDENT_PAT
+                  NAME
+                    IDENT "i"
+              COMMA ","
+              WHITESPACE " "
+              REST_PAT
+                DOTDOT ".."
+                NAME
+                  IDENT "rest"
+              R_BRACK "]"
+            R_PAREN ")"
+          WHITESPACE " "
+          PIPE "|"
+          WHITESPACE " "
+          TUPLE_PAT
+            L_PAREN "("
+            REST_PAT
+              DOTDOT ".."
+              NAME
+                IDENT "a_and_b"
+            COMMA ","
+            WHITESPACE " "
+            RANGE_PAT
+              LITERAL_PAT
+                LITERAL
+                  INT_NUMBER "1"
+              DOTDOTEQ "..="
+              LITERAL_PAT
+                LITERAL
+                  INT_NUMBER "9"
+            R_PAREN ")"
+    "#]],
+    );
+    check(
+        TopEntryPoint::Pattern,
+        "E::Bools { m } extra",
+        expect![[r#"
+            ERROR
+              RECORD_PAT
+                PATH
+                  PATH
+                    PATH_SEGMENT
+                      NAME_REF
+                        IDENT "E"
+                  DOTRO "::"
+                  PATH_SEGMENT
+                    NAME_REF
+                      IDENT "Bools"
+                WHITESPACE " "
+                L_CURLY "{"
+                WHITESPACE " "
+                RECORD_PAT_FIELD
+                  IDENT_PAT
+                    NAME
+                      IDENT "m"
+                WHITESPACE " "
+                R_CURLY "}"
+              WHITESPACE " "
+              IDENT "extra"
+            error 14: expected end of input
+        "#]],
+    );
+}
+
+#[test]
+fn destructuring() {
+    check(
+        TopEntryPoint::SourceFile,
+        r#"
+fn main({ x, ..rest }: Point) {
+    let (..a_and_b, c) = t;
+    for [k, _] in pairs {}
+    |(a, b)| a;
+}
+"#,
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                WHITESPACE "\n"
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "main"
+                  PARAM_LIST
+                    L_PAREN "("
+                    PARAM
+                      RECORD_PAT
+                        L_CURLY "{"
+                        WHITESPACE " "
+                        RECORD_PAT_FIELD
+                          IDENT_PAT
+                            NAME
+                              IDENT "x"
+                        COMMA ","
+                        WHITESPACE " "
+                        RECORD_PAT_FIELD
+                          REST_PAT
+                            DOTDOT ".."
+                            NAME
+                              IDENT "rest"
+                        WHITESPACE " "
+                        R_CURLY "}"
+                      COLON ":"
+                      WHITESPACE " "
+                      PATH_TYPE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "Point"
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      WHITESPACE "\n    "
+                      LET_STMT
+                        LET_KW "let"
+                        WHITESPACE " "
+                        TUPLE_PAT
+                          L_PAREN "("
+                          REST_PAT
+                            DOTDOT ".."
+                            NAME
+                              IDENT "a_and_b"
+                          COMMA ","
+                          WHITESPACE " "
+                          IDENT_PAT
+                            NAME
+                              IDENT "c"
+                          R_PAREN ")"
+                        WHITESPACE " "
+                        EQ "="
+                        WHITESPACE " "
+                        PATH_EXPR
+                          PATH
+                            PATH_SEGMENT
+                              NAME_REF
+                                IDENT "t"
+                        SEMICOLON ";"
+                      WHITESPACE "\n    "
+                      EXPR_STMT
+                        FOR_EXPR
+                          FOR_KW "for"
+                          WHITESPACE " "
+                          SLICE_PAT
+                            L_BRACK "["
+                            IDENT_PAT
+                              NAME
+                                IDENT "k"
+                            COMMA ","
+                            WHITESPACE " "
+                            WILDCARD_PAT
+                              UNDERSCORE "_"
+                            R_BRACK "]"
+                          WHITESPACE " "
+                          IN_KW "in"
+                          WHITESPACE " "
+                          PATH_EXPR
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "pairs"
+                          WHITESPACE " "
+                          BLOCK_EXPR
+                            STMT_LIST
+                              L_CURLY "{"
+                              R_CURLY "}"
+                      WHITESPACE "\n    "
+                      EXPR_STMT
+                        CLOSURE_EXPR
+                          PARAM_LIST
+                            PIPE "|"
+                            PARAM
+                              TUPLE_PAT
+                                L_PAREN "("
+                                IDENT_PAT
+                                  NAME
+                                    IDENT "a"
+                                COMMA ","
+                                WHITESPACE " "
+                                IDENT_PAT
+                                  NAME
+                                    IDENT "b"
+                                R_PAREN ")"
+                            PIPE "|"
+                          WHITESPACE " "
+                          PATH_EXPR
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "a"
+                        SEMICOLON ";"
+                      WHITESPACE "\n"
+                      R_CURLY "}"
+              WHITESPACE "\n"
+        "#]],
+    );
+}
+
+#[test]
+fn collection_literals() {
+    check(
+        TopEntryPoint::SourceFile,
+        r#"
+fn main() {
+    let m = [..defaults, "b": 2];
+    let s = [:];
+    let mixed = [:1, 2];
+}
+"#,
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                WHITESPACE "\n"
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "main"
+                  PARAM_LIST
+                    L_PAREN "("
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      WHITESPACE "\n    "
+                      LET_STMT
+                        LET_KW "let"
+                        WHITESPACE " "
+                        IDENT_PAT
+                          NAME
+                            IDENT "m"
+                        WHITESPACE " "
+                        EQ "="
+                        WHITESPACE " "
+                        MAP_EXPR
+                          L_BRACK "["
+                          MAP_ENTRY
+                            DOTDOT ".."
+                            PATH_EXPR
+                              PATH
+                                PATH_SEGMENT
+                                  NAME_REF
+                                    IDENT "defaults"
+                          COMMA ","
+                          WHITESPACE " "
+                          MAP_ENTRY
+                            LITERAL
+                              STRING "\"b\""
+                            COLON ":"
+                            WHITESPACE " "
+                            LITERAL
+                              INT_NUMBER "2"
+                          R_BRACK "]"
+                        SEMICOLON ";"
+                      WHITESPACE "\n    "
+                      LET_STMT
+                        LET_KW "let"
+                        WHITESPACE " "
+                        IDENT_PAT
+                          NAME
+                            IDENT "s"
+                        WHITESPACE " "
+                        EQ "="
+                        WHITESPACE " "
+                        MAP_EXPR
+                          L_BRACK "["
+                          COLON ":"
+                          R_BRACK "]"
+                        SEMICOLON ";"
+                      WHITESPACE "\n    "
+                      LET_STMT
+                        LET_KW "let"
+                        WHITESPACE " "
+                        IDENT_PAT
+                          NAME
+                            IDENT "mixed"
+                        WHITESPACE " "
+                        EQ "="
+                        WHITESPACE " "
+                        SET_EXPR
+                          L_BRACK "["
+                          SET_ENTRY
+                            COLON ":"
+                            LITERAL
+                              INT_NUMBER "1"
+                          COMMA ","
+                          WHITESPACE " "
+                          ERROR
+                            LITERAL
+                              INT_NUMBER "2"
+                          R_BRACK "]"
+                        SEMICOLON ";"
+                      WHITESPACE "\n"
+                      R_CURLY "}"
+              WHITESPACE "\n"
+            error 86: expected a set entry `:value`, found a list entry `value`: collection literals cannot mix kinds of entries
+        "#]],
+    );
+}
+
+#[test]
+fn meta_section() {
+    check(
+        TopEntryPoint::SourceFile,
+        r#"
+pub fn b() {
+  z();
+}
+---
+from ~/foo use Y, Z, z;
+from ../shared show theme::{dark, light};
+fn late() {}
+"#,
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                WHITESPACE "\n"
+                FN
+                  VISIBILITY
+                    PUB_KW "pub"
+                  WHITESPACE " "
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "b"
+                  PARAM_LIST
+                    L_PAREN "("
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      WHITESPACE "\n  "
+                      EXPR_STMT
+                        CALL_EXPR
+                          PATH_EXPR
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "z"
+                          ARG_LIST
+                            L_PAREN "("
+                            R_PAREN ")"
+                        SEMICOLON ";"
+                      WHITESPACE "\n"
+                      R_CURLY "}"
+              WHITESPACE "\n"
+              SECSEP "---"
+              META_SECTION
+                WHITESPACE "\n"
+                DEP
+                  FROM_KW "from"
+                  WHITESPACE " "
+                  REPO_DEP_PATH
+                    TILDE "~"
+                    SLASH "/"
+                    EXTERNAL_DEP_PATH
+                      DEP_PATH_SEGMENT
+                        NAME_REF
+                          IDENT "foo"
+                  WHITESPACE " "
+                  USE
+                    USE_KW "use"
+                    WHITESPACE " "
+                    USE_TREE_LIST
+                      USE_TREE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "Y"
+                      COMMA ","
+                      WHITESPACE " "
+                      USE_TREE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "Z"
+                      COMMA ","
+                      WHITESPACE " "
+                      USE_TREE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "z"
+                    SEMICOLON ";"
+                WHITESPACE "\n"
+                DEP
+                  FROM_KW "from"
+                  WHITESPACE " "
+                  LOCAL_DEP_PATH
+                    PARENT_DIR_DEP_PATH_SEGMENT
+                      DOTDOT ".."
+                      SLASH "/"
+                    EXTERNAL_DEP_PATH
+                      DEP_PATH_SEGMENT
+                        NAME_REF
+                          IDENT "shared"
+                  WHITESPACE " "
+                  USE
+                    SHOW_KW "show"
+                    WHITESPACE " "
+                    USE_TREE_LIST
+                      USE_TREE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "theme"
+                        DOTRO "::"
+                        USE_TREE_EXPANSION
+                          L_CURLY "{"
+                          USE_TREE_LIST
+                            USE_TREE
+                              PATH
+                                PATH_SEGMENT
+                                  NAME_REF
+                                    IDENT "dark"
+                            COMMA ","
+                            WHITESPACE " "
+                            USE_TREE
+                              PATH
+                                PATH_SEGMENT
+                                  NAME_REF
+                                    IDENT "light"
+                          R_CURLY "}"
+                    SEMICOLON ";"
+                WHITESPACE "\n"
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "late"
+                  PARAM_LIST
+                    L_PAREN "("
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      R_CURLY "}"
+              WHITESPACE "\n"
+        "#]],
+    );
+}
+
+#[test]
+fn doc_comments_attach_to_the_first_item_of_each_section() {
+    // The sections themselves never claim leading trivia, so that it is left
+    // for the item it documents.
+    check(
+        TopEntryPoint::SourceFile,
+        "//! File.\n\n/// Doc.\nfn a() {}\n---\n/// Dep doc.\nfrom b use c;\n",
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                COMMENT "//! File."
+                WHITESPACE "\n\n"
+                FN
+                  COMMENT "/// Doc."
+                  WHITESPACE "\n"
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "a"
+                  PARAM_LIST
+                    L_PAREN "("
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      R_CURLY "}"
+              WHITESPACE "\n"
+              SECSEP "---"
+              META_SECTION
+                WHITESPACE "\n"
+                DEP
+                  COMMENT "/// Dep doc."
+                  WHITESPACE "\n"
+                  FROM_KW "from"
+                  WHITESPACE " "
+                  EXTERNAL_DEP_PATH
+                    DEP_PATH_SEGMENT
+                      NAME_REF
+                        IDENT "b"
+                  WHITESPACE " "
+                  USE
+                    USE_KW "use"
+                    WHITESPACE " "
+                    USE_TREE_LIST
+                      USE_TREE
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "c"
+                    SEMICOLON ";"
+              WHITESPACE "\n"
+        "#]],
+    );
+}
+
+#[test]
+fn snippet_entry_points() {
+    check(
+        TopEntryPoint::Type,
+        "[str: i32]?",
+        expect![[r#"
+        OPTION_TYPE
+          MAP_TYPE
+            L_BRACK "["
+            PATH_TYPE
+              PATH
+                PATH_SEGMENT
+                  NAME_REF
+                    IDENT "str"
+            COLON ":"
+            WHITESPACE " "
+            PATH_TYPE
+              PATH
+                PATH_SEGMENT
+                  NAME_REF
+                    IDENT "i32"
+            R_BRACK "]"
+          QUESTION "?"
+    "#]],
+    );
+    check(
+        TopEntryPoint::Expr,
+        "a.b(1) + 2",
+        expect![[r#"
+        BIN_EXPR
+          METHOD_CALL_EXPR
+            PATH_EXPR
+              PATH
+                PATH_SEGMENT
+                  NAME_REF
+                    IDENT "a"
+            DOT "."
+            NAME_REF
+              IDENT "b"
+            METHOD_CALL_ARG_LIST
+              L_PAREN "("
+              MAYBE_SELFIFIED_ARG
+                ARG
+                  LITERAL
+                    INT_NUMBER "1"
+              R_PAREN ")"
+          WHITESPACE " "
+          PLUS "+"
+          WHITESPACE " "
+          LITERAL
+            INT_NUMBER "2"
+    "#]],
+    );
+    check(
+        TopEntryPoint::Expr,
+        "1 2",
+        expect![[r#"
+        ERROR
+          LITERAL
+            INT_NUMBER "1"
+          WHITESPACE " "
+          INT_NUMBER "2"
+        error 1: expected end of input
+    "#]],
+    );
+    check(
+        TopEntryPoint::MetaItem,
+        "path = 1",
+        expect![[r#"
+        META
+          PATH
+            PATH_SEGMENT
+              NAME_REF
+                IDENT "path"
+          WHITESPACE " "
+          EQ "="
+          WHITESPACE " "
+          LITERAL
+            INT_NUMBER "1"
+    "#]],
+    );
+    check(
+        TopEntryPoint::Pattern,
+        "",
+        expect![[r#"
+        ERROR
+          ERROR
+        error 0: expected pattern
+    "#]],
+    );
+}
+
+#[test]
+fn numeric_suffixes() {
+    check(
+        TopEntryPoint::Expr,
+        "[1u8, 12u32, 1.2f32, 1.2345e-6f64, 1f32, 0xffu8, 2usz, 0b1f32, 1.5u8]",
+        expect![[r#"
+            LIST_EXPR
+              L_BRACK "["
+              LIST_ENTRY
+                LITERAL
+                  INT_NUMBER "1u8"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  INT_NUMBER "12u32"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  FLOAT_NUMBER "1.2f32"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  FLOAT_NUMBER "1.2345e-6f64"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  INT_NUMBER "1f32"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  INT_NUMBER "0xffu8"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  INT_NUMBER "2usz"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  INT_NUMBER "0b1f32"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  FLOAT_NUMBER "1.5u8"
+              R_BRACK "]"
+            error 50: invalid suffix `usz` for number literal
+            error 58: invalid suffix `f32` for number literal
+            error 66: invalid suffix `u8` for float literal
+        "#]],
+    );
+}
+
+#[test]
+fn format_strings() {
+    check(
+        TopEntryPoint::Expr,
+        r#""${short_story(name)} and I am $age, ${ "${a}" }""#,
+        expect![[r#"
+            FORMAT_STRING
+              STRING_START "\""
+              INTERPOLATION
+                INTERPOLATION_START "${"
+                CALL_EXPR
+                  PATH_EXPR
+                    PATH
+                      PATH_SEGMENT
+                        NAME_REF
+                          IDENT "short_story"
+                  ARG_LIST
+                    L_PAREN "("
+                    ARG
+                      PATH_EXPR
+                        PATH
+                          PATH_SEGMENT
+                            NAME_REF
+                              IDENT "name"
+                    R_PAREN ")"
+                INTERPOLATION_END "}"
+              STRING_FRAGMENT " and I am "
+              INTERPOLATION
+                DOLLAR "$"
+                PATH_EXPR
+                  PATH
+                    PATH_SEGMENT
+                      NAME_REF
+                        IDENT "age"
+              STRING_FRAGMENT ", "
+              INTERPOLATION
+                INTERPOLATION_START "${"
+                WHITESPACE " "
+                FORMAT_STRING
+                  STRING_START "\""
+                  INTERPOLATION
+                    INTERPOLATION_START "${"
+                    PATH_EXPR
+                      PATH
+                        PATH_SEGMENT
+                          NAME_REF
+                            IDENT "a"
+                    INTERPOLATION_END "}"
+                  STRING_END "\""
+                WHITESPACE " "
+                INTERPOLATION_END "}"
+              STRING_END "\""
+        "#]],
+    );
+}
+
+#[test]
+fn adjacent_string_literals() {
+    check(
+        TopEntryPoint::Expr,
+        r#""123""xyz"
+    """ - with "quotes" """ "A $b" r"c""#,
+        expect![[r#"
+            FORMAT_STRING
+              STRING "\"123\""
+              STRING "\"xyz\""
+              WHITESPACE "\n    "
+              STRING "\"\"\" - with \"quotes\" \"\"\""
+              WHITESPACE " "
+              STRING_START "\"A "
+              INTERPOLATION
+                DOLLAR "$"
+                PATH_EXPR
+                  PATH
+                    PATH_SEGMENT
+                      NAME_REF
+                        IDENT "b"
+              STRING_END "\""
+              WHITESPACE " "
+              STRING "r\"c\""
+        "#]],
+    );
+}
+
+/// Every entry of the `[punctuation-names]` table in
+/// `denim_grammar_facts.toml`, in order.
+const PUNCTUATION: &[&str] = &[
+    "$", ";", ",", "(", ")", "{", "}", "[", "]", "<", ">", "@", "#", "~", "?", "&", "|", "+", "*",
+    "/", "%", "_", ".", "..", "...", "..=", ":", "::", "=", "==", "===", "=>", "!", "!=", "!==",
+    "-", "---", "->", "<=",
+];
+
+#[test]
+fn punctuation() {
+    let mut buf = String::new();
+    for punct in PUNCTUATION {
+        let (kind, diagnostics) = TokenizedStr::single_token(punct)
+            .unwrap_or_else(|| panic!("`{punct}` is not a single token"));
+        assert_eq!(diagnostics, [], "`{punct}` has problems");
+        writeln!(buf, "{punct:?} {kind:?}").unwrap();
+    }
+    expect![[r##"
+        "$" DOLLAR
+        ";" SEMICOLON
+        "," COMMA
+        "(" L_PAREN
+        ")" R_PAREN
+        "{" L_CURLY
+        "}" R_CURLY
+        "[" L_BRACK
+        "]" R_BRACK
+        "<" L_ANGLE
+        ">" R_ANGLE
+        "@" AT
+        "#" POUND
+        "~" TILDE
+        "?" QUESTION
+        "&" AMP
+        "|" PIPE
+        "+" PLUS
+        "*" STAR
+        "/" SLASH
+        "%" PERCENT
+        "_" UNDERSCORE
+        "." DOT
+        ".." DOTDOT
+        "..." DOTDOTDOT
+        "..=" DOTDOTEQ
+        ":" COLON
+        "::" DOTRO
+        "=" EQ
+        "==" EQEQ
+        "===" EQEQEQ
+        "=>" FAT_ARROW
+        "!" BANG
+        "!=" NEQ
+        "!==" NEQEQ
+        "-" MINUS
+        "---" SECSEP
+        "->" THIN_ARROW
+        "<=" LTEQ
+    "##]]
+    .assert_eq(&buf);
+}
+
+#[test]
+fn byte_and_c_string_literals() {
+    check(
+        TopEntryPoint::Expr,
+        r##"[b'\x7f', b"DNM\x00", br#"\"#, c"nul\0", cr"ok", b'ü', b"\u{a5}"]"##,
+        expect![[r##"
+            LIST_EXPR
+              L_BRACK "["
+              LIST_ENTRY
+                LITERAL
+                  BYTE "b'\\x7f'"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  BYTE_STRING "b\"DNM\\x00\""
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  BYTE_STRING "br#\"\\\"#"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  C_STRING "c\"nul\\0\""
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  C_STRING "cr\"ok\""
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  BYTE "b'ü'"
+              COMMA ","
+              WHITESPACE " "
+              LIST_ENTRY
+                LITERAL
+                  BYTE_STRING "b\"\\u{a5}\""
+              R_BRACK "]"
+            error 36: null character in C string literal
+            error 51: non-ASCII character in byte literal
+            error 58: unicode escape in byte string
+        "##]],
+    );
+}
+
+#[test]
+fn ident_hygiene() {
+    check(
+        TopEntryPoint::SourceFile,
+        "fn café() { let раypal = αlpha + 👖; }",
+        expect![[r#"
+            SOURCE_FILE
+              CODE_SECTION
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "café"
+                  PARAM_LIST
+                    L_PAREN "("
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      WHITESPACE " "
+                      LET_STMT
+                        LET_KW "let"
+                        WHITESPACE " "
+                        IDENT_PAT
+                          NAME
+                            IDENT "раypal"
+                        WHITESPACE " "
+                        EQ "="
+                        WHITESPACE " "
+                        BIN_EXPR
+                          PATH_EXPR
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "αlpha"
+                          WHITESPACE " "
+                          PLUS "+"
+                          WHITESPACE " "
+                          PATH_EXPR
+                            PATH
+                              PATH_SEGMENT
+                                NAME_REF
+                                  IDENT "👖"
+                        SEMICOLON ";"
+                      WHITESPACE " "
+                      R_CURLY "}"
+            error 37: Ident contains invalid characters
+            warning 17: identifier contains `р` (U+0440), which is easily confused with `p`
+            warning 19: identifier contains `а` (U+0430), which is easily confused with `a`
+            warning 17: identifier mixes characters from different scripts (Cyrillic, Latin)
+            warning 28: identifier contains `α` (U+03B1), which is easily confused with `a`
+            warning 28: identifier mixes characters from different scripts (Greek, Latin)
+        "#]],
+    );
+}
+
+#[test]
+fn single_script_idents() {
+    // Identifiers in a single script are fine, however much their characters
+    // look like ASCII.
+    let tokenized = TokenizedStr::new("let привет = αβγ + ωμέγα + рау;");
+    assert_eq!(tokenized.diagnostics(), []);
+}
+
+#[test]
+fn tokenizer_diagnostics() {
+    let text = "let x = 1u7;\nlet у = \"a\\qb\";\nlet z = '\\u{D800}';\n/* open";
+    let tokenized = TokenizedStr::new(text);
+
+    let mut buf = String::new();
+    for diagnostic in tokenized.diagnostics() {
+        let TokenizerDiagnostic { code, severity, message, range, fix, .. } = diagnostic;
+        writeln!(buf, "{code} {severity:?} {range:?} {:?}: {message}", &text[range.clone()])
+            .unwrap();
+        if let Some(SuggestedFix { label, range, replacement }) = fix {
+            writeln!(buf, "  fix {range:?} {replacement:?}: {label}").unwrap();
+        }
+    }
+    expect![[r#"
+        L0006 Error 9..11 "u7": invalid suffix `u7` for number literal
+          fix 9..11 "": remove the suffix
+        L0103 Error 24..26 "\\q": unknown character escape
+        L0116 Error 39..47 "\\u{D800}": invalid unicode character escape
+        L0001 Error 50..57 "/* open": Missing trailing `*/` symbols to terminate the block comment
+          fix 57..57 "*/": add `*/`
+    "#]]
+    .assert_eq(&buf);
+}
+
+#[test]
+fn non_ascii_literal_suffix() {
+    let text = "1µs";
+    let tokenized = TokenizedStr::new(text);
+
+    let ranges =
+        tokenized.diagnostics().iter().map(|it| &text[it.range.clone()]).collect::<Vec<_>>();
+    assert_eq!(ranges, ["µs"]);
+}
+
+#[test]
+fn shebang() {
+    check(
+        TopEntryPoint::SourceFile,
+        "\u{FEFF}#!/usr/bin/env denim\nfn main() {}\n",
+        expect![[r##"
+            SOURCE_FILE
+              CODE_SECTION
+                WHITESPACE "\u{feff}"
+                SHEBANG "#!/usr/bin/env denim"
+                WHITESPACE "\n"
+                FN
+                  FN_KW "fn"
+                  WHITESPACE " "
+                  NAME
+                    IDENT "main"
+                  PARAM_LIST
+                    L_PAREN "("
+                    R_PAREN ")"
+                  WHITESPACE " "
+                  BLOCK_EXPR
+                    STMT_LIST
+                      L_CURLY "{"
+                      R_CURLY "}"
+              WHITESPACE "\n"
+        "##]],
+    );
+    check(
+        TopEntryPoint::SourceFile,
+        "#![allow(unused)]\nfn main() {}\n",
+        expect![[r##"
+        SOURCE_FILE
+          CODE_SECTION
+            ATTR
+              POUND "#"
+              BANG "!"
+              L_BRACK "["
+              META
+                PATH
+                  PATH_SEGMENT
+                    NAME_REF
+                      IDENT "allow"
+                TOKEN_TREE
+                  L_PAREN "("
+                  IDENT "unused"
+                  R_PAREN ")"
+              R_BRACK "]"
+            WHITESPACE "\n"
+            FN
+              FN_KW "fn"
+              WHITESPACE " "
+              NAME
+                IDENT "main"
+              PARAM_LIST
+                L_PAREN "("
+                R_PAREN ")"
+              WHITESPACE " "
+              BLOCK_EXPR
+                STMT_LIST
+                  L_CURLY "{"
+                  R_CURLY "}"
+          WHITESPACE "\n"
+    "##]],
+    );
+}
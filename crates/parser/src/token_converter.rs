@@ -1,9 +1,13 @@
-use tokenizer::{Mode, StringLiteralEnding};
+use std::ops;
+
+use tokenizer::{EscapeError, IdentHygieneWarning, Mode, NumericBase, StringLiteralEnding};
 
 use crate::{
-    error_util::{error_to_diagnostic_message, unescape_string_error_message},
+    error_util::{
+        error_to_diagnostic_message, first_string_escape_error, ident_hygiene_warning_message,
+    },
     tokenized_str::TokenizedStr,
-    tokenizer_error::TokenizerError,
+    tokenizer_diagnostic::{TokenizerDiagnostic, TokenizerDiagnosticCode as Code},
     SyntaxKind::{self, *},
     T,
 };
@@ -16,7 +20,7 @@ pub(crate) struct TokenConverter<'a> {
 impl<'a> TokenConverter<'a> {
     pub(crate) fn new(text: &'a str) -> Self {
         Self {
-            res: TokenizedStr { text, kind: Vec::new(), start: Vec::new(), error: Vec::new() },
+            res: TokenizedStr { text, kind: Vec::new(), start: Vec::new(), diagnostic: Vec::new() },
             offset: 0,
         }
     }
@@ -26,14 +30,63 @@ impl<'a> TokenConverter<'a> {
         self.res
     }
 
-    pub(crate) fn push(&mut self, kind: SyntaxKind, len: usize, err: Option<&str>) {
+    pub(crate) fn push(&mut self, kind: SyntaxKind, len: usize) {
         self.res.push(kind, self.offset);
         self.offset += len;
+    }
+
+    /// Records `diagnostic` about the token that is about to be pushed.
+    ///
+    /// The ranges of `diagnostic` are relative to the start of that token.
+    fn diagnose(&mut self, mut diagnostic: TokenizerDiagnostic) {
+        let shift = |range: ops::Range<usize>| range.start + self.offset..range.end + self.offset;
+
+        diagnostic.token = self.res.kind.len() as u32;
+        diagnostic.range = shift(diagnostic.range);
+        if let Some(fix) = &mut diagnostic.fix {
+            fix.range = shift(fix.range.clone());
+        }
+
+        self.res.diagnostic.push(diagnostic);
+    }
+
+    /// Diagnoses a token of length `len` that is missing its `terminator`.
+    fn diagnose_unterminated(&mut self, code: Code, message: &str, len: usize, terminator: &str) {
+        self.diagnose(TokenizerDiagnostic::error(code, message, 0..len).with_fix(
+            format!("add `{terminator}`"),
+            len..len,
+            terminator,
+        ));
+    }
+
+    /// Diagnoses the first invalid escape in `text`, the contents of a string
+    /// literal in `mode` that start `start` bytes into the token.
+    fn diagnose_str_escapes(&mut self, text: &str, start: usize, mode: Mode) {
+        if let Some((range, e)) = first_string_escape_error(text, mode) {
+            let range = start + range.start..start + range.end;
+            self.diagnose_escape(e, range, mode);
+        }
+    }
 
-        if let Some(err) = err {
-            let token = self.res.len() as u32;
-            let msg = err.to_owned();
-            self.res.error.push(TokenizerError { msg, token });
+    /// Diagnoses the invalid escape `e` at `range` within the token.
+    fn diagnose_escape(&mut self, e: EscapeError, range: ops::Range<usize>, mode: Mode) {
+        let message = error_to_diagnostic_message(e, mode);
+        if !message.is_empty() {
+            self.diagnose(TokenizerDiagnostic::error(Code::InvalidEscape(e), message, range));
+        }
+    }
+
+    /// Diagnoses an identifier that is valid, but likely to be misread.
+    fn diagnose_ident_hygiene(&mut self, ident: &str) {
+        for warning in tokenizer::check_ident_hygiene(ident) {
+            let message = ident_hygiene_warning_message(&warning);
+            let (code, range) = match warning {
+                IdentHygieneWarning::Confusable { offset, c, .. } => {
+                    (Code::ConfusableIdent, offset..offset + c.len_utf8())
+                }
+                IdentHygieneWarning::MixedScript { .. } => (Code::MixedScriptIdent, 0..ident.len()),
+            };
+            self.diagnose(TokenizerDiagnostic::warning(code, message, range));
         }
     }
 
@@ -42,14 +95,19 @@ impl<'a> TokenConverter<'a> {
         // We drop some useful information here (see patterns with double dots `..`)
         // Storing that info in `SyntaxKind` is not possible due to its layout requirements of
         // being `u16` that come from `rowan::SyntaxKind`.
-        let mut err = "";
+        let len = token_text.len();
 
         let syntax_kind = {
             match kind {
                 tokenizer::TokenKind::LineComment { is_doc_comment: _ } => COMMENT,
                 tokenizer::TokenKind::BlockComment { is_terminated } => {
                     if !is_terminated {
-                        err = "Missing trailing `*/` symbols to terminate the block comment";
+                        self.diagnose_unterminated(
+                            Code::UnterminatedBlockComment,
+                            "Missing trailing `*/` symbols to terminate the block comment",
+                            len,
+                            "*/",
+                        );
                     }
                     COMMENT
                 }
@@ -58,22 +116,62 @@ impl<'a> TokenConverter<'a> {
 
                 tokenizer::TokenKind::Ident if token_text == "_" => UNDERSCORE,
                 tokenizer::TokenKind::Ident => {
-                    SyntaxKind::from_keyword(token_text).unwrap_or(IDENT)
+                    self.diagnose_ident_hygiene(token_text);
+
+                    let ident = tokenizer::nfc_normalize_ident(token_text);
+                    SyntaxKind::from_keyword(&ident).unwrap_or(IDENT)
                 }
                 tokenizer::TokenKind::InvalidIdent => {
-                    err = "Ident contains invalid characters";
+                    self.diagnose(TokenizerDiagnostic::error(
+                        Code::InvalidIdent,
+                        "Ident contains invalid characters",
+                        0..len,
+                    ));
                     IDENT
                 }
 
-                tokenizer::TokenKind::Literal { kind, .. } => {
-                    self.extend_literal(token_text.len(), kind);
+                tokenizer::TokenKind::Literal { kind, suffix_start } => {
+                    self.extend_literal(len, *suffix_start as usize, kind);
                     return;
                 }
 
+                tokenizer::TokenKind::StrStart { is_multiline } => {
+                    let quotes_len = if *is_multiline { 3 } else { 1 };
+                    self.diagnose_str_escapes(&token_text[quotes_len..], quotes_len, Mode::Str);
+                    STRING_START
+                }
+                tokenizer::TokenKind::StrFragment => {
+                    self.diagnose_str_escapes(token_text, 0, Mode::Str);
+                    STRING_FRAGMENT
+                }
+                tokenizer::TokenKind::StrEnd { ending } => {
+                    match ending {
+                        StringLiteralEnding::TerminatedString { is_multiline } => {
+                            let quotes_len = if *is_multiline { 3 } else { 1 };
+                            let text = &token_text[..len - quotes_len];
+                            self.diagnose_str_escapes(text, 0, Mode::Str);
+                        }
+                        StringLiteralEnding::UnterminatedString => {
+                            self.diagnose_unterminated(
+                                Code::UnterminatedStr,
+                                "Missing trailing `\"` symbol to terminate the string literal",
+                                len,
+                                "\"",
+                            );
+                        }
+                    }
+                    STRING_END
+                }
+                tokenizer::TokenKind::InterpolationStart { is_braced: false } => T![$],
+                tokenizer::TokenKind::InterpolationStart { is_braced: true } => INTERPOLATION_START,
+                tokenizer::TokenKind::InterpolationEnd => INTERPOLATION_END,
+
                 tokenizer::TokenKind::Semi => T![;],
                 tokenizer::TokenKind::Comma => T![,],
                 tokenizer::TokenKind::Dot => T![.],
                 tokenizer::TokenKind::DotDot => T![..],
+                tokenizer::TokenKind::DotDotDot => T![...],
+                tokenizer::TokenKind::DotDotEq => T![..=],
                 tokenizer::TokenKind::Dotro => T![::],
                 tokenizer::TokenKind::OpenParen => T!['('],
                 tokenizer::TokenKind::CloseParen => T![')'],
@@ -90,85 +188,245 @@ impl<'a> TokenConverter<'a> {
                 tokenizer::TokenKind::Eq => T![=],
                 tokenizer::TokenKind::EqEq => T![==],
                 tokenizer::TokenKind::EqEqEq => T![===],
+                tokenizer::TokenKind::FatArrow => T![=>],
                 tokenizer::TokenKind::NEq => T![!=],
                 tokenizer::TokenKind::NEqEq => T![!==],
                 tokenizer::TokenKind::Bang => T![!],
                 tokenizer::TokenKind::Lt => T![<],
+                tokenizer::TokenKind::LtEq => T![<=],
                 tokenizer::TokenKind::Gt => T![>],
                 tokenizer::TokenKind::Minus => T![-],
+                tokenizer::TokenKind::ThinArrow => T![->],
                 tokenizer::TokenKind::Amp => T![&],
                 tokenizer::TokenKind::Pipe => T![|],
                 tokenizer::TokenKind::Plus => T![+],
                 tokenizer::TokenKind::SectionSeparator => T![---],
+                tokenizer::TokenKind::Shebang => SHEBANG,
                 tokenizer::TokenKind::Star => T![*],
                 tokenizer::TokenKind::Slash => T![/],
                 tokenizer::TokenKind::Percent => T![%],
                 tokenizer::TokenKind::Unknown => ERROR,
                 tokenizer::TokenKind::UnknownPrefix if token_text == "builtin" => IDENT,
                 tokenizer::TokenKind::UnknownPrefix => {
-                    err = "unknown literal prefix";
+                    self.diagnose(TokenizerDiagnostic::error(
+                        Code::UnknownLiteralPrefix,
+                        "unknown literal prefix",
+                        0..len,
+                    ));
                     IDENT
                 }
                 tokenizer::TokenKind::End => EOF,
             }
         };
 
-        let err = if err.is_empty() { None } else { Some(err) };
-        self.push(syntax_kind, token_text.len(), err);
+        self.push(syntax_kind, len);
     }
 
-    pub(crate) fn extend_literal(&mut self, len: usize, kind: &tokenizer::LiteralKind) {
-        let mut err = "";
+    pub(crate) fn extend_literal(
+        &mut self,
+        len: usize,
+        suffix_start: usize,
+        kind: &tokenizer::LiteralKind,
+    ) {
+        let text = &self.res.text[self.offset..][..len];
+        let suffix = &text[suffix_start..];
 
         let syntax_kind = match *kind {
-            tokenizer::LiteralKind::Int { is_empty, base: _ } => {
+            tokenizer::LiteralKind::Int { is_empty, base } => {
                 if is_empty {
-                    err = "Missing digits after the integer base prefix";
+                    self.diagnose(TokenizerDiagnostic::error(
+                        Code::MissingIntDigits,
+                        "Missing digits after the integer base prefix",
+                        0..len,
+                    ));
+                } else if !is_valid_int_suffix(suffix, base) {
+                    self.diagnose(
+                        TokenizerDiagnostic::error(
+                            Code::InvalidIntSuffix,
+                            format!("invalid suffix `{suffix}` for number literal"),
+                            suffix_start..len,
+                        )
+                        .with_fix(
+                            "remove the suffix",
+                            suffix_start..len,
+                            "",
+                        ),
+                    );
                 }
                 INT_NUMBER
             }
             tokenizer::LiteralKind::Float { is_empty, base: _ } => {
                 if is_empty {
-                    err = "Missing digits after the exponent symbol";
+                    self.diagnose(TokenizerDiagnostic::error(
+                        Code::MissingExponentDigits,
+                        "Missing digits after the exponent symbol",
+                        0..len,
+                    ));
+                } else if !is_valid_float_suffix(suffix) {
+                    self.diagnose(
+                        TokenizerDiagnostic::error(
+                            Code::InvalidFloatSuffix,
+                            format!("invalid suffix `{suffix}` for float literal"),
+                            suffix_start..len,
+                        )
+                        .with_fix(
+                            "remove the suffix",
+                            suffix_start..len,
+                            "",
+                        ),
+                    );
                 }
                 FLOAT_NUMBER
             }
+            tokenizer::LiteralKind::Byte { is_terminated } => {
+                if !is_terminated {
+                    self.diagnose_unterminated(
+                        Code::UnterminatedByte,
+                        "Missing trailing `'` symbol to terminate the byte literal",
+                        len,
+                        "'",
+                    );
+                } else {
+                    let end = text.rfind('\'').unwrap();
+                    if let Err(e) = tokenizer::unescape::unescape_byte(&text[2..end]) {
+                        self.diagnose_escape(e, 2..end, Mode::Byte);
+                    }
+                }
+                BYTE
+            }
+            tokenizer::LiteralKind::ByteStr { is_terminated } => {
+                if !is_terminated {
+                    self.diagnose_unterminated(
+                        Code::UnterminatedByteStr,
+                        "Missing trailing `\"` symbol to terminate the byte string literal",
+                        len,
+                        "\"",
+                    );
+                } else {
+                    self.diagnose_str_escapes(&text[2..len - 1], 2, Mode::ByteStr);
+                }
+                BYTE_STRING
+            }
+            tokenizer::LiteralKind::CStr { is_terminated } => {
+                if !is_terminated {
+                    self.diagnose_unterminated(
+                        Code::UnterminatedCStr,
+                        "Missing trailing `\"` symbol to terminate the C string literal",
+                        len,
+                        "\"",
+                    );
+                } else {
+                    self.diagnose_str_escapes(&text[2..len - 1], 2, Mode::CStr);
+                }
+                C_STRING
+            }
             tokenizer::LiteralKind::Char { is_terminated } => {
                 if !is_terminated {
-                    err = "Missing trailing `'` symbol to terminate the character literal";
+                    self.diagnose_unterminated(
+                        Code::UnterminatedChar,
+                        "Missing trailing `'` symbol to terminate the character literal",
+                        len,
+                        "'",
+                    );
                 } else {
-                    let text = &self.res.text[self.offset + 1..][..len - 1];
-                    let i = text.rfind('\'').unwrap();
-                    let text = &text[..i];
-                    if let Err(e) = tokenizer::unescape::unescape_char(text) {
-                        err = error_to_diagnostic_message(e, Mode::Char);
+                    let end = text.rfind('\'').unwrap();
+                    if let Err(e) = tokenizer::unescape::unescape_char(&text[1..end]) {
+                        self.diagnose_escape(e, 1..end, Mode::Char);
                     }
                 }
                 CHAR
             }
             tokenizer::LiteralKind::Str { ending } => {
                 match ending {
-                    StringLiteralEnding::TerminatedString { is_multiline: _ } => {
-                        let text = &self.res.text[self.offset + 1..][..len - 1];
-                        let i = text.rfind('"').unwrap();
-                        let text = &text[..i];
-                        err = unescape_string_error_message(text, Mode::Str);
+                    StringLiteralEnding::TerminatedString { is_multiline } => {
+                        let quotes_len = if is_multiline { 3 } else { 1 };
+                        let contents = &text[quotes_len..len - quotes_len];
+                        self.diagnose_str_escapes(contents, quotes_len, Mode::Str);
                     }
                     StringLiteralEnding::UnterminatedString => {
-                        err = "Missing trailing `\"` symbol to terminate the string literal";
+                        let terminator = if text.starts_with(r#"""""#) { r#"""""# } else { "\"" };
+                        self.diagnose_unterminated(
+                            Code::UnterminatedStr,
+                            "Missing trailing `\"` symbol to terminate the string literal",
+                            len,
+                            terminator,
+                        );
                     }
                 }
                 STRING
             }
             tokenizer::LiteralKind::RawStr { pound_count } => {
                 if pound_count.is_none() {
-                    err = "Invalid raw string literal";
+                    self.diagnose(TokenizerDiagnostic::error(
+                        Code::InvalidRawStr,
+                        "Invalid raw string literal",
+                        0..len,
+                    ));
                 }
                 STRING
             }
+            tokenizer::LiteralKind::RawByteStr { pound_count } => {
+                if let Some(pound_count) = pound_count {
+                    let (start, contents) = raw_str_contents(text, pound_count);
+                    self.diagnose_str_escapes(contents, start, Mode::RawByteStr);
+                } else {
+                    self.diagnose(TokenizerDiagnostic::error(
+                        Code::InvalidRawStr,
+                        "Invalid raw byte string literal",
+                        0..len,
+                    ));
+                }
+                BYTE_STRING
+            }
+            tokenizer::LiteralKind::RawCStr { pound_count } => {
+                if let Some(pound_count) = pound_count {
+                    let (start, contents) = raw_str_contents(text, pound_count);
+                    self.diagnose_str_escapes(contents, start, Mode::RawCStr);
+                } else {
+                    self.diagnose(TokenizerDiagnostic::error(
+                        Code::InvalidRawStr,
+                        "Invalid raw C string literal",
+                        0..len,
+                    ));
+                }
+                C_STRING
+            }
         };
 
-        let err = if err.is_empty() { None } else { Some(err) };
-        self.push(syntax_kind, len, err);
+        self.push(syntax_kind, len);
     }
 }
+
+/// Returns the contents of a terminated raw string literal (e.g. `abc` for
+/// `br#"abc"#`), along with the offset that they start at.
+fn raw_str_contents(text: &str, pound_count: u8) -> (usize, &str) {
+    let start = text.find('"').unwrap() + 1;
+    let end = text.len() - pound_count as usize - 1;
+    (start, &text[start..end])
+}
+
+/// Returns `true` if `suffix` can end an integer literal written in `base`.
+///
+/// Decimal integer literals may also carry a float suffix (e.g. `1f32`).
+fn is_valid_int_suffix(suffix: &str, base: NumericBase) -> bool {
+    matches!(
+        suffix,
+        "" | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+    ) || (base == NumericBase::Decimal && is_valid_float_suffix(suffix))
+}
+
+/// Returns `true` if `suffix` can end a float literal.
+fn is_valid_float_suffix(suffix: &str) -> bool {
+    matches!(suffix, "" | "f32" | "f64")
+}
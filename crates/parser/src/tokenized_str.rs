@@ -10,21 +10,41 @@
 
 use std::ops;
 
-use crate::{token_converter::TokenConverter, tokenizer_error::TokenizerError, SyntaxKind};
+use crate::{
+    token_converter::TokenConverter,
+    tokenizer_diagnostic::{Severity, TokenizerDiagnostic},
+    SyntaxKind,
+};
 
 pub struct TokenizedStr<'a> {
-    pub(crate) error: Vec<TokenizerError>,
+    pub(crate) diagnostic: Vec<TokenizerDiagnostic>,
     pub(crate) kind: Vec<SyntaxKind>,
     pub(crate) start: Vec<u32>,
     pub(crate) text: &'a str,
 }
 
 impl<'a> TokenizedStr<'a> {
+    /// Tokenizes `text`, a whole source file, which may start with a byte
+    /// order mark and a shebang line.
     pub fn new(text: &'a str) -> TokenizedStr<'a> {
         let _p = tracing::info_span!("TokenizedStr::new").entered();
+        Self::from_tokens(text, tokenizer::tokenize(text))
+    }
+
+    /// Tokenizes `text`, which does not start a file (e.g. a block being
+    /// reparsed or a single expression).
+    pub fn new_fragment(text: &'a str) -> TokenizedStr<'a> {
+        let _p = tracing::info_span!("TokenizedStr::new_fragment").entered();
+        Self::from_tokens(text, tokenizer::tokenize_code(text))
+    }
+
+    fn from_tokens(
+        text: &'a str,
+        tokens: impl Iterator<Item = tokenizer::Token>,
+    ) -> TokenizedStr<'a> {
         let mut conv = TokenConverter::new(text);
 
-        for token in tokenizer::tokenize(&text[conv.offset..]) {
+        for token in tokens {
             let token_text = &text[conv.offset..][..token.len as usize];
 
             conv.extend_token(&token.kind, token_text);
@@ -33,12 +53,14 @@ impl<'a> TokenizedStr<'a> {
         conv.finalize_with_eof()
     }
 
-    pub fn single_token(text: &'a str) -> Option<(SyntaxKind, Option<String>)> {
+    /// Returns the kind of the token that `text` consists of, along with the
+    /// problems with it, or [None] if `text` is not exactly one token.
+    pub fn single_token(text: &'a str) -> Option<(SyntaxKind, Vec<TokenizerDiagnostic>)> {
         if text.is_empty() {
             return None;
         }
 
-        let token = tokenizer::tokenize(text).next()?;
+        let token = tokenizer::tokenize_code(text).next()?;
         if token.len as usize != text.len() {
             return None;
         }
@@ -46,7 +68,7 @@ impl<'a> TokenizedStr<'a> {
         let mut conv = TokenConverter::new(text);
         conv.extend_token(&token.kind, text);
         match &*conv.res.kind {
-            [kind] => Some((*kind, conv.res.error.pop().map(|it| it.msg))),
+            [kind] => Some((*kind, conv.res.diagnostic)),
             _ => None,
         }
     }
@@ -98,12 +120,23 @@ impl<'a> TokenizedStr<'a> {
 
     pub fn error(&self, i: usize) -> Option<&str> {
         assert!(i < self.len());
-        let err = self.error.binary_search_by_key(&(i as u32), |i| i.token).ok()?;
-        Some(self.error[err].msg.as_str())
+        self.errors().find(|it| it.token() == i).map(|it| it.message.as_str())
+    }
+
+    /// Returns every problem found while tokenizing, in the order of the
+    /// tokens that they are about.
+    pub fn diagnostics(&self) -> &[TokenizerDiagnostic] {
+        &self.diagnostic
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &TokenizerDiagnostic> + '_ {
+        self.diagnostic.iter().filter(|it| it.severity == Severity::Error)
     }
 
-    pub fn errors(&self) -> impl Iterator<Item = (usize, &str)> + '_ {
-        self.error.iter().map(|it| (it.token as usize, it.msg.as_str()))
+    /// Returns the warnings about tokens that are valid, but likely to be
+    /// misread (e.g. identifiers containing confusable characters).
+    pub fn warnings(&self) -> impl Iterator<Item = &TokenizerDiagnostic> + '_ {
+        self.diagnostic.iter().filter(|it| it.severity == Severity::Warning)
     }
 
     pub(crate) fn push(&mut self, kind: SyntaxKind, offset: usize) {
@@ -0,0 +1,169 @@
+//! See docs for `TokenizerDiagnostic`.
+
+use std::{fmt, ops};
+
+use tokenizer::EscapeError;
+
+/// Problem with a single token that was found while tokenizing text (e.g. an
+/// unterminated string literal or an invalid escape inside of one).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenizerDiagnostic {
+    /// Identifies the kind of problem, independent of the wording of
+    /// `message`.
+    pub code: TokenizerDiagnosticCode,
+    pub severity: Severity,
+    pub message: String,
+    /// Byte range of the offending text, which can be narrower than the token
+    /// that it is in (e.g. the range of a single escape in a string literal).
+    pub range: ops::Range<usize>,
+    pub fix: Option<SuggestedFix>,
+    /// Index of the token that this diagnostic is about.
+    pub(crate) token: u32,
+}
+
+/// How much a [TokenizerDiagnostic] matters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// The token is valid, but likely to be misread.
+    Warning,
+    /// The token is invalid.
+    Error,
+}
+
+/// An edit that fixes the problem described by a [TokenizerDiagnostic].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuggestedFix {
+    /// Short description of the edit, e.g. "add `*/`".
+    pub label: String,
+    /// Byte range of the text to replace, which is empty for insertions.
+    pub range: ops::Range<usize>,
+    pub replacement: String,
+}
+
+/// Enumerates every kind of problem that a [TokenizerDiagnostic] can describe.
+///
+/// See [TokenizerDiagnosticCode::as_str] for the stable code of each.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenizerDiagnosticCode {
+    UnterminatedBlockComment,
+    InvalidIdent,
+    UnknownLiteralPrefix,
+    MissingIntDigits,
+    MissingExponentDigits,
+    InvalidIntSuffix,
+    InvalidFloatSuffix,
+    UnterminatedChar,
+    UnterminatedByte,
+    UnterminatedStr,
+    UnterminatedByteStr,
+    UnterminatedCStr,
+    InvalidRawStr,
+    ConfusableIdent,
+    MixedScriptIdent,
+    ConfusableIdents,
+    InvalidEscape(EscapeError),
+}
+
+impl TokenizerDiagnosticCode {
+    /// Returns the code that identifies this kind of problem.
+    ///
+    /// Codes never change meaning and are never reused, so they are safe to
+    /// filter on.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TokenizerDiagnosticCode::UnterminatedBlockComment => "L0001",
+            TokenizerDiagnosticCode::InvalidIdent => "L0002",
+            TokenizerDiagnosticCode::UnknownLiteralPrefix => "L0003",
+            TokenizerDiagnosticCode::MissingIntDigits => "L0004",
+            TokenizerDiagnosticCode::MissingExponentDigits => "L0005",
+            TokenizerDiagnosticCode::InvalidIntSuffix => "L0006",
+            TokenizerDiagnosticCode::InvalidFloatSuffix => "L0007",
+            TokenizerDiagnosticCode::UnterminatedChar => "L0008",
+            TokenizerDiagnosticCode::UnterminatedByte => "L0009",
+            TokenizerDiagnosticCode::UnterminatedStr => "L0010",
+            TokenizerDiagnosticCode::UnterminatedByteStr => "L0011",
+            TokenizerDiagnosticCode::UnterminatedCStr => "L0012",
+            TokenizerDiagnosticCode::InvalidRawStr => "L0013",
+            TokenizerDiagnosticCode::ConfusableIdent => "L0014",
+            TokenizerDiagnosticCode::MixedScriptIdent => "L0015",
+            TokenizerDiagnosticCode::ConfusableIdents => "L0016",
+            TokenizerDiagnosticCode::InvalidEscape(e) => match e {
+                EscapeError::ZeroChars => "L0100",
+                EscapeError::MoreThanOneChar => "L0101",
+                EscapeError::LoneSlash => "L0102",
+                EscapeError::InvalidEscape => "L0103",
+                EscapeError::BareCarriageReturn => "L0104",
+                EscapeError::BareCarriageReturnInRawString => "L0105",
+                EscapeError::EscapeOnlyChar => "L0106",
+                EscapeError::TooShortHexEscape => "L0107",
+                EscapeError::InvalidCharInHexEscape => "L0108",
+                EscapeError::OutOfRangeHexEscape => "L0109",
+                EscapeError::NoBraceInUnicodeEscape => "L0110",
+                EscapeError::InvalidCharInUnicodeEscape => "L0111",
+                EscapeError::EmptyUnicodeEscape => "L0112",
+                EscapeError::UnclosedUnicodeEscape => "L0113",
+                EscapeError::LeadingUnderscoreUnicodeEscape => "L0114",
+                EscapeError::OverlongUnicodeEscape => "L0115",
+                EscapeError::LoneSurrogateUnicodeEscape => "L0116",
+                EscapeError::OutOfRangeUnicodeEscape => "L0117",
+                EscapeError::UnicodeEscapeInByte => "L0118",
+                EscapeError::NonAsciiCharInByte => "L0119",
+                EscapeError::NulInCStr => "L0120",
+                EscapeError::UnskippedWhitespaceWarning => "L0121",
+                EscapeError::MultipleSkippedLinesWarning => "L0122",
+            },
+        }
+    }
+}
+
+impl fmt::Display for TokenizerDiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TokenizerDiagnostic {
+    pub(crate) fn error(
+        code: TokenizerDiagnosticCode,
+        message: impl Into<String>,
+        range: ops::Range<usize>,
+    ) -> TokenizerDiagnostic {
+        TokenizerDiagnostic {
+            code,
+            severity: Severity::Error,
+            message: message.into(),
+            range,
+            fix: None,
+            token: 0,
+        }
+    }
+
+    /// Returns a warning about the text at `range`.
+    ///
+    /// Warnings about a whole file, like [TokenizerDiagnosticCode::ConfusableIdents],
+    /// are only found after tokenizing it, so they are not about any token in
+    /// particular.
+    pub fn warning(
+        code: TokenizerDiagnosticCode,
+        message: impl Into<String>,
+        range: ops::Range<usize>,
+    ) -> TokenizerDiagnostic {
+        TokenizerDiagnostic { severity: Severity::Warning, ..Self::error(code, message, range) }
+    }
+
+    pub(crate) fn with_fix(
+        mut self,
+        label: impl Into<String>,
+        range: ops::Range<usize>,
+        replacement: impl Into<String>,
+    ) -> TokenizerDiagnostic {
+        self.fix =
+            Some(SuggestedFix { label: label.into(), range, replacement: replacement.into() });
+        self
+    }
+
+    /// Returns the index of the token that this diagnostic is about.
+    pub fn token(&self) -> usize {
+        self.token as usize
+    }
+}
@@ -1,4 +0,0 @@
-pub(crate) struct TokenizerError {
-    pub(crate) msg: String,
-    pub(crate) token: u32,
-}
@@ -13,6 +13,7 @@ pub enum TopEntryPoint {
     Pattern,
     Type,
     Expr,
+    MetaItem,
 }
 
 impl TopEntryPoint {
@@ -20,9 +21,10 @@ impl TopEntryPoint {
         let _p = tracing::info_span!("TopEntryPoint::parse", ?self).entered();
         let entry_point: fn(&'_ mut parser::Parser<'_>) = match self {
             TopEntryPoint::SourceFile => grammar::entry::top::source_file,
-            TopEntryPoint::Pattern => todo!(),
-            TopEntryPoint::Type => todo!(),
-            TopEntryPoint::Expr => todo!(),
+            TopEntryPoint::Pattern => grammar::entry::top::pattern,
+            TopEntryPoint::Type => grammar::entry::top::type_,
+            TopEntryPoint::Expr => grammar::entry::top::expr,
+            TopEntryPoint::MetaItem => grammar::entry::top::meta_item,
         };
 
         let mut p = parser::Parser::new(input);
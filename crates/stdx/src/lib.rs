@@ -1 +1,5 @@
 pub mod limit;
+pub mod rand;
+
+/// Extension of Denim source files, as in `main.👖`.
+pub const DENIM_EXTENSION: &str = "👖";
@@ -0,0 +1,35 @@
+//! rand defines a tiny random number generator for randomized tests.
+
+/// xorshift64*, which is plenty random for picking test inputs and keeps
+/// every run of a test identical.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator that always produces the same numbers for the same
+    /// `seed`.
+    pub fn new(seed: u64) -> Self {
+        // A state of zero would only ever produce zeros.
+        Self { state: seed | 1 }
+    }
+
+    /// Returns the next number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Returns one of `options`.
+    pub fn pick<T: Copy>(&mut self, options: &[T]) -> T {
+        options[self.below(options.len())]
+    }
+}
@@ -1,8 +1,12 @@
 mod ast_children;
 mod ast_node;
 mod ast_token;
+mod doc_comment_text;
+pub mod edit;
+mod edit_in_place;
 #[allow(dead_code)]
 mod generated;
+pub mod make;
 mod node_ext;
 pub mod support;
 #[allow(dead_code)]
@@ -18,5 +22,7 @@ use crate::{
 pub use ast_children::*;
 pub use ast_node::*;
 pub use ast_token::*;
+pub use doc_comment_text::{DocCommentText, DocLink};
+pub use edit_in_place::Removable;
 pub use generated::{nodes::*, tokens::*};
 pub use traits::*;
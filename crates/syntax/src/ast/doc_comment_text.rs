@@ -0,0 +1,198 @@
+//! See docs for `DocCommentText`.
+
+use pulldown_cmark::{BrokenLink, Options, Parser};
+
+use crate::{
+    ast::{self, AstToken},
+    TextRange, TextSize,
+};
+
+/// Documentation of an item, gathered from its doc comments.
+///
+/// Doc comments are Markdown, and refer to other symbols Dart-style, by
+/// putting their paths in square brackets (e.g. ``/// Wraps [`Foo.bar`].``).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocCommentText {
+    /// Contents of the doc comments, one line per line of doc comment, with
+    /// the comment prefixes and their common indentation stripped.
+    text: String,
+    links: Vec<DocLink>,
+}
+
+/// Link to a symbol from within a [DocCommentText], e.g. `[Foo]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocLink {
+    /// Path of the linked symbol, e.g. `Foo.bar` for ``[`Foo.bar`]``.
+    pub target: String,
+    /// Range of the link in the source file, brackets included.
+    pub range: TextRange,
+}
+
+/// A line of [DocCommentText::text], along with where it came from.
+struct Line {
+    /// Offset of the line in the gathered text.
+    text_start: usize,
+    /// Offset of the line in the source file.
+    source_start: TextSize,
+    len: usize,
+}
+
+impl DocCommentText {
+    /// Gathers the doc comments of `comments` into a single [DocCommentText],
+    /// or returns [None] if there are none.
+    pub(crate) fn from_comments(
+        comments: impl Iterator<Item = ast::Comment>,
+    ) -> Option<DocCommentText> {
+        let comments: Vec<ast::Comment> = comments.collect();
+
+        let mut source_lines = Vec::new();
+        for comment in &comments {
+            let Some(doc) = comment.doc_comment() else {
+                continue;
+            };
+
+            let mut start = comment.syntax().text_range().start() + TextSize::of(comment.prefix());
+            for line in doc.split('\n') {
+                source_lines.push((start, line.strip_suffix('\r').unwrap_or(line)));
+                start += TextSize::of(line) + TextSize::of('\n');
+            }
+        }
+
+        if source_lines.is_empty() {
+            return None;
+        }
+
+        let indent = source_lines
+            .iter()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(_, line)| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+
+        let mut text = String::new();
+        let mut lines = Vec::new();
+        for (source_start, line) in source_lines {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+
+            let line_indent = indent.min(line.len() - line.trim_start().len());
+            let line = &line[line_indent..];
+            lines.push(Line {
+                text_start: text.len(),
+                source_start: source_start + TextSize::try_from(line_indent).unwrap(),
+                len: line.len(),
+            });
+            text.push_str(line);
+        }
+
+        let links = find_links(&text)
+            .into_iter()
+            .filter_map(|(target, range)| {
+                let start = source_offset(&lines, range.start)?;
+                let end = source_offset(&lines, range.end)?;
+                Some(DocLink { target, range: TextRange::new(start, end) })
+            })
+            .collect();
+
+        Some(DocCommentText { text, links })
+    }
+
+    /// Returns the Markdown of the doc comments.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the links to other symbols, in order of appearance.
+    pub fn links(&self) -> &[DocLink] {
+        &self.links
+    }
+}
+
+/// Returns the target and range of every symbol link in the Markdown `text`.
+///
+/// Symbol links are the reference links whose reference is not defined
+/// anywhere (e.g. `[Foo]` rather than `[Foo]` along with a `[Foo]: url` line),
+/// and that contain no whitespace so that prose like `[0, 1]` is ignored.
+fn find_links(text: &str) -> Vec<(String, std::ops::Range<usize>)> {
+    let mut links = Vec::new();
+    let mut on_broken_link = |link: BrokenLink<'_>| {
+        let target = link.reference.trim_matches('`');
+        if !target.is_empty() && !target.contains(char::is_whitespace) {
+            links.push((target.to_owned(), link.span));
+        }
+        None
+    };
+
+    Parser::new_with_broken_link_callback(text, Options::empty(), Some(&mut on_broken_link))
+        .for_each(drop);
+
+    links
+}
+
+/// Maps `offset` within the text of `lines` back to an offset in the source
+/// file.
+fn source_offset(lines: &[Line], offset: usize) -> Option<TextSize> {
+    let line = lines
+        .iter()
+        .find(|line| (line.text_start..=line.text_start + line.len).contains(&offset))?;
+
+    Some(line.source_start + TextSize::try_from(offset - line.text_start).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{self, HasDocComments, SourceFile},
+        AstNode,
+    };
+
+    #[test]
+    fn doc_comment_text_and_links() {
+        let text = "// Not documentation.\n\
+                    /// Converts a [Celsius] value into [`Fahrenheit`].\n\
+                    ///\n\
+                    ///     let f = to_fahrenheit(c); // [NotALink]\n\
+                    ///\n\
+                    /// See [Temperature.convert], [the docs][Docs], but not\n\
+                    /// [a link](Url), [0, 1] or [Defined].\n\
+                    ///\n\
+                    /// [Defined]: https://example.com\n\
+                    fn to_fahrenheit(c: Celsius) -> Fahrenheit {}";
+        let file = SourceFile::parse(text).tree();
+        let func = file.syntax().descendants().find_map(ast::Fn::cast).unwrap();
+
+        let docs = func.doc_comment_text().unwrap();
+        assert_eq!(
+            docs.text(),
+            "Converts a [Celsius] value into [`Fahrenheit`].\n\
+             \n\
+             \x20   let f = to_fahrenheit(c); // [NotALink]\n\
+             \n\
+             See [Temperature.convert], [the docs][Docs], but not\n\
+             [a link](Url), [0, 1] or [Defined].\n\
+             \n\
+             [Defined]: https://example.com"
+        );
+
+        let links: Vec<_> =
+            docs.links().iter().map(|link| (link.target.as_str(), &text[link.range])).collect();
+        assert_eq!(
+            links,
+            [
+                ("Celsius", "[Celsius]"),
+                ("Fahrenheit", "[`Fahrenheit`]"),
+                ("Temperature.convert", "[Temperature.convert]"),
+                ("Docs", "[the docs][Docs]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_doc_comment_text() {
+        let file = SourceFile::parse("// Not documentation.\nfn f() {}").tree();
+        let func = file.syntax().descendants().find_map(ast::Fn::cast).unwrap();
+
+        assert_eq!(func.doc_comment_text(), None);
+    }
+}
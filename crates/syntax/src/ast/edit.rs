@@ -0,0 +1,81 @@
+//! Utilities for laying out nodes that get inserted into existing trees.
+
+use std::fmt;
+
+use crate::{
+    ast::{self, AstToken},
+    ted, NodeOrToken, SyntaxElement, SyntaxNode, WalkEvent,
+};
+
+/// Depth of indentation of a line, where every level is two spaces (Denim's
+/// canonical indentation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IndentLevel(pub u8);
+
+impl From<u8> for IndentLevel {
+    fn from(level: u8) -> IndentLevel {
+        IndentLevel(level)
+    }
+}
+
+impl fmt::Display for IndentLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SPACES: &str = "                                                                ";
+        let len = SPACES.len().min(self.0 as usize * 2);
+        f.write_str(&SPACES[..len])
+    }
+}
+
+impl IndentLevel {
+    /// Returns the indentation of the line that `node` starts on.
+    pub fn from_node(node: &SyntaxNode) -> IndentLevel {
+        match node.first_token() {
+            Some(it) => IndentLevel::from_element(&it.into()),
+            None => IndentLevel(0),
+        }
+    }
+
+    /// Returns the indentation of the line that `element` starts on.
+    pub fn from_element(element: &SyntaxElement) -> IndentLevel {
+        let mut token = match element {
+            NodeOrToken::Node(node) => match node.first_token() {
+                Some(it) => it,
+                None => return IndentLevel(0),
+            },
+            NodeOrToken::Token(token) => token.clone(),
+        };
+
+        while let Some(prev) = token.prev_token() {
+            if let Some(ws) = ast::Whitespace::cast(prev.clone()) {
+                if let Some((_, indent)) = ws.text().rsplit_once('\n') {
+                    return IndentLevel((indent.len() / 2) as u8);
+                }
+            }
+            token = prev;
+        }
+        IndentLevel(0)
+    }
+
+    /// Indents every line of `node` after the first one by this many extra
+    /// levels.
+    ///
+    /// `node` must be mutable.
+    pub fn increase_indent(self, node: &SyntaxNode) {
+        if self.0 == 0 {
+            return;
+        }
+
+        let tokens = node.preorder_with_tokens().filter_map(|event| match event {
+            WalkEvent::Leave(NodeOrToken::Token(it)) => Some(it),
+            _ => None,
+        });
+        for token in tokens.filter_map(ast::Whitespace::cast).collect::<Vec<_>>() {
+            if token.text().contains('\n') {
+                let new_ws = ast::make::tokens::whitespace(
+                    &token.syntax().text().replace('\n', &format!("\n{self}")),
+                );
+                ted::replace(token.syntax(), &new_ws);
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! Structural editing of mutable syntax trees, built on top of [`ted`].
+//!
+//! Every method here edits the tree that `self` belongs to in place, so that
+//! tree has to be mutable (see [`AstNode::clone_for_update`]), and so do the
+//! nodes that get inserted into it.
+
+use parser::T;
+
+use crate::{
+    ast::{self, make, AstNode},
+    ted::{self, Position},
+    SyntaxElement, SyntaxKind,
+};
+
+impl ast::SourceFile {
+    /// Returns the `MetaSection` of this file, adding an empty one (along with
+    /// the `---` that precedes it) to the end of the file if there is none.
+    pub fn get_or_create_meta_section(&self) -> ast::MetaSection {
+        if let Some(meta) = self.meta() {
+            return meta;
+        }
+
+        let meta = make::meta_section().clone_for_update();
+        let mut elements: Vec<SyntaxElement> = Vec::new();
+        let ends_with_newline = self
+            .syntax()
+            .last_token()
+            .is_some_and(|it| it.kind() == SyntaxKind::WHITESPACE && it.text().ends_with('\n'));
+        if self.syntax().first_token().is_some() && !ends_with_newline {
+            elements.push(make::tokens::single_newline().into());
+        }
+        elements.push(make::tokens::secsep().into());
+        elements.push(meta.syntax().clone().into());
+        ted::insert_all_raw(Position::last_child_of(self.syntax()), elements);
+        meta
+    }
+}
+
+impl ast::MetaSection {
+    /// Adds `dep` on a line of its own after every other dependency.
+    pub fn add_dep(&self, dep: ast::Dep) {
+        let anchor = match self.deps().last() {
+            Some(last) => Some(last.syntax().clone()),
+            None => self.syntax().children().filter(|it| it.kind() == SyntaxKind::ATTR).last(),
+        };
+        let position = match anchor {
+            Some(anchor) => Position::after(anchor),
+            None => Position::first_child_of(self.syntax()),
+        };
+        ted::insert_all_raw(
+            position,
+            vec![make::tokens::single_newline().into(), dep.syntax().clone().into()],
+        );
+    }
+}
+
+impl ast::CodeSection {
+    /// Adds `item` after every other item, separated from it by a blank line.
+    pub fn add_item(&self, item: ast::CodeItem) {
+        match self.code_items().last() {
+            Some(last) => ted::insert_all_raw(
+                Position::after(last.syntax()),
+                vec![make::tokens::blank_line().into(), item.syntax().clone().into()],
+            ),
+            None => ted::insert_all_raw(
+                Position::last_child_of(self.syntax()),
+                vec![item.syntax().clone().into()],
+            ),
+        }
+    }
+}
+
+impl ast::ParamList {
+    /// Adds `param` after every other parameter.
+    pub fn add_param(&self, param: ast::Param) {
+        let anchor = match self.params().last() {
+            Some(last) => Some(last.syntax().clone()),
+            None => self.self_param().map(|it| it.syntax().clone()),
+        };
+        match anchor {
+            // Reuse a trailing comma if there is one.
+            Some(anchor) => match next_non_ws(&anchor.clone().into()) {
+                Some(comma) if comma.kind() == T![,] => {
+                    ted::insert(Position::after(comma), param.syntax())
+                }
+                _ => ted::insert_all_raw(
+                    Position::after(anchor),
+                    vec![
+                        make::tokens::comma().into(),
+                        make::tokens::single_space().into(),
+                        param.syntax().clone().into(),
+                    ],
+                ),
+            },
+            None => match self.l_paren_token() {
+                Some(l_paren) => ted::insert_raw(Position::after(l_paren), param.syntax()),
+                None => ted::append_child_raw(self.syntax(), param.syntax()),
+            },
+        }
+    }
+}
+
+impl ast::UseTreeList {
+    /// Adds `use_tree` after every other use tree.
+    pub fn add_use_tree(&self, use_tree: ast::UseTree) {
+        match self.use_trees().last() {
+            Some(last) => ted::insert_all_raw(
+                Position::after(last.syntax()),
+                vec![
+                    make::tokens::comma().into(),
+                    make::tokens::single_space().into(),
+                    use_tree.syntax().clone().into(),
+                ],
+            ),
+            None => ted::append_child(self.syntax(), use_tree.syntax()),
+        }
+    }
+}
+
+/// Nodes that can be removed from the list that they are in, along with the
+/// separators and whitespace that only they need.
+pub trait Removable: AstNode {
+    fn remove(&self);
+}
+
+impl Removable for ast::Dep {
+    fn remove(&self) {
+        remove_with_preceding_ws(self);
+    }
+}
+
+impl Removable for ast::CodeItem {
+    fn remove(&self) {
+        remove_with_preceding_ws(self);
+    }
+}
+
+impl Removable for ast::Param {
+    fn remove(&self) {
+        remove_comma_separated(self);
+    }
+}
+
+impl Removable for ast::UseTree {
+    fn remove(&self) {
+        remove_comma_separated(self);
+    }
+}
+
+impl Removable for ast::Variant {
+    fn remove(&self) {
+        remove_comma_separated(self);
+    }
+}
+
+impl Removable for ast::RecordField {
+    fn remove(&self) {
+        remove_comma_separated(self);
+    }
+}
+
+/// Removes `node` along with the whitespace that separates it from whatever
+/// comes before it.
+fn remove_with_preceding_ws(node: &impl AstNode) {
+    if let Some(ws) = node.syntax().prev_sibling_or_token() {
+        if ws.kind() == SyntaxKind::WHITESPACE {
+            ted::remove(ws);
+        }
+    }
+    ted::remove(node.syntax());
+}
+
+/// Removes `node` from a comma-separated list, along with its comma and the
+/// whitespace that separates it from its neighbors.
+fn remove_comma_separated(node: &impl AstNode) {
+    let node: SyntaxElement = node.syntax().clone().into();
+    let mut removed = vec![node.clone()];
+
+    match next_non_ws(&node).filter(|it| it.kind() == T![,]) {
+        Some(comma) => {
+            removed.push(comma.clone());
+            let ws = match node.prev_sibling_or_token() {
+                Some(prev) if prev.kind() == SyntaxKind::WHITESPACE => Some(prev),
+                // The first element of a list keeps the whitespace in front of
+                // it, so drop the whitespace in front of the next one instead.
+                _ => comma.next_sibling_or_token(),
+            };
+            removed.extend(ws.filter(|it| it.kind() == SyntaxKind::WHITESPACE));
+        }
+        None => {
+            let mut prev = node.prev_sibling_or_token();
+            if let Some(ws) = prev.clone().filter(|it| it.kind() == SyntaxKind::WHITESPACE) {
+                prev = ws.prev_sibling_or_token();
+                removed.push(ws);
+            }
+            removed.extend(prev.filter(|it| it.kind() == T![,]));
+        }
+    }
+
+    for element in removed {
+        ted::remove(element);
+    }
+}
+
+fn next_non_ws(element: &SyntaxElement) -> Option<SyntaxElement> {
+    let mut next = element.next_sibling_or_token();
+    while let Some(element) = next {
+        if element.kind() != SyntaxKind::WHITESPACE {
+            return Some(element);
+        }
+        next = element.next_sibling_or_token();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{self, make, Removable},
+        AstNode,
+    };
+
+    fn parse(text: &str) -> ast::SourceFile {
+        ast::SourceFile::parse(text).tree().clone_for_update()
+    }
+
+    #[test]
+    fn add_and_remove_deps() {
+        let file = parse("fn f() {}\n");
+        let meta = file.get_or_create_meta_section();
+        meta.add_dep(
+            make::dep(
+                make::dep_path("std/fmt"),
+                make::use_([make::use_tree(make::path_from_text("Display"), None)]),
+            )
+            .clone_for_update(),
+        );
+        meta.add_dep(
+            make::dep(
+                make::dep_path("./util"),
+                make::show([make::use_tree(make::path_from_text("helper"), None)]),
+            )
+            .clone_for_update(),
+        );
+        assert_eq!(
+            file.to_string(),
+            "fn f() {}\n---\nfrom std/fmt use Display;\nfrom ./util show helper;"
+        );
+
+        let use_trees = meta.deps().next().unwrap().use_or_show().unwrap().use_tree_list().unwrap();
+        use_trees
+            .add_use_tree(make::use_tree(make::path_from_text("Debug"), None).clone_for_update());
+        meta.deps().nth(1).unwrap().remove();
+        assert_eq!(file.to_string(), "fn f() {}\n---\nfrom std/fmt use Display, Debug;");
+
+        use_trees.use_trees().next().unwrap().remove();
+        assert_eq!(file.to_string(), "fn f() {}\n---\nfrom std/fmt use Debug;");
+        assert!(ast::SourceFile::parse(&file.to_string()).errors().is_empty());
+    }
+
+    #[test]
+    fn add_item() {
+        let file = parse("fn f() {}\n");
+        let item = make::type_alias(None, make::name("T"), make::ty("int"));
+        file.code().unwrap().add_item(ast::CodeItem::TypeAlias(item.clone_for_update()));
+        assert_eq!(file.to_string(), "fn f() {}\n\ntype T = int;\n");
+    }
+
+    #[test]
+    fn add_and_remove_params() {
+        let file = parse("fn f() {}");
+        let param_list = file.syntax().descendants().find_map(ast::ParamList::cast).unwrap();
+        let param = |name| {
+            make::param(make::ident_pat(make::name(name)).into(), make::ty("int"))
+                .clone_for_update()
+        };
+
+        param_list.add_param(param("a"));
+        param_list.add_param(param("b"));
+        param_list.add_param(param("c"));
+        assert_eq!(param_list.to_string(), "(a: int, b: int, c: int)");
+
+        param_list.params().nth(1).unwrap().remove();
+        assert_eq!(param_list.to_string(), "(a: int, c: int)");
+        param_list.params().nth(1).unwrap().remove();
+        assert_eq!(param_list.to_string(), "(a: int)");
+        param_list.params().next().unwrap().remove();
+        assert_eq!(param_list.to_string(), "()");
+    }
+
+    #[test]
+    fn remove_variants() {
+        let file = parse("enum E {\n  A,\n  B(int),\n  C,\n}");
+        let variants = || file.syntax().descendants().filter_map(ast::Variant::cast);
+
+        variants().next().unwrap().remove();
+        assert_eq!(file.to_string(), "enum E {\n  B(int),\n  C,\n}");
+        variants().last().unwrap().remove();
+        assert_eq!(file.to_string(), "enum E {\n  B(int),\n}");
+    }
+}
@@ -1,6 +1,6 @@
 //! Generated by `cargo xtask gen grammar`, do not edit by hand.
 
-#![allow(non_snake_case)]
+#![allow(non_snake_case, clippy::enum_variant_names, clippy::wrong_self_convention)]
 use crate::{
     ast::{self, support, AstChildren, AstNode},
     SyntaxKind::{self, *},
@@ -368,6 +368,24 @@ impl ForExpr {
     pub fn in_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![in]) }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FormatString {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasAttrs for FormatString {}
+impl FormatString {
+    #[inline]
+    pub fn interpolations(&self) -> AstChildren<Interpolation> { support::children(&self.syntax) }
+    #[inline]
+    pub fn string_end_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![string_end])
+    }
+    #[inline]
+    pub fn string_start_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![string_start])
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GenericArgList {
     pub(crate) syntax: SyntaxNode,
@@ -468,6 +486,25 @@ impl InferType {
     pub fn underscore_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![_]) }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Interpolation {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Interpolation {
+    #[inline]
+    pub fn expr(&self) -> Option<Expr> { support::child(&self.syntax) }
+    #[inline]
+    pub fn dollar_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![$]) }
+    #[inline]
+    pub fn interpolation_end_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![interpolation_end])
+    }
+    #[inline]
+    pub fn interpolation_start_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![interpolation_start])
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LetExpr {
     pub(crate) syntax: SyntaxNode,
@@ -614,6 +651,22 @@ impl MapEntry {
     pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![:]) }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MapEntryPat {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasAttrs for MapEntryPat {}
+impl MapEntryPat {
+    #[inline]
+    pub fn key(&self) -> Option<Literal> { support::child(&self.syntax) }
+    #[inline]
+    pub fn pat(&self) -> Option<Pat> { support::child(&self.syntax) }
+    #[inline]
+    pub fn minus_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![-]) }
+    #[inline]
+    pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![:]) }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MapExpr {
     pub(crate) syntax: SyntaxNode,
@@ -889,6 +942,8 @@ impl Param {
     pub fn dotdotdot_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![...]) }
     #[inline]
     pub fn colon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![:]) }
+    #[inline]
+    pub fn mut_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![mut]) }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1045,7 +1100,10 @@ impl RangePat {}
 pub struct RecordExpr {
     pub(crate) syntax: SyntaxNode,
 }
+impl ast::HasAttrs for RecordExpr {}
 impl RecordExpr {
+    #[inline]
+    pub fn path(&self) -> Option<Path> { support::child(&self.syntax) }
     #[inline]
     pub fn record_expr_field_list(&self) -> Option<RecordExprFieldList> {
         support::child(&self.syntax)
@@ -1119,7 +1177,7 @@ impl RecordPat {
     #[inline]
     pub fn fields(&self) -> AstChildren<RecordPatField> { support::children(&self.syntax) }
     #[inline]
-    pub fn rest_pat(&self) -> Option<RestPat> { support::child(&self.syntax) }
+    pub fn path(&self) -> Option<Path> { support::child(&self.syntax) }
     #[inline]
     pub fn l_curly_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T!['{']) }
     #[inline]
@@ -1197,6 +1255,7 @@ pub struct RestPat {
     pub(crate) syntax: SyntaxNode,
 }
 impl ast::HasAttrs for RestPat {}
+impl ast::HasName for RestPat {}
 impl RestPat {
     #[inline]
     pub fn dotdot_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![..]) }
@@ -1725,6 +1784,7 @@ pub enum Expr {
     FieldExpr(FieldExpr),
     FnRefExpr(FnRefExpr),
     ForExpr(ForExpr),
+    FormatString(FormatString),
     IfExpr(IfExpr),
     IndexExpr(IndexExpr),
     LetExpr(LetExpr),
@@ -1740,6 +1800,7 @@ pub enum Expr {
     ParenExpr(ParenExpr),
     PathExpr(PathExpr),
     RangeExpr(RangeExpr),
+    RecordExpr(RecordExpr),
     ReturnExpr(ReturnExpr),
     SetExpr(SetExpr),
     TryExpr(TryExpr),
@@ -1767,6 +1828,7 @@ pub enum Pat {
     AliasedTuplePat(AliasedTuplePat),
     IdentPat(IdentPat),
     LiteralPat(LiteralPat),
+    MapEntryPat(MapEntryPat),
     OrPat(OrPat),
     ParenPat(ParenPat),
     PathPat(PathPat),
@@ -2229,6 +2291,20 @@ impl AstNode for ForExpr {
     #[inline]
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for FormatString {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool { kind == FORMAT_STRING }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for GenericArgList {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool { kind == GENERIC_ARG_LIST }
@@ -2341,6 +2417,20 @@ impl AstNode for InferType {
     #[inline]
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for Interpolation {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool { kind == INTERPOLATION }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for LetExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool { kind == LET_EXPR }
@@ -2481,6 +2571,20 @@ impl AstNode for MapEntry {
     #[inline]
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
 }
+impl AstNode for MapEntryPat {
+    #[inline]
+    fn can_cast(kind: SyntaxKind) -> bool { kind == MAP_ENTRY_PAT }
+    #[inline]
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
 impl AstNode for MapExpr {
     #[inline]
     fn can_cast(kind: SyntaxKind) -> bool { kind == MAP_EXPR }
@@ -3773,6 +3877,10 @@ impl From<ForExpr> for Expr {
     #[inline]
     fn from(node: ForExpr) -> Expr { Expr::ForExpr(node) }
 }
+impl From<FormatString> for Expr {
+    #[inline]
+    fn from(node: FormatString) -> Expr { Expr::FormatString(node) }
+}
 impl From<IfExpr> for Expr {
     #[inline]
     fn from(node: IfExpr) -> Expr { Expr::IfExpr(node) }
@@ -3833,6 +3941,10 @@ impl From<RangeExpr> for Expr {
     #[inline]
     fn from(node: RangeExpr) -> Expr { Expr::RangeExpr(node) }
 }
+impl From<RecordExpr> for Expr {
+    #[inline]
+    fn from(node: RecordExpr) -> Expr { Expr::RecordExpr(node) }
+}
 impl From<ReturnExpr> for Expr {
     #[inline]
     fn from(node: ReturnExpr) -> Expr { Expr::ReturnExpr(node) }
@@ -3874,6 +3986,7 @@ impl AstNode for Expr {
                 | FIELD_EXPR
                 | FN_REF_EXPR
                 | FOR_EXPR
+                | FORMAT_STRING
                 | IF_EXPR
                 | INDEX_EXPR
                 | LET_EXPR
@@ -3889,6 +4002,7 @@ impl AstNode for Expr {
                 | PAREN_EXPR
                 | PATH_EXPR
                 | RANGE_EXPR
+                | RECORD_EXPR
                 | RETURN_EXPR
                 | SET_EXPR
                 | TRY_EXPR
@@ -3912,6 +4026,7 @@ impl AstNode for Expr {
             FIELD_EXPR => Expr::FieldExpr(FieldExpr { syntax }),
             FN_REF_EXPR => Expr::FnRefExpr(FnRefExpr { syntax }),
             FOR_EXPR => Expr::ForExpr(ForExpr { syntax }),
+            FORMAT_STRING => Expr::FormatString(FormatString { syntax }),
             IF_EXPR => Expr::IfExpr(IfExpr { syntax }),
             INDEX_EXPR => Expr::IndexExpr(IndexExpr { syntax }),
             LET_EXPR => Expr::LetExpr(LetExpr { syntax }),
@@ -3927,6 +4042,7 @@ impl AstNode for Expr {
             PAREN_EXPR => Expr::ParenExpr(ParenExpr { syntax }),
             PATH_EXPR => Expr::PathExpr(PathExpr { syntax }),
             RANGE_EXPR => Expr::RangeExpr(RangeExpr { syntax }),
+            RECORD_EXPR => Expr::RecordExpr(RecordExpr { syntax }),
             RETURN_EXPR => Expr::ReturnExpr(ReturnExpr { syntax }),
             SET_EXPR => Expr::SetExpr(SetExpr { syntax }),
             TRY_EXPR => Expr::TryExpr(TryExpr { syntax }),
@@ -3952,6 +4068,7 @@ impl AstNode for Expr {
             Expr::FieldExpr(it) => &it.syntax,
             Expr::FnRefExpr(it) => &it.syntax,
             Expr::ForExpr(it) => &it.syntax,
+            Expr::FormatString(it) => &it.syntax,
             Expr::IfExpr(it) => &it.syntax,
             Expr::IndexExpr(it) => &it.syntax,
             Expr::LetExpr(it) => &it.syntax,
@@ -3967,6 +4084,7 @@ impl AstNode for Expr {
             Expr::ParenExpr(it) => &it.syntax,
             Expr::PathExpr(it) => &it.syntax,
             Expr::RangeExpr(it) => &it.syntax,
+            Expr::RecordExpr(it) => &it.syntax,
             Expr::ReturnExpr(it) => &it.syntax,
             Expr::SetExpr(it) => &it.syntax,
             Expr::TryExpr(it) => &it.syntax,
@@ -4050,6 +4168,10 @@ impl From<LiteralPat> for Pat {
     #[inline]
     fn from(node: LiteralPat) -> Pat { Pat::LiteralPat(node) }
 }
+impl From<MapEntryPat> for Pat {
+    #[inline]
+    fn from(node: MapEntryPat) -> Pat { Pat::MapEntryPat(node) }
+}
 impl From<OrPat> for Pat {
     #[inline]
     fn from(node: OrPat) -> Pat { Pat::OrPat(node) }
@@ -4094,6 +4216,7 @@ impl AstNode for Pat {
             ALIASED_TUPLE_PAT
                 | IDENT_PAT
                 | LITERAL_PAT
+                | MAP_ENTRY_PAT
                 | OR_PAT
                 | PAREN_PAT
                 | PATH_PAT
@@ -4111,6 +4234,7 @@ impl AstNode for Pat {
             ALIASED_TUPLE_PAT => Pat::AliasedTuplePat(AliasedTuplePat { syntax }),
             IDENT_PAT => Pat::IdentPat(IdentPat { syntax }),
             LITERAL_PAT => Pat::LiteralPat(LiteralPat { syntax }),
+            MAP_ENTRY_PAT => Pat::MapEntryPat(MapEntryPat { syntax }),
             OR_PAT => Pat::OrPat(OrPat { syntax }),
             PAREN_PAT => Pat::ParenPat(ParenPat { syntax }),
             PATH_PAT => Pat::PathPat(PathPat { syntax }),
@@ -4130,6 +4254,7 @@ impl AstNode for Pat {
             Pat::AliasedTuplePat(it) => &it.syntax,
             Pat::IdentPat(it) => &it.syntax,
             Pat::LiteralPat(it) => &it.syntax,
+            Pat::MapEntryPat(it) => &it.syntax,
             Pat::OrPat(it) => &it.syntax,
             Pat::ParenPat(it) => &it.syntax,
             Pat::PathPat(it) => &it.syntax,
@@ -4327,6 +4452,7 @@ impl AstNode for AnyHasAttrs {
                 | FN
                 | FN_REF_EXPR
                 | FOR_EXPR
+                | FORMAT_STRING
                 | IDENT_PAT
                 | IF_EXPR
                 | IMPL
@@ -4338,6 +4464,7 @@ impl AstNode for AnyHasAttrs {
                 | LITERAL
                 | LOOP_EXPR
                 | MAP_ENTRY
+                | MAP_ENTRY_PAT
                 | MAP_EXPR
                 | MATCH_ARM
                 | MATCH_ARM_LIST
@@ -4351,6 +4478,7 @@ impl AstNode for AnyHasAttrs {
                 | PAREN_EXPR
                 | PATH_EXPR
                 | RANGE_EXPR
+                | RECORD_EXPR
                 | RECORD_EXPR_FIELD
                 | RECORD_EXPR_FIELD_LIST
                 | RECORD_FIELD
@@ -4448,6 +4576,10 @@ impl From<ForExpr> for AnyHasAttrs {
     #[inline]
     fn from(node: ForExpr) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
 }
+impl From<FormatString> for AnyHasAttrs {
+    #[inline]
+    fn from(node: FormatString) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
+}
 impl From<IdentPat> for AnyHasAttrs {
     #[inline]
     fn from(node: IdentPat) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
@@ -4492,6 +4624,10 @@ impl From<MapEntry> for AnyHasAttrs {
     #[inline]
     fn from(node: MapEntry) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
 }
+impl From<MapEntryPat> for AnyHasAttrs {
+    #[inline]
+    fn from(node: MapEntryPat) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
+}
 impl From<MapExpr> for AnyHasAttrs {
     #[inline]
     fn from(node: MapExpr) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
@@ -4544,6 +4680,10 @@ impl From<RangeExpr> for AnyHasAttrs {
     #[inline]
     fn from(node: RangeExpr) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
 }
+impl From<RecordExpr> for AnyHasAttrs {
+    #[inline]
+    fn from(node: RecordExpr) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
+}
 impl From<RecordExprField> for AnyHasAttrs {
     #[inline]
     fn from(node: RecordExprField) -> AnyHasAttrs { AnyHasAttrs { syntax: node.syntax } }
@@ -4783,6 +4923,7 @@ impl AstNode for AnyHasName {
                 | RECORD_FIELD
                 | RECORD_TYPE_FIELD
                 | RENAME
+                | REST_PAT
                 | SELF_PARAM
                 | TRAIT
                 | TYPE_ALIAS
@@ -4821,6 +4962,10 @@ impl From<Rename> for AnyHasName {
     #[inline]
     fn from(node: Rename) -> AnyHasName { AnyHasName { syntax: node.syntax } }
 }
+impl From<RestPat> for AnyHasName {
+    #[inline]
+    fn from(node: RestPat) -> AnyHasName { AnyHasName { syntax: node.syntax } }
+}
 impl From<SelfParam> for AnyHasName {
     #[inline]
     fn from(node: SelfParam) -> AnyHasName { AnyHasName { syntax: node.syntax } }
@@ -5100,6 +5245,11 @@ impl std::fmt::Display for ForExpr {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for FormatString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for GenericArgList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -5140,6 +5290,11 @@ impl std::fmt::Display for InferType {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for Interpolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for LetExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -5190,6 +5345,11 @@ impl std::fmt::Display for MapEntry {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for MapEntryPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for MapExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
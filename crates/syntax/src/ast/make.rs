@@ -0,0 +1,643 @@
+//! Constructors for well-formed Denim nodes.
+//!
+//! Every constructor lays out the source text of the node that it builds,
+//! parses it, and then plucks the node out of the resulting tree. This way the
+//! parser stays the single source of truth for what Denim syntax trees look
+//! like. The built nodes are detached and immutable, so call
+//! [`AstNode::clone_for_update`] on them before inserting them into a mutable
+//! tree with [`ted`](crate::ted).
+//!
+//! Constructors take the nodes that they are made of rather than strings
+//! wherever possible, so that mistakes show up as type errors rather than as
+//! panics.
+
+use itertools::Itertools;
+use parser::T;
+
+use crate::{
+    ast::{self, AstNode, SourceFile},
+    validation::is_plain_name,
+    SyntaxError, SyntaxKind,
+};
+
+pub fn name(text: &str) -> ast::Name {
+    ast_from_text(&format!("fn {text}() {{}}"))
+}
+
+pub fn name_ref(text: &str) -> ast::NameRef {
+    ast_from_text(&format!("fn f() {{ {text}; }}"))
+}
+
+/// Builds a path out of the `::`-separated `text`, e.g. `fmt::Display`.
+pub fn path_from_text(text: &str) -> ast::Path {
+    ast_from_text(&format!("type T = {text};"))
+}
+
+pub fn path_from_segments(segments: impl IntoIterator<Item = ast::NameRef>) -> ast::Path {
+    path_from_text(&segments.into_iter().join("::"))
+}
+
+pub fn visibility_pub() -> ast::Visibility {
+    ast_from_text("pub fn f() {}")
+}
+
+// Types
+
+/// Builds the type written as `text`, e.g. `[str: i32]?`.
+pub fn ty(text: &str) -> ast::Type {
+    snippet_from_text(crate::parse_type(text), text)
+}
+
+pub fn ty_path(path: ast::Path) -> ast::Type {
+    ty(&path.to_string())
+}
+
+pub fn ty_option(ty: ast::Type) -> ast::Type {
+    self::ty(&format!("{ty}?"))
+}
+
+pub fn ty_result(ty: ast::Type) -> ast::Type {
+    self::ty(&format!("{ty}!"))
+}
+
+pub fn ty_list(ty: ast::Type) -> ast::Type {
+    self::ty(&format!("[{ty}]"))
+}
+
+pub fn ty_map(key: ast::Type, value: ast::Type) -> ast::Type {
+    ty(&format!("[{key}: {value}]"))
+}
+
+pub fn ty_tuple(types: impl IntoIterator<Item = ast::Type>) -> ast::Type {
+    ty(&tuple_text(types))
+}
+
+// Patterns
+
+pub fn ident_pat(name: ast::Name) -> ast::IdentPat {
+    pat_cast(&name.to_string())
+}
+
+pub fn wildcard_pat() -> ast::WildcardPat {
+    pat_cast("_")
+}
+
+pub fn literal_pat(text: &str) -> ast::LiteralPat {
+    pat_cast(text)
+}
+
+pub fn path_pat(path: ast::Path) -> ast::Pat {
+    pat(&path.to_string())
+}
+
+pub fn tuple_pat(pats: impl IntoIterator<Item = ast::Pat>) -> ast::TuplePat {
+    pat_cast(&tuple_text(pats))
+}
+
+fn pat(text: &str) -> ast::Pat {
+    snippet_from_text(crate::parse_pattern(text), text)
+}
+
+fn pat_cast<N: AstNode>(text: &str) -> N {
+    N::cast(pat(text).syntax().clone())
+        .unwrap_or_else(|| panic!("`{text}` is not a {}", std::any::type_name::<N>()))
+}
+
+// Expressions
+
+/// Builds the expression written as `text`, e.g. `a.b(1) + 2`.
+pub fn expr(text: &str) -> ast::Expr {
+    snippet_from_text(crate::parse_expr(text), text)
+}
+
+/// Builds a literal out of its source text, e.g. `"hi"` or `42`.
+pub fn expr_literal(text: &str) -> ast::Literal {
+    match expr(text) {
+        ast::Expr::Literal(it) => it,
+        _ => panic!("`{text}` is not a literal"),
+    }
+}
+
+pub fn expr_path(path: ast::Path) -> ast::Expr {
+    expr(&path.to_string())
+}
+
+pub fn expr_paren(expr: ast::Expr) -> ast::Expr {
+    self::expr(&format!("({expr})"))
+}
+
+/// Builds the binary expression `lhs op rhs`, where `op` is one of the
+/// operators that Denim has (e.g. `T![+]` or `T![and]`, but not `T![%]`).
+///
+/// Operands that would otherwise bind differently are put in parentheses, e.g.
+/// `a + b` times `c` becomes `(a + b) * c`.
+pub fn expr_bin_op(lhs: ast::Expr, op: SyntaxKind, rhs: ast::Expr) -> ast::Expr {
+    let op = match op {
+        T![or] => "or",
+        T![and] => "and",
+        T![==] => "==",
+        T![!=] => "!=",
+        T![===] => "===",
+        T![!==] => "!==",
+        T![<=] => "<=",
+        T![<] => "<",
+        T![+] => "+",
+        T![*] => "*",
+        T![-] => "-",
+        T![/] => "/",
+        T![=] => "=",
+        _ => panic!("{op:?} is not a Denim binary operator"),
+    };
+    let precedence = bin_op_precedence(op);
+    let is_right_assoc = op == "=";
+
+    let lhs = match operand_precedence(&lhs) {
+        Some((it, extends_right))
+            if it < precedence || it == precedence && (is_right_assoc || extends_right) =>
+        {
+            expr_paren(lhs)
+        }
+        _ => lhs,
+    };
+    let rhs = match operand_precedence(&rhs) {
+        Some((it, _)) if it < precedence || it == precedence && !is_right_assoc => expr_paren(rhs),
+        _ => rhs,
+    };
+    expr(&format!("{lhs} {op} {rhs}"))
+}
+
+/// Returns how tightly the parser binds the binary operator written as `op`;
+/// the higher, the tighter.
+fn bin_op_precedence(op: &str) -> u8 {
+    match op {
+        "=" | "+=" | "-=" | "*=" | "/=" | "%=" | "|=" | "&=" | "<<=" | ">>=" => 1,
+        ".." | "..=" => 2,
+        "or" => 3,
+        "and" => 4,
+        "==" | "!=" | "===" | "!==" | "<=" | "<" | ">=" | ">" => 5,
+        "|" => 6,
+        "&" => 8,
+        "<<" | ">>" => 9,
+        "+" | "-" => 10,
+        "*" | "/" | "%" => 11,
+        _ => u8::MAX,
+    }
+}
+
+/// Returns how tightly `expr` binds as the operand of a binary operator, and
+/// whether it swallows whatever follows it (like `not a` does), or `None` if
+/// it never needs parentheses.
+fn operand_precedence(expr: &ast::Expr) -> Option<(u8, bool)> {
+    match expr {
+        ast::Expr::BinExpr(it) => {
+            let op = it
+                .syntax()
+                .children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .filter(|it| !it.kind().is_trivia())
+                .map(|it| it.text().to_owned())
+                .collect::<String>();
+            Some((bin_op_precedence(&op), false))
+        }
+        ast::Expr::RangeExpr(_) => Some((bin_op_precedence(".."), true)),
+        ast::Expr::NotExpr(_) => Some((bin_op_precedence("=="), true)),
+        _ => None,
+    }
+}
+
+pub fn expr_call(f: ast::Expr, arg_list: ast::ArgList) -> ast::Expr {
+    expr(&format!("{f}{arg_list}"))
+}
+
+/// Builds `receiver.method(args)`, where `args` are laid out like in
+/// [`arg_list`].
+pub fn expr_method_call(
+    receiver: ast::Expr,
+    method: ast::NameRef,
+    args: impl IntoIterator<Item = (Option<ast::Name>, ast::Expr)>,
+) -> ast::Expr {
+    expr(&format!("{receiver}.{method}({})", args_text(args)))
+}
+
+pub fn expr_field(receiver: ast::Expr, field: ast::NameRef) -> ast::Expr {
+    expr(&format!("{receiver}.{field}"))
+}
+
+pub fn expr_return(expr: Option<ast::Expr>) -> ast::Expr {
+    match expr {
+        Some(expr) => self::expr(&format!("return {expr}")),
+        None => self::expr("return"),
+    }
+}
+
+/// Builds an `if` expression, where `else_branch` is either a block or another
+/// `if` expression.
+pub fn expr_if(
+    condition: ast::Expr,
+    then_branch: ast::BlockExpr,
+    else_branch: Option<ast::Expr>,
+) -> ast::Expr {
+    match else_branch {
+        Some(else_branch) => expr(&format!("if {condition} {then_branch} else {else_branch}")),
+        None => expr(&format!("if {condition} {then_branch}")),
+    }
+}
+
+pub fn expr_tuple(exprs: impl IntoIterator<Item = ast::Expr>) -> ast::Expr {
+    expr(&tuple_text(exprs))
+}
+
+/// Lays out the parenthesized `items` of a tuple. A single item gets a trailing
+/// comma, because without one it would just be an item in parentheses.
+fn tuple_text(items: impl IntoIterator<Item = impl std::fmt::Display>) -> String {
+    let mut len = 0;
+    let contents = items.into_iter().inspect(|_| len += 1).join(", ");
+    let trailing_comma = if len == 1 { "," } else { "" };
+    format!("({contents}{trailing_comma})")
+}
+
+/// Builds an argument list out of `args`, each an optional label and a value,
+/// e.g. `(age: 20, name)`.
+///
+/// # Panics
+///
+/// Panics if there is more than one argument and one of them has no label,
+/// unless its value is just a name, which labels itself.
+pub fn arg_list(args: impl IntoIterator<Item = (Option<ast::Name>, ast::Expr)>) -> ast::ArgList {
+    ast_from_text(&format!("fn f() {{ f({}); }}", args_text(args)))
+}
+
+#[track_caller]
+fn args_text(args: impl IntoIterator<Item = (Option<ast::Name>, ast::Expr)>) -> String {
+    let args = args.into_iter().collect::<Vec<_>>();
+    let args_len = args.len();
+    args.into_iter()
+        .map(|(label, value)| match label {
+            Some(label) => format!("{label}: {value}"),
+            None => {
+                assert!(
+                    args_len < 2 || is_plain_name(&value),
+                    "`{value}` needs a label, because there is more than one argument"
+                );
+                value.to_string()
+            }
+        })
+        .join(", ")
+}
+
+/// Builds a block out of `stmts` followed by `tail_expr`, with every one of
+/// them on its own line.
+pub fn block_expr(
+    stmts: impl IntoIterator<Item = ast::Stmt>,
+    tail_expr: Option<ast::Expr>,
+) -> ast::BlockExpr {
+    let mut text = "{\n".to_owned();
+    for stmt in stmts {
+        text += &format!("  {stmt}\n");
+    }
+    if let Some(tail_expr) = tail_expr {
+        text += &format!("  {tail_expr}\n");
+    }
+    text += "}";
+    if text == "{\n}" {
+        text = "{}".to_owned();
+    }
+    ast_from_text(&format!("fn f() {text}"))
+}
+
+// Statements
+
+pub fn let_stmt(pat: ast::Pat, ty: Option<ast::Type>, initializer: ast::Expr) -> ast::LetStmt {
+    let ty = ty.map(|ty| format!(": {ty}")).unwrap_or_default();
+    ast_from_text(&format!("fn f() {{ let {pat}{ty} = {initializer}; }}"))
+}
+
+pub fn expr_stmt(expr: ast::Expr) -> ast::ExprStmt {
+    ast_from_text(&format!("fn f() {{ {expr}; }}"))
+}
+
+// Items
+
+pub fn param(pat: ast::Pat, ty: ast::Type) -> ast::Param {
+    ast_from_text(&format!("fn f({pat}: {ty}) {{}}"))
+}
+
+pub fn param_list(params: impl IntoIterator<Item = ast::Param>) -> ast::ParamList {
+    ast_from_text(&format!("fn f({}) {{}}", params.into_iter().join(", ")))
+}
+
+pub fn ret_type(ty: ast::Type) -> ast::RetType {
+    ast_from_text(&format!("fn f() -> {ty} {{}}"))
+}
+
+pub fn fn_(
+    visibility: Option<ast::Visibility>,
+    name: ast::Name,
+    param_list: ast::ParamList,
+    ret_type: Option<ast::RetType>,
+    body: ast::BlockExpr,
+) -> ast::Fn {
+    let visibility = visibility.map(|it| format!("{it} ")).unwrap_or_default();
+    let ret_type = ret_type.map(|it| format!(" {it}")).unwrap_or_default();
+    ast_from_text(&format!("{visibility}fn {name}{param_list}{ret_type} {body}"))
+}
+
+pub fn record_field(name: ast::Name, ty: ast::Type) -> ast::RecordField {
+    ast_from_text(&format!("enum E {{ V {{ {name}: {ty} }} }}"))
+}
+
+pub fn record_field_list(
+    fields: impl IntoIterator<Item = ast::RecordField>,
+) -> ast::RecordFieldList {
+    ast_from_text(&format!("enum E {{ V {{ {} }} }}", fields.into_iter().join(", ")))
+}
+
+pub fn tuple_field_list(types: impl IntoIterator<Item = ast::Type>) -> ast::TupleFieldList {
+    ast_from_text(&format!("enum E {{ V({}) }}", types.into_iter().join(", ")))
+}
+
+pub fn variant(name: ast::Name, field_list: Option<ast::FieldList>) -> ast::Variant {
+    let field_list = match field_list {
+        Some(ast::FieldList::RecordFieldList(it)) => format!(" {it}"),
+        Some(ast::FieldList::TupleFieldList(it)) => it.to_string(),
+        None => String::new(),
+    };
+    ast_from_text(&format!("enum E {{ {name}{field_list} }}"))
+}
+
+pub fn variant_list(variants: impl IntoIterator<Item = ast::Variant>) -> ast::VariantList {
+    let variants = variants.into_iter().map(|it| format!("  {it},\n")).join("");
+    ast_from_text(&format!("enum E {{\n{variants}}}"))
+}
+
+pub fn enum_(
+    visibility: Option<ast::Visibility>,
+    name: ast::Name,
+    variant_list: ast::VariantList,
+) -> ast::Enum {
+    let visibility = visibility.map(|it| format!("{it} ")).unwrap_or_default();
+    ast_from_text(&format!("{visibility}enum {name} {variant_list}"))
+}
+
+pub fn type_alias(
+    visibility: Option<ast::Visibility>,
+    name: ast::Name,
+    ty: ast::Type,
+) -> ast::TypeAlias {
+    let visibility = visibility.map(|it| format!("{it} ")).unwrap_or_default();
+    ast_from_text(&format!("{visibility}type {name} = {ty};"))
+}
+
+pub fn assoc_item_list(fns: impl IntoIterator<Item = ast::Fn>) -> ast::AssocItemList {
+    let fns = fns.into_iter().map(|it| format!("  {it}\n")).join("");
+    ast_from_text(&format!("impl T {{\n{fns}}}"))
+}
+
+pub fn trait_(
+    visibility: Option<ast::Visibility>,
+    name: ast::Name,
+    assoc_item_list: ast::AssocItemList,
+) -> ast::Trait {
+    let visibility = visibility.map(|it| format!("{it} ")).unwrap_or_default();
+    ast_from_text(&format!("{visibility}trait {name} {assoc_item_list}"))
+}
+
+pub fn impl_(
+    trait_: Option<ast::Type>,
+    self_ty: ast::Type,
+    assoc_item_list: ast::AssocItemList,
+) -> ast::Impl {
+    let trait_ = trait_.map(|it| format!("{it} for ")).unwrap_or_default();
+    ast_from_text(&format!("impl {trait_}{self_ty} {assoc_item_list}"))
+}
+
+// Deps
+
+/// Builds a dependency path out of its source text, e.g. `std/fmt`,
+/// `./sibling` or `~/repo/dir`.
+pub fn dep_path(text: &str) -> ast::DepPath {
+    ast_from_text(&format!("---\nfrom {text} use x;"))
+}
+
+pub fn use_tree(path: ast::Path, rename: Option<ast::Name>) -> ast::UseTree {
+    let rename = rename.map(|it| format!(" as {it}")).unwrap_or_default();
+    ast_from_text(&format!("---\nfrom x use {path}{rename};"))
+}
+
+/// Builds `use` followed by `use_trees`, which must not be empty.
+pub fn use_(use_trees: impl IntoIterator<Item = ast::UseTree>) -> ast::Use {
+    ast_from_text(&format!("---\nfrom x use {};", use_trees.into_iter().join(", ")))
+}
+
+/// Builds `show` followed by `use_trees`, which must not be empty.
+pub fn show(use_trees: impl IntoIterator<Item = ast::UseTree>) -> ast::Use {
+    ast_from_text(&format!("---\nfrom x show {};", use_trees.into_iter().join(", ")))
+}
+
+/// Builds an empty `MetaSection`, which is what follows the `---` of a file.
+pub fn meta_section() -> ast::MetaSection {
+    ast_from_text("---\n")
+}
+
+pub fn dep(dep_path: ast::DepPath, use_or_show: ast::Use) -> ast::Dep {
+    ast_from_text(&format!("---\nfrom {dep_path} {use_or_show}"))
+}
+
+/// Parses `text` as a whole source file, and returns the first `N` in it.
+///
+/// # Panics
+///
+/// Panics if `text` does not parse cleanly or contains no `N`, which means
+/// that a constructor was given nodes that do not fit together.
+#[track_caller]
+fn ast_from_text<N: AstNode>(text: &str) -> N {
+    let parse = SourceFile::parse(text);
+    assert!(parse.errors().is_empty(), "`{text}` does not parse: {:?}", parse.errors());
+
+    let node = match parse.tree().syntax().descendants().find_map(N::cast) {
+        Some(it) => it,
+        None => panic!("`{text}` contains no {}", std::any::type_name::<N>()),
+    };
+    N::cast(node.syntax().clone_subtree()).unwrap()
+}
+
+#[track_caller]
+fn snippet_from_text<N: AstNode>((node, errors): (Option<N>, Vec<SyntaxError>), text: &str) -> N {
+    assert!(errors.is_empty(), "`{text}` does not parse: {errors:?}");
+
+    let node = node.unwrap_or_else(|| panic!("`{text}` does not parse"));
+    N::cast(node.syntax().clone_subtree()).unwrap()
+}
+
+/// Constructors for the tokens that separate nodes.
+///
+/// Unlike nodes, the tokens are mutable, so they can be inserted into trees as
+/// is.
+pub mod tokens {
+    use std::sync::LazyLock;
+
+    use parser::T;
+
+    use crate::{ast::SourceFile, AstNode, Parse, SyntaxToken};
+
+    static COMMA: LazyLock<Parse<SourceFile>> =
+        LazyLock::new(|| SourceFile::parse("fn f(a, b) {}"));
+
+    pub fn single_space() -> SyntaxToken {
+        whitespace(" ")
+    }
+
+    pub fn single_newline() -> SyntaxToken {
+        whitespace("\n")
+    }
+
+    pub fn blank_line() -> SyntaxToken {
+        whitespace("\n\n")
+    }
+
+    /// Builds a whitespace token out of `text`, which must be all whitespace.
+    pub fn whitespace(text: &str) -> SyntaxToken {
+        assert!(text.trim().is_empty(), "{text:?} is not whitespace");
+        SourceFile::parse(text).tree().syntax().clone_for_update().first_token().unwrap()
+    }
+
+    pub fn secsep() -> SyntaxToken {
+        SourceFile::parse("---").tree().syntax().clone_for_update().first_token().unwrap()
+    }
+
+    pub fn comma() -> SyntaxToken {
+        COMMA
+            .tree()
+            .syntax()
+            .clone_for_update()
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|it| it.kind() == T![,])
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors_build_well_formed_nodes() {
+        let int = || ty_path(path_from_text("int"));
+        let x = || expr_path(path_from_text("x"));
+
+        let body = block_expr(
+            [ast::Stmt::LetStmt(let_stmt(
+                ident_pat(name("y")).into(),
+                Some(ty_option(int())),
+                expr_bin_op(x(), T![+], expr_literal("1").into()),
+            ))],
+            Some(expr_method_call(
+                expr_path(path_from_text("y")),
+                name_ref("unwrap_or"),
+                [(None, expr_literal("0").into())],
+            )),
+        );
+        let func = fn_(
+            Some(visibility_pub()),
+            name("inc"),
+            param_list([param(ident_pat(name("x")).into(), int())]),
+            Some(ret_type(int())),
+            body,
+        );
+        assert_eq!(
+            func.to_string(),
+            "pub fn inc(x: int) -> int {\n  let y: int? = x + 1;\n  y.unwrap_or(0)\n}"
+        );
+
+        let variants = variant_list([
+            variant(name("A"), None),
+            variant(name("B"), Some(tuple_field_list([int(), ty_list(int())]).into())),
+            variant(
+                name("C"),
+                Some(record_field_list([record_field(name("c"), ty_map(int(), int()))]).into()),
+            ),
+        ]);
+        assert_eq!(
+            enum_(None, name("E"), variants).to_string(),
+            "enum E {\n  A,\n  B(int, [int]),\n  C { c: [int: int] },\n}"
+        );
+
+        assert_eq!(ty_tuple([int()]).to_string(), "(int,)");
+        assert_eq!(
+            tuple_pat([wildcard_pat().into(), literal_pat("1").into()]).to_string(),
+            "(_, 1)"
+        );
+
+        let dep = dep(
+            dep_path("~/lib/fmt"),
+            use_([use_tree(path_from_text("Display"), Some(name("Show")))]),
+        );
+        assert_eq!(dep.to_string(), "from ~/lib/fmt use Display as Show;");
+    }
+
+    #[test]
+    fn calls_label_their_arguments() {
+        let call = expr_call(
+            expr("story"),
+            arg_list([
+                (Some(name("age")), expr_literal("20").into()),
+                (None, expr("name")),
+                (Some(name("place")), expr_bin_op(expr("a"), T![+], expr("b"))),
+            ]),
+        );
+        assert_eq!(call.to_string(), "story(age: 20, name, place: a + b)");
+
+        let call = expr_method_call(
+            expr("x"),
+            name_ref("add"),
+            [(Some(name("a")), expr("1")), (Some(name("b")), expr("2"))],
+        );
+        assert_eq!(call.to_string(), "x.add(a: 1, b: 2)");
+
+        assert_eq!(arg_list([(None, expr("1"))]).to_string(), "(1)");
+    }
+
+    #[test]
+    fn tuples_of_one_have_a_trailing_comma() {
+        assert!(matches!(expr_tuple([expr("f(a: 1, b: 2)")]), ast::Expr::TupleExpr(_)));
+        assert_eq!(expr_tuple([expr("(1, 2)")]).to_string(), "((1, 2),)");
+        assert!(matches!(ty_tuple([ty("(int, str)")]), ast::Type::TupleType(_)));
+        assert_eq!(tuple_pat([pat("(a, b)")]).to_string(), "((a, b),)");
+        assert_eq!(expr_tuple([]).to_string(), "()");
+    }
+
+    #[test]
+    fn bin_ops_parenthesize_looser_operands() {
+        let round_trips = |built: ast::Expr, text: &str| {
+            assert_eq!(built.to_string(), text);
+            assert_eq!(format!("{:#?}", built.syntax()), format!("{:#?}", expr(text).syntax()));
+        };
+        round_trips(expr_bin_op(expr("a + b"), T![*], expr("c")), "(a + b) * c");
+        round_trips(expr_bin_op(expr("a"), T![-], expr("b - c")), "a - (b - c)");
+        round_trips(expr_bin_op(expr("a - b"), T![-], expr("c")), "a - b - c");
+        round_trips(expr_bin_op(expr("a * b"), T![+], expr("c * d")), "a * b + c * d");
+        round_trips(expr_bin_op(expr("not a"), T![==], expr("b")), "(not a) == b");
+        round_trips(expr_bin_op(expr("a..b"), T![<], expr("c")), "(a..b) < c");
+        round_trips(expr_bin_op(expr("a"), T![=], expr("b = c")), "a = b = c");
+        round_trips(expr_bin_op(expr("a = b"), T![=], expr("c")), "(a = b) = c");
+    }
+
+    #[test]
+    #[should_panic]
+    fn constructors_reject_malformed_nodes() {
+        expr_bin_op(expr("a"), T![;], expr("b"));
+    }
+
+    #[test]
+    #[should_panic = "is not a Denim binary operator"]
+    fn constructors_reject_excluded_operators() {
+        expr_bin_op(expr("a"), T![%], expr("b"));
+    }
+
+    #[test]
+    #[should_panic = "`2` needs a label"]
+    fn constructors_reject_unlabeled_arguments() {
+        arg_list([(Some(name("a")), expr("1")), (None, expr("2"))]);
+    }
+}
@@ -1,3 +1,36 @@
-use crate::ast;
+use tokenizer::EscapeError;
+
+use crate::{
+    ast::{self, AstNode, AstToken},
+    TextRange,
+};
 
 impl ast::HasAttrs for ast::AnyHasDocComments {}
+
+impl ast::Literal {
+    /// Returns the string literals that make up this literal, in order.
+    ///
+    /// Adjacent string literals (e.g. `"123" "xyz"`) are joined into a single
+    /// literal, so there can be more than one.
+    pub fn strings(&self) -> impl Iterator<Item = ast::String> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter_map(ast::String::cast)
+    }
+
+    /// Returns the value of this literal if it is a string literal, joining
+    /// the values of adjacent string literals together.
+    ///
+    /// Errors come with the range of the string literal that they are in.
+    pub fn string_value(&self) -> Option<Result<String, (TextRange, EscapeError)>> {
+        let mut strings = self.strings().peekable();
+        strings.peek()?;
+
+        Some(strings.try_fold(String::new(), |mut value, string| {
+            let piece = string.value().map_err(|e| (string.syntax().text_range(), e))?;
+            value.push_str(&piece);
+            Ok(value)
+        }))
+    }
+}
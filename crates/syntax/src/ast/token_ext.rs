@@ -1,9 +1,15 @@
 //! There are many AstNodes, but only a few tokens, so we hand-write them here.
 
-use std::{borrow::Cow, num::ParseIntError};
+use std::{
+    borrow::Cow,
+    num::{ParseFloatError, ParseIntError},
+};
 
 pub use always_assert::always;
-use tokenizer::{unescape_byte, unescape_char, unescape_unicode, EscapeError, Mode};
+use tokenizer::{
+    nfc_normalize_ident, unescape_byte, unescape_char, unescape_mixed, unescape_unicode,
+    EscapeError, MixedUnit, Mode,
+};
 
 use crate::{
     ast::{self, AstToken},
@@ -111,10 +117,19 @@ impl CommentKind {
     }
 }
 
+impl ast::Ident {
+    /// Returns the text of this identifier normalized to Unicode Normalization
+    /// Form C (NFC), so that identifiers that look the same compare equal no
+    /// matter how their characters were composed.
+    pub fn text_nfc(&self) -> Cow<'_, str> {
+        nfc_normalize_ident(self.text())
+    }
+}
+
 impl ast::Whitespace {
     pub fn spans_multiple_lines(&self) -> bool {
         let text = self.text();
-        text.find('\n').map_or(false, |idx| text[idx + 1..].contains('\n'))
+        text.find('\n').is_some_and(|idx| text[idx + 1..].contains('\n'))
     }
 }
 
@@ -133,9 +148,20 @@ impl QuoteOffsets {
             return None;
         }
 
+        // Multi-line string literals are delimited by `"""` on either side.
+        let multiline_quotes = r#"""""#;
+        let quotes_len = if literal[left_quote..].starts_with(multiline_quotes)
+            && literal[..=right_quote].ends_with(multiline_quotes)
+            && right_quote - left_quote >= 2 * multiline_quotes.len() - 1
+        {
+            multiline_quotes.len()
+        } else {
+            1
+        };
+
         let start = TextSize::from(0);
-        let left_quote = TextSize::try_from(left_quote).unwrap() + TextSize::of('"');
-        let right_quote = TextSize::try_from(right_quote).unwrap();
+        let left_quote = TextSize::try_from(left_quote + quotes_len).unwrap();
+        let right_quote = TextSize::try_from(right_quote + 1 - quotes_len).unwrap();
         let end = TextSize::of(literal);
 
         let res = QuoteOffsets {
@@ -152,6 +178,9 @@ pub trait IsString: AstToken {
     fn is_raw(&self) -> bool {
         self.text().starts_with(Self::RAW_PREFIX)
     }
+    fn is_multiline(&self) -> bool {
+        self.text().starts_with(r#"""""#)
+    }
     fn quote_offsets(&self) -> Option<QuoteOffsets> {
         let text = self.text();
         let offsets = QuoteOffsets::new(text)?;
@@ -209,6 +238,11 @@ impl IsString for ast::String {
 }
 
 impl ast::String {
+    /// Returns the value of this string literal with its escapes processed.
+    ///
+    /// Multi-line string literals are also dedented: the indentation of the
+    /// closing `"""` is stripped from every line, and so are the line breaks
+    /// right after the opening `"""` and right before the closing `"""`.
     pub fn value(&self) -> Result<Cow<'_, str>, EscapeError> {
         let text = self.text();
         let text_range = self.text_range_between_quotes().ok_or(EscapeError::LoneSlash)?;
@@ -216,29 +250,162 @@ impl ast::String {
         if self.is_raw() {
             return Ok(Cow::Borrowed(text));
         }
+        if self.is_multiline() {
+            let text = dedent_multiline_str(text);
+            return unescape_str(&text).map(|it| Cow::Owned(it.into_owned()));
+        }
+        unescape_str(text)
+    }
+}
+
+fn unescape_str(text: &str) -> Result<Cow<'_, str>, EscapeError> {
+    let mut buf = String::new();
+    let mut prev_end = 0;
+    let mut has_error = None;
+    unescape_unicode(text, Mode::Str, &mut |char_range, unescaped_char| match (
+        unescaped_char,
+        buf.capacity() == 0,
+    ) {
+        (Ok(c), false) => buf.push(c),
+        (Ok(_), true) if char_range.len() == 1 && char_range.start == prev_end => {
+            prev_end = char_range.end
+        }
+        (Ok(c), true) => {
+            buf.reserve_exact(text.len());
+            buf.push_str(&text[..prev_end]);
+            buf.push(c);
+        }
+        (Err(e), _) => has_error = Some(e),
+    });
+
+    match (has_error, buf.capacity() == 0) {
+        (Some(e), _) => Err(e),
+        (None, true) => Ok(Cow::Borrowed(text)),
+        (None, false) => Ok(Cow::Owned(buf)),
+    }
+}
+
+/// Dedents the contents of a multi-line string literal by the column of its
+/// closing `"""`, which only counts if it sits on a line of its own.
+fn dedent_multiline_str(text: &str) -> String {
+    let text = text.strip_prefix('\n').or_else(|| text.strip_prefix("\r\n")).unwrap_or(text);
+    let Some((body, indent)) = text.rsplit_once('\n') else {
+        return text.to_owned();
+    };
+    if !indent.chars().all(|c| c == ' ' || c == '\t') {
+        return text.to_owned();
+    }
+    let body = body.strip_suffix('\r').unwrap_or(body);
+
+    body.split('\n')
+        .map(|line| line.strip_prefix(indent).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl IsString for ast::ByteString {
+    const RAW_PREFIX: &'static str = "br";
+    const MODE: Mode = Mode::ByteStr;
+}
+
+impl ast::ByteString {
+    /// Returns the bytes of this byte string literal with its escapes
+    /// processed.
+    pub fn value(&self) -> Result<Cow<'_, [u8]>, EscapeError> {
+        let text = self.text();
+        let text_range = self.text_range_between_quotes().ok_or(EscapeError::LoneSlash)?;
+        let text = &text[text_range - self.syntax().text_range().start()];
+        if self.is_raw() {
+            return Ok(Cow::Borrowed(text.as_bytes()));
+        }
 
-        let mut buf = String::new();
+        let mut buf: Vec<u8> = Vec::new();
         let mut prev_end = 0;
         let mut has_error = None;
         unescape_unicode(text, Self::MODE, &mut |char_range, unescaped_char| match (
-            unescaped_char,
+            unescaped_char.map(|c| c as u8),
             buf.capacity() == 0,
         ) {
-            (Ok(c), false) => buf.push(c),
+            (Ok(b), false) => buf.push(b),
             (Ok(_), true) if char_range.len() == 1 && char_range.start == prev_end => {
                 prev_end = char_range.end
             }
-            (Ok(c), true) => {
+            (Ok(b), true) => {
                 buf.reserve_exact(text.len());
-                buf.push_str(&text[..prev_end]);
-                buf.push(c);
+                buf.extend_from_slice(&text.as_bytes()[..prev_end]);
+                buf.push(b);
             }
             (Err(e), _) => has_error = Some(e),
         });
 
         match (has_error, buf.capacity() == 0) {
             (Some(e), _) => Err(e),
-            (None, true) => Ok(Cow::Borrowed(text)),
+            (None, true) => Ok(Cow::Borrowed(text.as_bytes())),
+            (None, false) => Ok(Cow::Owned(buf)),
+        }
+    }
+}
+
+impl IsString for ast::CString {
+    const RAW_PREFIX: &'static str = "cr";
+    const MODE: Mode = Mode::CStr;
+
+    fn escaped_char_ranges(&self, cb: &mut dyn FnMut(TextRange, Result<char, EscapeError>)) {
+        let Some(text_range_no_quotes) = self.text_range_between_quotes() else {
+            return;
+        };
+
+        let start = self.syntax().text_range().start();
+        let text = &self.text()[text_range_no_quotes - start];
+        let offset = text_range_no_quotes.start() - start;
+
+        unescape_mixed(text, Self::MODE, &mut |range, unescaped| {
+            if let Some((s, e)) = range.start.try_into().ok().zip(range.end.try_into().ok()) {
+                // C strings can contain high bytes that are not chars, so only
+                // the ranges of the escapes are meaningful here.
+                cb(TextRange::new(s, e) + offset, unescaped.map(|_| ' '));
+            }
+        });
+    }
+}
+
+impl ast::CString {
+    /// Returns the bytes of this C string literal with its escapes processed,
+    /// not including its implicit trailing nul byte.
+    pub fn value(&self) -> Result<Cow<'_, [u8]>, EscapeError> {
+        let text = self.text();
+        let text_range = self.text_range_between_quotes().ok_or(EscapeError::LoneSlash)?;
+        let text = &text[text_range - self.syntax().text_range().start()];
+        if self.is_raw() {
+            return Ok(Cow::Borrowed(text.as_bytes()));
+        }
+
+        let mut buf: Vec<u8> = Vec::new();
+        let mut prev_end = 0;
+        let mut has_error = None;
+        let extend_unit = |buf: &mut Vec<u8>, unit: MixedUnit| match unit {
+            MixedUnit::Char(c) => buf.extend(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            MixedUnit::HighByte(b) => buf.push(b),
+        };
+        unescape_mixed(text, Self::MODE, &mut |char_range, unescaped| match (
+            unescaped,
+            buf.capacity() == 0,
+        ) {
+            (Ok(u), false) => extend_unit(&mut buf, u),
+            (Ok(_), true) if char_range.len() == 1 && char_range.start == prev_end => {
+                prev_end = char_range.end
+            }
+            (Ok(u), true) => {
+                buf.reserve_exact(text.len());
+                buf.extend_from_slice(&text.as_bytes()[..prev_end]);
+                extend_unit(&mut buf, u);
+            }
+            (Err(e), _) => has_error = Some(e),
+        });
+
+        match (has_error, buf.capacity() == 0) {
+            (Some(e), _) => Err(e),
+            (None, true) => Ok(Cow::Borrowed(text.as_bytes())),
             (None, false) => Ok(Cow::Owned(buf)),
         }
     }
@@ -326,6 +493,10 @@ impl ast::FloatNumber {
         }
     }
 
+    pub fn value(&self) -> Result<f64, ParseFloatError> {
+        self.value_string().parse()
+    }
+
     pub fn value_string(&self) -> String {
         let (text, _) = self.split_into_parts();
         text.replace('_', "")
@@ -383,3 +554,115 @@ impl ast::Byte {
         unescape_byte(text)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{
+        ast::{self, AstToken},
+        parse_expr, AstNode, TextRange,
+    };
+
+    fn literal<T: AstToken>(text: &str) -> T {
+        let (expr, errors) = parse_expr(text);
+        assert!(errors.is_empty(), "{errors:?}");
+        expr.unwrap().syntax().first_token().and_then(T::cast).unwrap()
+    }
+
+    #[test]
+    fn ident_text_nfc() {
+        let ident: ast::Ident = literal("cafe\u{301}");
+        assert_eq!(ident.text(), "cafe\u{301}");
+        assert_eq!(ident.text_nfc(), "caf\u{e9}");
+
+        let ident: ast::Ident = literal("caf\u{e9}");
+        assert!(matches!(ident.text_nfc(), Cow::Borrowed("caf\u{e9}")));
+    }
+
+    #[test]
+    fn int_number_suffix_and_value() {
+        let check = |text: &str, value: u128, suffix: Option<&str>| {
+            let int: ast::IntNumber = literal(text);
+            assert_eq!(int.value(), Ok(value), "{text}");
+            assert_eq!(int.suffix(), suffix, "{text}");
+        };
+
+        check("92", 92, None);
+        check("1u8", 1, Some("u8"));
+        check("123_i64", 123, Some("i64"));
+        check("0xffu8", 255, Some("u8"));
+        check("0x1f32", 0x1f32, None);
+        check("0b1010_u8", 10, Some("u8"));
+        check("1f32", 1, Some("f32"));
+    }
+
+    #[test]
+    fn float_number_suffix_and_value() {
+        let check = |text: &str, value: f64, suffix: Option<&str>| {
+            let float: ast::FloatNumber = literal(text);
+            assert_eq!(float.value(), Ok(value), "{text}");
+            assert_eq!(float.suffix(), suffix, "{text}");
+        };
+
+        check("1.5", 1.5, None);
+        check("1.2f32", 1.2, Some("f32"));
+        check("1.2345e-6f64", 1.2345e-6, Some("f64"));
+        check("1_000.0_1e3", 1_000.01e3, None);
+    }
+
+    #[test]
+    fn multiline_string_value() {
+        let check = |text: &str, value: &str| {
+            let string: ast::String = literal(text);
+            assert_eq!(string.value().as_deref(), Ok(value), "{text}");
+        };
+
+        check(r#""a \"b\" \$c""#, r#"a "b" $c"#);
+        check(r#""""""""#, "");
+        check(r#""""one line""""#, "one line");
+        check(r#""""a "quoted" word""""#, r#"a "quoted" word"#);
+        check(
+            "\"\"\"\n  take\n    all\n\n      the\n  space\\t\n  \"\"\"",
+            "take\n  all\n\n    the\nspace\t",
+        );
+        check("\"\"\"starts here\n  and keeps going\"\"\"", "starts here\n  and keeps going");
+    }
+
+    #[test]
+    fn byte_and_c_string_values() {
+        let byte: ast::Byte = literal(r"b'\x7f'");
+        assert_eq!(byte.value(), Ok(0x7f));
+
+        let check_byte_string = |text: &str, value: &[u8]| {
+            let string: ast::ByteString = literal(text);
+            assert_eq!(string.value().as_deref(), Ok(value), "{text}");
+        };
+
+        check_byte_string(r#"b"abc""#, b"abc");
+        check_byte_string(r#"b"\xff\n\"""#, b"\xff\n\"");
+        check_byte_string(r##"br#"\x00"#"##, br"\x00");
+
+        let check_c_string = |text: &str, value: &[u8]| {
+            let string: ast::CString = literal(text);
+            assert_eq!(string.value().as_deref(), Ok(value), "{text}");
+        };
+
+        check_c_string(r#"c"abc""#, b"abc");
+        check_c_string(r#"c"\xff\u{a5}""#, b"\xff\xc2\xa5");
+        check_c_string(r#"cr"\n""#, br"\n");
+    }
+
+    #[test]
+    fn adjacent_string_literals_value() {
+        let (expr, _) = parse_expr(r#""123""xyz" "\u{21}" "#);
+        let Some(ast::Expr::Literal(lit)) = expr else { panic!() };
+        assert_eq!(lit.strings().count(), 3);
+        assert_eq!(lit.string_value(), Some(Ok("123xyz!".to_owned())));
+
+        let (expr, _) = parse_expr(r#""ok" "\q""#);
+        let Some(ast::Expr::Literal(lit)) = expr else { panic!() };
+        let (range, _) = lit.string_value().unwrap().unwrap_err();
+        assert_eq!(range, TextRange::new(5.into(), 9.into()));
+    }
+}
@@ -77,6 +77,12 @@ pub trait HasDocComments: HasAttrs {
     fn doc_comments(&self) -> DocCommentIter {
         DocCommentIter { iter: self.syntax().children_with_tokens() }
     }
+
+    /// Gathers the doc comments attached to this node into a single
+    /// [ast::DocCommentText], or returns [None] if there are none.
+    fn doc_comment_text(&self) -> Option<ast::DocCommentText> {
+        self.doc_comments().doc_comment_text()
+    }
 }
 
 impl DocCommentIter {
@@ -84,17 +90,8 @@ impl DocCommentIter {
         DocCommentIter { iter: syntax_node.children_with_tokens() }
     }
 
-    #[cfg(test)]
-    pub fn doc_comment_text(self) -> Option<String> {
-        let docs = itertools::Itertools::join(
-            &mut self.filter_map(|comment| comment.doc_comment().map(ToOwned::to_owned)),
-            "\n",
-        );
-        if docs.is_empty() {
-            None
-        } else {
-            Some(docs)
-        }
+    pub fn doc_comment_text(self) -> Option<ast::DocCommentText> {
+        ast::DocCommentText::from_comments(self)
     }
 }
 
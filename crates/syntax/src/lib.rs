@@ -1,19 +1,225 @@
-mod ast;
+pub mod ast;
 mod attr;
 mod attr_kind;
 mod denim_language;
+mod line_index;
+mod parsing;
+mod syntax_error;
 mod syntax_node;
+pub mod ted;
+mod text_edit;
+mod validation;
 
 pub use crate::{
     ast::{AstNode, AstToken},
+    line_index::{LineCol, LineIndex, WideEncoding, WideLineCol},
+    parsing::{parse_expr, parse_pattern, parse_type},
+    syntax_error::SyntaxError,
     syntax_node::{
         DenimLanguage, PreorderWithTokens, SyntaxElement, SyntaxElementChildren, SyntaxNode,
         SyntaxNodeChildren, SyntaxToken,
     },
+    text_edit::Indel,
+};
+pub use parser::{
+    Severity, SuggestedFix, SyntaxKind, TokenizerDiagnostic, TokenizerDiagnosticCode, T,
 };
-pub use parser::{SyntaxKind, T};
 pub use rowan::{
     api::Preorder, Direction, GreenNode, NodeOrToken, SyntaxText, TextRange, TextSize,
     TokenAtOffset, WalkEvent,
 };
 pub use smol_str::{format_smolstr, SmolStr, ToSmolStr};
+
+use std::{fmt::Write, marker::PhantomData, sync::Arc};
+
+use parser::TopEntryPoint;
+
+/// `Parse` is the result of the parsing: a syntax tree, a collection of
+/// errors and the problems found while tokenizing.
+///
+/// Note that we always produce a syntax tree, even for completely invalid
+/// files.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Parse<T> {
+    green: GreenNode,
+    /// Errors of the tokenizer and of the parser.
+    errors: Option<Arc<[SyntaxError]>>,
+    /// Errors of [validation], which are recomputed for the whole tree rather
+    /// than carried over when reparsing.
+    validation_errors: Option<Arc<[SyntaxError]>>,
+    tokenizer_diagnostics: Option<Arc<[TokenizerDiagnostic]>>,
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Parse<T> {
+    fn clone(&self) -> Parse<T> {
+        Parse {
+            green: self.green.clone(),
+            errors: self.errors.clone(),
+            validation_errors: self.validation_errors.clone(),
+            tokenizer_diagnostics: self.tokenizer_diagnostics.clone(),
+            _ty: PhantomData,
+        }
+    }
+}
+
+impl<T> Parse<T> {
+    fn new(
+        green: GreenNode,
+        errors: Vec<SyntaxError>,
+        validation_errors: Vec<SyntaxError>,
+        tokenizer_diagnostics: Vec<TokenizerDiagnostic>,
+    ) -> Parse<T> {
+        fn shared<T>(items: Vec<T>) -> Option<Arc<[T]>> {
+            if items.is_empty() {
+                None
+            } else {
+                Some(items.into())
+            }
+        }
+
+        Parse {
+            green,
+            errors: shared(errors),
+            validation_errors: shared(validation_errors),
+            tokenizer_diagnostics: shared(tokenizer_diagnostics),
+            _ty: PhantomData,
+        }
+    }
+
+    /// Changes the type of the tree, keeping everything else.
+    fn retype<U>(self) -> Parse<U> {
+        Parse {
+            green: self.green,
+            errors: self.errors,
+            validation_errors: self.validation_errors,
+            tokenizer_diagnostics: self.tokenizer_diagnostics,
+            _ty: PhantomData,
+        }
+    }
+
+    pub fn syntax_node(&self) -> SyntaxNode {
+        SyntaxNode::new_root(self.green.clone())
+    }
+
+    /// Returns the errors found while tokenizing, parsing and then validating
+    /// the tree.
+    pub fn errors(&self) -> Vec<SyntaxError> {
+        let errors = self.errors.as_deref().unwrap_or_default();
+        let validation_errors = self.validation_errors.as_deref().unwrap_or_default();
+        errors.iter().chain(validation_errors).cloned().collect()
+    }
+
+    /// Returns every problem found while tokenizing, with its code, severity
+    /// and suggested fix.
+    ///
+    /// The errors among these are also part of [Parse::errors]. Their ranges
+    /// are relative to the start of the text, but [TokenizerDiagnostic::token]
+    /// is meaningless once the text has been reparsed.
+    pub fn tokenizer_diagnostics(&self) -> &[TokenizerDiagnostic] {
+        self.tokenizer_diagnostics.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the warnings about text that is valid, but likely to be
+    /// misread (e.g. identifiers that look just like other identifiers).
+    pub fn warnings(&self) -> impl Iterator<Item = &TokenizerDiagnostic> + '_ {
+        self.tokenizer_diagnostics().iter().filter(|it| it.severity == Severity::Warning)
+    }
+}
+
+impl<T: AstNode> Parse<T> {
+    /// Converts this parse result into a parse result for an untyped syntax
+    /// tree.
+    pub fn to_syntax(self) -> Parse<SyntaxNode> {
+        self.retype()
+    }
+
+    /// Gets the parsed syntax tree as a typed ast node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the root node cannot be casted into the typed ast node (e.g.
+    /// if it's an `ERROR` node).
+    pub fn tree(&self) -> T {
+        T::cast(self.syntax_node()).unwrap()
+    }
+
+    /// Converts from `Parse<T>` to [`Result<T, Vec<SyntaxError>>`].
+    pub fn ok(self) -> Result<T, Vec<SyntaxError>> {
+        match self.errors() {
+            errors if !errors.is_empty() => Err(errors),
+            _ => Ok(self.tree()),
+        }
+    }
+}
+
+impl Parse<SyntaxNode> {
+    pub fn cast<N: AstNode>(self) -> Option<Parse<N>> {
+        if N::cast(self.syntax_node()).is_some() {
+            Some(self.retype())
+        } else {
+            None
+        }
+    }
+}
+
+impl Parse<ast::SourceFile> {
+    /// Dumps the syntax tree followed by its errors, one per line, in the
+    /// format used by the parser tests.
+    pub fn debug_dump(&self) -> String {
+        let mut buf = format!("{:#?}", self.tree().syntax());
+        for err in self.errors() {
+            writeln!(buf, "error {:?}: {}", err.range(), err).unwrap();
+        }
+        for warning in self.warnings() {
+            writeln!(buf, "warning {:?}: {}", warning.range, warning.message).unwrap();
+        }
+        buf
+    }
+
+    /// Applies `indel` to the parsed text, reparsing as little as possible.
+    pub fn reparse(&self, indel: &Indel) -> Parse<ast::SourceFile> {
+        self.incremental_reparse(indel).unwrap_or_else(|| self.full_reparse(indel))
+    }
+
+    fn incremental_reparse(&self, indel: &Indel) -> Option<Parse<ast::SourceFile>> {
+        // Validation errors and confusable identifiers are recomputed for the
+        // whole new tree, so only the errors of the parser and the problems
+        // with single tokens get carried over.
+        let errors = self.errors.as_deref().unwrap_or_default().to_vec();
+        let diagnostics = self
+            .tokenizer_diagnostics()
+            .iter()
+            .filter(|it| it.code != TokenizerDiagnosticCode::ConfusableIdents)
+            .cloned()
+            .collect();
+        parsing::incremental_reparse(self.tree().syntax(), indel, errors, diagnostics).map(
+            |(green_node, errors, mut diagnostics, _reparsed_range)| {
+                let root = SyntaxNode::new_root(green_node.clone());
+                diagnostics.extend(parsing::confusable_idents(&root));
+                Parse::new(green_node, errors, validation::validate(&root), diagnostics)
+            },
+        )
+    }
+
+    fn full_reparse(&self, indel: &Indel) -> Parse<ast::SourceFile> {
+        let mut text = self.tree().syntax().text().to_string();
+        indel.apply(&mut text);
+        ast::SourceFile::parse(&text)
+    }
+}
+
+impl ast::SourceFile {
+    /// Parses `text` as a whole Denim source file.
+    pub fn parse(text: &str) -> Parse<ast::SourceFile> {
+        let (green, errors, mut diagnostics) =
+            parsing::parse_text_at(text, TopEntryPoint::SourceFile);
+        let root = SyntaxNode::new_root(green.clone());
+
+        let validation_errors = validation::validate(&root);
+        diagnostics.extend(parsing::confusable_idents(&root));
+
+        assert_eq!(root.kind(), SyntaxKind::SOURCE_FILE);
+        Parse::new(green, errors, validation_errors, diagnostics)
+    }
+}
@@ -0,0 +1,320 @@
+//! See docs for `LineIndex`.
+
+use std::collections::HashMap;
+
+use crate::{TextRange, TextSize};
+
+/// Maps flat [TextSize] offsets into `(line, col)` representation, and back.
+///
+/// Lines are only ever terminated by `\n`, so the `\r` of a `\r\n` line ending
+/// is considered to be the last character of its line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Offset of the start of every line, not including the first one.
+    newlines: Box<[TextSize]>,
+    /// Non-ASCII characters of every line that has any, keyed by line.
+    line_wide_chars: HashMap<u32, Box<[WideChar]>>,
+    /// Length of the indexed text.
+    len: TextSize,
+}
+
+/// Line and column of a position in text, where the column is measured in
+/// UTF-8 code units (i.e. bytes).
+///
+/// Both the line and the column are zero-based.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+/// Line and column of a position in text, where the column is measured in the
+/// code units of a [WideEncoding].
+///
+/// Both the line and the column are zero-based.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WideLineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+/// Text encodings that columns can be measured in besides UTF-8.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WideEncoding {
+    /// Columns count UTF-16 code units, like JavaScript and Kotlin strings do.
+    Utf16,
+    /// Columns count unicode scalar values (i.e. `char`s), like Swift's
+    /// `String.unicodeScalars` does.
+    Utf32,
+}
+
+impl WideEncoding {
+    /// Returns the number of code units needed to encode `c`.
+    pub fn measure(self, c: char) -> usize {
+        match self {
+            WideEncoding::Utf16 => c.len_utf16(),
+            WideEncoding::Utf32 => 1,
+        }
+    }
+}
+
+/// A non-ASCII character within a single line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct WideChar {
+    /// Start offset of the character within its line.
+    start: TextSize,
+    /// End offset of the character within its line.
+    end: TextSize,
+}
+
+impl WideChar {
+    /// Returns the length of this character in UTF-8.
+    fn len(self) -> TextSize {
+        self.end - self.start
+    }
+
+    /// Returns the length of this character in `enc`.
+    fn wide_len(self, enc: WideEncoding) -> u32 {
+        match enc {
+            WideEncoding::Utf16 if self.len() == TextSize::from(4) => 2,
+            WideEncoding::Utf16 | WideEncoding::Utf32 => 1,
+        }
+    }
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> LineIndex {
+        let mut newlines = Vec::new();
+        let mut line_wide_chars = HashMap::new();
+        let mut wide_chars = Vec::new();
+
+        let mut offset = TextSize::from(0);
+        let mut col = TextSize::from(0);
+        let mut line = 0;
+        for c in text.chars() {
+            let c_len = TextSize::of(c);
+            offset += c_len;
+
+            if c == '\n' {
+                newlines.push(offset);
+                if !wide_chars.is_empty() {
+                    line_wide_chars.insert(line, std::mem::take(&mut wide_chars).into());
+                }
+
+                col = TextSize::from(0);
+                line += 1;
+                continue;
+            }
+
+            if !c.is_ascii() {
+                wide_chars.push(WideChar { start: col, end: col + c_len });
+            }
+
+            col += c_len;
+        }
+
+        if !wide_chars.is_empty() {
+            line_wide_chars.insert(line, wide_chars.into());
+        }
+
+        LineIndex { newlines: newlines.into(), line_wide_chars, len: TextSize::of(text) }
+    }
+
+    /// Returns the length of the indexed text.
+    pub fn len(&self) -> TextSize {
+        self.len
+    }
+
+    /// Returns `true` if the indexed text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == TextSize::from(0)
+    }
+
+    /// Returns the number of lines in the indexed text, which is always at
+    /// least one.
+    pub fn line_count(&self) -> u32 {
+        self.newlines.len() as u32 + 1
+    }
+
+    /// Transforms `offset` into a [LineCol].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the end of the indexed text.
+    pub fn line_col(&self, offset: TextSize) -> LineCol {
+        self.try_line_col(offset).expect("offset is out of bounds")
+    }
+
+    /// Transforms `offset` into a [LineCol], or returns [None] if `offset` is
+    /// past the end of the indexed text.
+    pub fn try_line_col(&self, offset: TextSize) -> Option<LineCol> {
+        if offset > self.len {
+            return None;
+        }
+
+        let line = self.newlines.partition_point(|&it| it <= offset);
+        let line_start = self.line_start(line as u32)?;
+
+        Some(LineCol { line: line as u32, col: (offset - line_start).into() })
+    }
+
+    /// Transforms `line_col` into an offset, or returns [None] if `line_col`
+    /// does not point into the indexed text, is past the end of its line, or
+    /// points into the middle of a character.
+    ///
+    /// The end of a line is right before its `\n`, if it has one.
+    pub fn offset(&self, line_col: LineCol) -> Option<TextSize> {
+        let offset = self.line_start(line_col.line)? + TextSize::from(line_col.col);
+        let line_end = match self.newlines.get(line_col.line as usize) {
+            Some(&newline) => newline - TextSize::of('\n'),
+            None => self.len,
+        };
+        if offset > line_end {
+            return None;
+        }
+
+        let splits_char = self
+            .wide_chars(line_col.line)
+            .any(|c| u32::from(c.start) < line_col.col && line_col.col < u32::from(c.end));
+        (!splits_char).then_some(offset)
+    }
+
+    /// Returns the range of `line`, including its trailing `\n` if it has one.
+    pub fn line(&self, line: u32) -> Option<TextRange> {
+        let start = self.line_start(line)?;
+        let end = self.newlines.get(line as usize).copied().unwrap_or(self.len);
+
+        Some(TextRange::new(start, end))
+    }
+
+    /// Converts the UTF-8 column of `line_col` into a column of `enc`, or
+    /// returns [None] if `line_col` does not point into the indexed text or
+    /// points into the middle of a character.
+    pub fn to_wide(&self, enc: WideEncoding, line_col: LineCol) -> Option<WideLineCol> {
+        self.line_start(line_col.line)?;
+
+        let mut col = line_col.col;
+        for c in self.wide_chars(line_col.line) {
+            if u32::from(c.end) <= line_col.col {
+                col = col - u32::from(c.len()) + c.wide_len(enc);
+            } else if u32::from(c.start) < line_col.col {
+                return None;
+            } else {
+                break;
+            }
+        }
+
+        Some(WideLineCol { line: line_col.line, col })
+    }
+
+    /// Converts the `enc` column of `line_col` into a UTF-8 column, or returns
+    /// [None] if `line_col` does not point into the indexed text or points
+    /// into the middle of a character.
+    pub fn to_utf8(&self, enc: WideEncoding, line_col: WideLineCol) -> Option<LineCol> {
+        self.line_start(line_col.line)?;
+
+        // `col` is measured in UTF-8 up to the character being looked at, and
+        // in `enc` from there on.
+        let mut col = line_col.col;
+        for c in self.wide_chars(line_col.line) {
+            if col >= u32::from(c.start) + c.wide_len(enc) {
+                col = col + u32::from(c.len()) - c.wide_len(enc);
+            } else if col > u32::from(c.start) {
+                return None;
+            } else {
+                break;
+            }
+        }
+
+        Some(LineCol { line: line_col.line, col })
+    }
+
+    /// Returns the offset that `line` starts at, or [None] if there is no such
+    /// line.
+    fn line_start(&self, line: u32) -> Option<TextSize> {
+        match (line as usize).checked_sub(1) {
+            None => Some(TextSize::from(0)),
+            Some(newline) => self.newlines.get(newline).copied(),
+        }
+    }
+
+    /// Returns the non-ASCII characters of `line`, in order.
+    fn wide_chars(&self, line: u32) -> impl Iterator<Item = WideChar> + '_ {
+        self.line_wide_chars.get(&line).into_iter().flat_map(|it| it.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_col_and_offset_round_trip() {
+        let text = "fn main() {\r\n  let x = 1;\r\n}\n";
+        let index = LineIndex::new(text);
+
+        assert_eq!(index.line_count(), 4);
+
+        let check = |offset: u32, line: u32, col: u32| {
+            let line_col = LineCol { line, col };
+            assert_eq!(index.line_col(offset.into()), line_col, "{offset}");
+            assert_eq!(index.offset(line_col), Some(offset.into()), "{offset}");
+        };
+
+        check(0, 0, 0);
+        check(11, 0, 11);
+        // The `\r` of a `\r\n` belongs to the line that it ends.
+        check(12, 0, 12);
+        check(13, 1, 0);
+        check(15, 1, 2);
+        check(27, 2, 0);
+        check(29, 3, 0);
+
+        assert_eq!(index.try_line_col(30.into()), None);
+        assert_eq!(index.offset(LineCol { line: 4, col: 0 }), None);
+        // Columns past the end of a line do not wrap around to the next one.
+        assert_eq!(index.offset(LineCol { line: 0, col: 13 }), None);
+        assert_eq!(index.offset(LineCol { line: 2, col: 2 }), None);
+        assert_eq!(index.offset(LineCol { line: 3, col: 1 }), None);
+        assert_eq!(index.line(1), Some(TextRange::new(13.into(), 27.into())));
+        assert_eq!(index.line(3), Some(TextRange::new(29.into(), 29.into())));
+        assert_eq!(index.line(4), None);
+    }
+
+    #[test]
+    fn wide_columns() {
+        // `é` is 2 bytes in UTF-8 and 1 code unit in UTF-16, while `👖` is 4
+        // bytes in UTF-8 and 2 code units (a surrogate pair) in UTF-16.
+        let text = "let 👖 = \"é\";\r\nlet 👖👖 = 1;";
+        let index = LineIndex::new(text);
+
+        let check = |line: u32, utf8: u32, utf16: u32, utf32: u32| {
+            let line_col = LineCol { line, col: utf8 };
+            for (enc, col) in [(WideEncoding::Utf16, utf16), (WideEncoding::Utf32, utf32)] {
+                let wide = WideLineCol { line, col };
+                assert_eq!(index.to_wide(enc, line_col), Some(wide), "{line_col:?} {enc:?}");
+                assert_eq!(index.to_utf8(enc, wide), Some(line_col), "{wide:?} {enc:?}");
+            }
+        };
+
+        check(0, 0, 0, 0);
+        check(0, 4, 4, 4);
+        check(0, 8, 6, 5);
+        check(0, 12, 10, 9);
+        check(0, 14, 11, 10);
+        check(0, 17, 14, 13);
+        check(1, 4, 4, 4);
+        check(1, 8, 6, 5);
+        check(1, 12, 8, 6);
+        check(1, 17, 13, 11);
+
+        // Columns in the middle of a character have no equivalent.
+        assert_eq!(index.to_wide(WideEncoding::Utf16, LineCol { line: 0, col: 5 }), None);
+        assert_eq!(index.to_utf8(WideEncoding::Utf16, WideLineCol { line: 1, col: 5 }), None);
+        assert_eq!(index.to_wide(WideEncoding::Utf32, LineCol { line: 2, col: 0 }), None);
+        assert_eq!(index.offset(LineCol { line: 0, col: 4 }), Some(4.into()));
+        assert_eq!(index.offset(LineCol { line: 0, col: 5 }), None);
+        assert_eq!(index.offset(LineCol { line: 1, col: 9 }), None);
+        assert_eq!(index.offset(LineCol { line: 1, col: 12 }), Some(30.into()));
+    }
+}
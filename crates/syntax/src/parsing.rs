@@ -0,0 +1,232 @@
+//! Glue between the parser, which only knows about events, and rowan, which
+//! wants a green tree.
+
+mod reparsing;
+
+use parser::{StrStep, TokenizedStr, TokenizerDiagnostic, TokenizerDiagnosticCode, TopEntryPoint};
+use rowan::{GreenNode, GreenNodeBuilder, Language};
+use tokenizer::IdentSkeletons;
+
+use crate::{
+    ast, AstNode, DenimLanguage, SyntaxError, SyntaxKind, SyntaxNode, TextRange, TextSize,
+};
+
+pub(crate) use reparsing::incremental_reparse;
+
+/// Parses `text` as a single expression, e.g. `a.b(1) + 2`.
+///
+/// Returns the expression, if one could be found at all, along with every
+/// error encountered while parsing it. Trailing input that is not part of the
+/// expression is reported as an error.
+pub fn parse_expr(text: &str) -> (Option<ast::Expr>, Vec<SyntaxError>) {
+    parse_snippet(text, TopEntryPoint::Expr)
+}
+
+/// Parses `text` as a single type, e.g. `[str: i32]?`.
+///
+/// See [`parse_expr`] for what gets returned.
+pub fn parse_type(text: &str) -> (Option<ast::Type>, Vec<SyntaxError>) {
+    parse_snippet(text, TopEntryPoint::Type)
+}
+
+/// Parses `text` as a single pattern, e.g. `Some((a, ..rest))`.
+///
+/// See [`parse_expr`] for what gets returned.
+pub fn parse_pattern(text: &str) -> (Option<ast::Pat>, Vec<SyntaxError>) {
+    parse_snippet(text, TopEntryPoint::Pattern)
+}
+
+fn parse_snippet<N: AstNode>(text: &str, entry: TopEntryPoint) -> (Option<N>, Vec<SyntaxError>) {
+    let (green, errors, _) = parse_text_at(text, entry);
+    let root = SyntaxNode::new_root(green);
+    // Entry points wrap their construct in an `ERROR` node when there is input
+    // left over, so the construct may be one level down.
+    let node = N::cast(root.clone()).or_else(|| root.children().find_map(N::cast));
+    (node, errors)
+}
+
+pub(crate) fn parse_text_at(
+    text: &str,
+    entry: TopEntryPoint,
+) -> (GreenNode, Vec<SyntaxError>, Vec<TokenizerDiagnostic>) {
+    // Only a whole file can start with a byte order mark or a shebang.
+    let tokenized = match entry {
+        TopEntryPoint::SourceFile => TokenizedStr::new(text),
+        _ => TokenizedStr::new_fragment(text),
+    };
+    let input = tokenized.to_input();
+    let output = entry.parse(&input);
+    build_tree(tokenized, output)
+}
+
+/// Builds the tree described by `output`, returning it along with every error
+/// and every problem found while tokenizing.
+///
+/// Tokenizer errors show up in both, once as a plain [SyntaxError] and once
+/// with all of their details.
+pub(crate) fn build_tree(
+    tokenized: TokenizedStr<'_>,
+    output: parser::Output,
+) -> (GreenNode, Vec<SyntaxError>, Vec<TokenizerDiagnostic>) {
+    let mut builder = GreenNodeBuilder::new();
+    let mut errors = Vec::new();
+    tokenized.intersperse_trivia(&output, &mut |step| match step {
+        StrStep::Token { kind, text } => builder.token(DenimLanguage::kind_to_raw(kind), text),
+        StrStep::Enter { kind } => builder.start_node(DenimLanguage::kind_to_raw(kind)),
+        StrStep::Exit => builder.finish_node(),
+        StrStep::Error { msg, pos } => {
+            errors.push(SyntaxError::new_at_offset(msg, text_size(pos)));
+        }
+    });
+
+    for err in tokenized.errors() {
+        errors.push(SyntaxError::new(
+            err.message.clone(),
+            TextRange::new(text_size(err.range.start), text_size(err.range.end)),
+        ));
+    }
+
+    (builder.finish(), errors, tokenized.diagnostics().to_vec())
+}
+
+/// Warns about every identifier in `root` that looks just like a different
+/// identifier before it.
+///
+/// Unlike the other warnings, these depend on the whole file rather than on a
+/// single token.
+pub(crate) fn confusable_idents(root: &SyntaxNode) -> Vec<TokenizerDiagnostic> {
+    let mut skeletons = IdentSkeletons::default();
+    let mut warnings = Vec::new();
+    let idents = root
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| it.kind() == SyntaxKind::IDENT);
+    for ident in idents {
+        if let Some(other) = skeletons.check(ident.text()) {
+            let range = ident.text_range();
+            warnings.push(TokenizerDiagnostic::warning(
+                TokenizerDiagnosticCode::ConfusableIdents,
+                format!("identifier is easily confused with `{other}`"),
+                range.start().into()..range.end().into(),
+            ));
+        }
+    }
+    warnings
+}
+
+fn text_size(offset: usize) -> TextSize {
+    TextSize::try_from(offset).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Severity, SyntaxKind};
+
+    #[test]
+    fn parse_snippets() {
+        let (expr, errors) = parse_expr("a.b(1) + 2");
+        assert!(matches!(expr, Some(ast::Expr::BinExpr(_))));
+        assert!(errors.is_empty());
+
+        let (ty, errors) = parse_type("[str: i32]?");
+        assert!(matches!(ty, Some(ast::Type::OptionType(_))));
+        assert!(errors.is_empty());
+
+        let (pat, errors) = parse_pattern("Some((a, ..rest))");
+        assert!(matches!(pat, Some(ast::Pat::AliasedTuplePat(_))));
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn parse_snippet_with_trailing_input() {
+        let (expr, errors) = parse_expr("1 2");
+        assert_eq!(expr.unwrap().syntax().text(), "1");
+        assert_eq!(errors, vec![SyntaxError::new_at_offset("expected end of input", 1.into())]);
+
+        let (ty, errors) = parse_type("");
+        assert!(ty.is_none());
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn float_field_names_do_not_panic() {
+        for text in ["x.0.", "x.1e3", "x.1e"] {
+            let (expr, errors) = parse_expr(text);
+            assert_eq!(expr.unwrap().syntax().text(), text);
+            assert!(!errors.is_empty(), "{text:?} should have errors");
+        }
+
+        let parse = ast::SourceFile::parse("fn f() { g(x.0) }");
+        let edit = crate::Indel::insert(14.into(), ".".to_owned());
+        let reparsed = parse.reparse(&edit);
+        assert_eq!(reparsed.syntax_node().text(), "fn f() { g(x.0.) }");
+        assert!(!reparsed.errors().is_empty());
+    }
+
+    #[test]
+    fn source_file_round_trips() {
+        let text = "/// Docs.\nfn main() {\n    let x = t.0.1;\n}\n---\nfrom std use fmt;\n";
+        let parse = ast::SourceFile::parse(text);
+        assert!(parse.errors().is_empty(), "{:?}", parse.errors());
+        assert_eq!(parse.syntax_node().text(), text);
+
+        let file = parse.tree();
+        assert!(file.code().is_some());
+        assert!(file.meta().is_some());
+
+        // The doc comment belongs to the function it documents.
+        let fn_ = file.syntax().descendants().find(|it| it.kind() == SyntaxKind::FN).unwrap();
+        assert_eq!(fn_.first_token().unwrap().text(), "/// Docs.");
+
+        // `t.0.1` is a single float token that gets split into two field
+        // accesses.
+        let fields = file
+            .syntax()
+            .descendants()
+            .filter(|it| it.kind() == SyntaxKind::FIELD_EXPR)
+            .map(|it| it.text().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, ["t.0.1", "t.0"]);
+    }
+
+    #[test]
+    fn source_file_errors() {
+        let parse = ast::SourceFile::parse("fn f() { \"oops }");
+        let errors = parse.errors();
+        let errors = errors.iter().map(|it| it.message()).collect::<Vec<_>>();
+        assert!(errors.contains(&"Missing trailing `\"` symbol to terminate the string literal"));
+        assert!(parse.clone().ok().is_err());
+        assert_eq!(parse.syntax_node().text(), "fn f() { \"oops }");
+    }
+
+    #[test]
+    fn source_file_tokenizer_diagnostics() {
+        let text = "fn f() { let рау = pay + pаy + 1u7; }";
+        let parse = ast::SourceFile::parse(text);
+
+        let diagnostics = parse
+            .tokenizer_diagnostics()
+            .iter()
+            .map(|it| {
+                let fix = it.fix.as_ref().map(|fix| (&text[fix.range.clone()], &*fix.replacement));
+                (it.code.as_str(), it.severity, &text[it.range.clone()], fix)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            diagnostics,
+            [
+                ("L0014", Severity::Warning, "а", None),
+                ("L0015", Severity::Warning, "pаy", None),
+                ("L0006", Severity::Error, "u7", Some(("u7", ""))),
+                ("L0016", Severity::Warning, "pay", None),
+                ("L0016", Severity::Warning, "pаy", None),
+            ]
+        );
+
+        let warnings = parse.warnings().map(|it| it.message.as_str()).collect::<Vec<_>>();
+        assert_eq!(warnings[3], "identifier is easily confused with `рау`");
+        let errors = parse.errors().into_iter().map(|it| it.to_string()).collect::<Vec<_>>();
+        assert_eq!(errors, ["invalid suffix `u7` for number literal"]);
+    }
+}
@@ -0,0 +1,549 @@
+//! Implementation of incremental re-parsing.
+//!
+//! We use two simple strategies for this:
+//!   - if the edit modifies only a single token (like changing an identifier's
+//!     letter), we replace only this token.
+//!   - otherwise, we search for the nearest `{}` block which contains the edit
+//!     and try to parse only this block.
+
+use parser::{Reparser, Severity, TokenizedStr, TokenizerDiagnostic};
+use rowan::{GreenNode, GreenToken, Language};
+
+use crate::{
+    parsing::build_tree,
+    syntax_node::{DenimLanguage, SyntaxElement, SyntaxNode},
+    NodeOrToken, SyntaxError,
+    SyntaxKind::*,
+    TextRange, TextSize, T,
+};
+
+use crate::text_edit::Indel;
+
+/// The result of reparsing part of a tree: the new tree, its errors and
+/// tokenizer diagnostics, and the range of the old tree that was reparsed.
+type Reparsed = (GreenNode, Vec<SyntaxError>, Vec<TokenizerDiagnostic>, TextRange);
+
+pub(crate) fn incremental_reparse(
+    node: &SyntaxNode,
+    edit: &Indel,
+    errors: Vec<SyntaxError>,
+    diagnostics: Vec<TokenizerDiagnostic>,
+) -> Option<Reparsed> {
+    let (green, new_errors, new_diagnostics, old_range) =
+        reparse_token(node, edit).or_else(|| reparse_block(node, edit))?;
+    Some((
+        green,
+        merge_errors(errors, new_errors, old_range, edit),
+        merge_diagnostics(diagnostics, new_diagnostics, old_range, edit),
+        old_range,
+    ))
+}
+
+fn reparse_token(root: &SyntaxNode, edit: &Indel) -> Option<Reparsed> {
+    let prev_token = root.covering_element(edit.delete).as_token()?.clone();
+    let prev_token_kind = prev_token.kind();
+    match prev_token_kind {
+        WHITESPACE | COMMENT | IDENT | STRING => {
+            if prev_token_kind == WHITESPACE {
+                // Adding or removing a new line may extend the previous token
+                // or, by adding or removing a blank line, change which item a
+                // comment documents.
+                let deleted_range = edit.delete - prev_token.text_range().start();
+                if prev_token.text()[deleted_range].contains('\n') || edit.insert.contains('\n') {
+                    return None;
+                }
+            }
+
+            let mut new_text = get_text_after_edit(prev_token.clone().into(), edit);
+            let (new_token_kind, new_diagnostics) = TokenizedStr::single_token(&new_text)?;
+
+            if new_token_kind != prev_token_kind {
+                return None;
+            }
+
+            // Doc comments are attached to the items that they document, so
+            // turning a comment into a doc comment (or vice versa) can move it.
+            if prev_token_kind == COMMENT
+                && comment_flavor(prev_token.text()) != comment_flavor(&new_text)
+            {
+                return None;
+            }
+
+            // Check that edited token is not a part of the bigger token.
+            // E.g. if for source code `"str"a` the user removed the last `"`,
+            // then the string literal would swallow `a`. The next character can
+            // also make the edited token invalid, e.g. the `a` in `a"str"` is an
+            // unknown literal prefix.
+            if let Some(next_char) = root.text().char_at(prev_token.text_range().end()) {
+                new_text.push(next_char);
+                let with_next_char = TokenizedStr::new_fragment(&new_text);
+                let new_err = new_diagnostics.iter().find(|it| it.severity == Severity::Error);
+                if with_next_char.len() != 2
+                    || with_next_char.error(0) != new_err.map(|it| it.message.as_str())
+                {
+                    return None;
+                }
+                new_text.pop();
+            }
+
+            let new_token = GreenToken::new(DenimLanguage::kind_to_raw(prev_token_kind), &new_text);
+            let new_errors = new_diagnostics
+                .iter()
+                .filter(|it| it.severity == Severity::Error)
+                .map(|err| {
+                    let range = TextRange::new(
+                        TextSize::try_from(err.range.start).unwrap(),
+                        TextSize::try_from(err.range.end).unwrap(),
+                    );
+                    SyntaxError::new(err.message.clone(), range)
+                })
+                .collect();
+            Some((
+                prev_token.replace_with(new_token),
+                new_errors,
+                new_diagnostics,
+                prev_token.text_range(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn reparse_block(root: &SyntaxNode, edit: &Indel) -> Option<Reparsed> {
+    let (node, reparser) = find_reparsable_node(root, edit.delete)?;
+    let text = get_text_after_edit(node.clone().into(), edit);
+
+    let tokenized = TokenizedStr::new_fragment(text.as_str());
+    let parser_input = tokenized.to_input();
+    if !is_balanced(&tokenized) {
+        return None;
+    }
+
+    let tree_traversal = reparser.parse(&parser_input);
+
+    let (green, new_parser_errors, new_diagnostics) = build_tree(tokenized, tree_traversal);
+
+    // The reparser may give up before the closing `}`, or decide that the edit
+    // turned the node into a node of a different kind; either way the parent
+    // of the node has to be reparsed too.
+    if green.text_len() != TextSize::of(&text)
+        || DenimLanguage::kind_from_raw(green.kind()) != node.kind()
+    {
+        return None;
+    }
+
+    Some((node.replace_with(green), new_parser_errors, new_diagnostics, node.text_range()))
+}
+
+fn get_text_after_edit(element: SyntaxElement, edit: &Indel) -> String {
+    let edit = Indel::replace(edit.delete - element.text_range().start(), edit.insert.clone());
+
+    let mut text = match element {
+        NodeOrToken::Token(token) => token.text().to_owned(),
+        NodeOrToken::Node(node) => node.text().to_string(),
+    };
+    edit.apply(&mut text);
+    text
+}
+
+/// Mirrors how the tree builder decides whether a comment documents the item
+/// after it: inner doc comments never do, outer doc comments do even across a
+/// blank line, and plain comments only do when directly above the item.
+fn comment_flavor(text: &str) -> u8 {
+    if text.starts_with("//!") || text.starts_with("/*!") {
+        1
+    } else if !(text.starts_with("////") || text.starts_with("/***"))
+        && (text.starts_with("///") || text.starts_with("/**"))
+    {
+        2
+    } else {
+        0
+    }
+}
+
+fn find_reparsable_node(node: &SyntaxNode, range: TextRange) -> Option<(SyntaxNode, Reparser)> {
+    let node = node.covering_element(range);
+
+    node.ancestors().find_map(|node| {
+        let first_child = node.first_child_or_token().map(|it| it.kind());
+        let parent = node.parent().map(|it| it.kind());
+        Reparser::for_node(node.kind(), first_child, parent).map(|r| (node, r))
+    })
+}
+
+fn is_balanced(tokenized: &TokenizedStr<'_>) -> bool {
+    if tokenized.is_empty()
+        || tokenized.kind(0) != T!['{']
+        || tokenized.kind(tokenized.len() - 1) != T!['}']
+    {
+        return false;
+    }
+    let mut balance = 0usize;
+    for i in 1..tokenized.len() - 1 {
+        match tokenized.kind(i) {
+            T!['{'] => balance += 1,
+            T!['}'] => {
+                balance = match balance.checked_sub(1) {
+                    Some(b) => b,
+                    None => return false,
+                }
+            }
+            // The separator ends the code section no matter how deeply nested
+            // it is.
+            T![---] => return false,
+            _ => (),
+        }
+    }
+    balance == 0
+}
+
+fn merge_errors(
+    old_errors: Vec<SyntaxError>,
+    new_errors: Vec<SyntaxError>,
+    range_before_reparse: TextRange,
+    edit: &Indel,
+) -> Vec<SyntaxError> {
+    let mut res = Vec::new();
+
+    for old_err in old_errors {
+        let old_err_range = old_err.range();
+        if old_err_range.end() <= range_before_reparse.start() {
+            res.push(old_err);
+        } else if old_err_range.start() >= range_before_reparse.end() {
+            let inserted_len = TextSize::of(&edit.insert);
+            // Note: the parens keep the subtraction from underflowing.
+            res.push(old_err.with_range((old_err_range + inserted_len) - edit.delete.len()));
+        }
+    }
+    res.extend(new_errors.into_iter().map(|new_err| {
+        let offseted_range = new_err.range() + range_before_reparse.start();
+        new_err.with_range(offseted_range)
+    }));
+    res
+}
+
+/// Does for tokenizer diagnostics what [merge_errors] does for errors.
+fn merge_diagnostics(
+    old_diagnostics: Vec<TokenizerDiagnostic>,
+    new_diagnostics: Vec<TokenizerDiagnostic>,
+    range_before_reparse: TextRange,
+    edit: &Indel,
+) -> Vec<TokenizerDiagnostic> {
+    let reparse_start = usize::from(range_before_reparse.start());
+    let reparse_end = usize::from(range_before_reparse.end());
+    let shift = |diagnostic: &mut TokenizerDiagnostic, by: &dyn Fn(usize) -> usize| {
+        diagnostic.range = by(diagnostic.range.start)..by(diagnostic.range.end);
+        if let Some(fix) = &mut diagnostic.fix {
+            fix.range = by(fix.range.start)..by(fix.range.end);
+        }
+    };
+
+    let mut res = Vec::new();
+    for mut old_diagnostic in old_diagnostics {
+        if old_diagnostic.range.end <= reparse_start {
+            res.push(old_diagnostic);
+        } else if old_diagnostic.range.start >= reparse_end {
+            let inserted_len = edit.insert.len();
+            let deleted_len = usize::from(edit.delete.len());
+            shift(&mut old_diagnostic, &|offset| offset + inserted_len - deleted_len);
+            res.push(old_diagnostic);
+        }
+    }
+    res.extend(new_diagnostics.into_iter().map(|mut new_diagnostic| {
+        shift(&mut new_diagnostic, &|offset| offset + reparse_start);
+        new_diagnostic
+    }));
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stdx::rand::Rng;
+
+    use crate::{ast, AstNode, Parse};
+
+    /// Applies the edit marked by a pair of `$0`s in `before`, checking that
+    /// the reparsed fragment spanned `reparsed_len` bytes of the old tree and that the result
+    /// matches a from-scratch parse.
+    fn do_check(before: &str, replace_with: &str, reparsed_len: u32) {
+        let (start, rest) = before.split_once("$0").unwrap();
+        let (deleted, end) = rest.split_once("$0").unwrap();
+        let before = format!("{start}{deleted}{end}");
+        let delete = TextRange::at(TextSize::of(start), TextSize::of(deleted));
+        let edit = Indel::replace(delete, replace_with.to_owned());
+        let after = format!("{start}{replace_with}{end}");
+
+        let fully_reparsed = ast::SourceFile::parse(&after);
+        let incrementally_reparsed: Parse<ast::SourceFile> = {
+            let before = ast::SourceFile::parse(&before);
+            let (green, new_errors, new_diagnostics, range) = incremental_reparse(
+                before.tree().syntax(),
+                &edit,
+                before.errors.as_deref().unwrap_or_default().to_vec(),
+                before.tokenizer_diagnostics().to_vec(),
+            )
+            .expect("cannot incrementally reparse");
+            assert_eq!(range.len(), reparsed_len.into(), "reparsed fragment has wrong length");
+            let validation_errors =
+                crate::validation::validate(&SyntaxNode::new_root(green.clone()));
+            Parse::new(green, new_errors, validation_errors, new_diagnostics)
+        };
+
+        assert_eq!(incrementally_reparsed.debug_dump(), fully_reparsed.debug_dump());
+    }
+
+    #[test]
+    fn reparse_block_tests() {
+        do_check(
+            r"
+fn foo() {
+    let x = foo + $0bar$0
+}
+",
+            "baz",
+            3,
+        );
+        do_check(
+            r"
+fn foo() {
+    let x = foo$0 + bar$0
+}
+",
+            "baz",
+            25,
+        );
+        do_check(
+            r"
+enum Foo {
+    A { f: i32 },
+    B$0 = 1$0,
+}
+",
+            "(i32)",
+            32,
+        );
+        do_check(
+            r"
+fn foo() {
+    x.match {
+        a => 1,$0$0
+    };
+}
+",
+            " b => 2,",
+            23,
+        );
+        do_check(
+            r"
+impl Foo {
+    fn a() {}
+$0$0}
+",
+            "    fn b() {}\n",
+            17,
+        );
+        do_check(
+            r"
+fn main() {}
+---
+from foo use bar::{$0a$0, c};
+",
+            "b::*",
+            6,
+        );
+        do_check(
+            r"
+fn main() {}
+---
+from foo use bar::{a, $0c$0};
+",
+            "c, d",
+            6,
+        );
+    }
+
+    #[test]
+    fn reparse_token_tests() {
+        do_check(
+            r"$0$0
+fn foo() -> i32 { 1 }
+",
+            "   ",
+            1,
+        );
+        do_check(
+            r"
+fn foo() -> $0$0 {}
+",
+            "  ",
+            2,
+        );
+        do_check(
+            r"
+fn $0foo$0() -> i32 { 1 }
+",
+            "bar",
+            3,
+        );
+        do_check(
+            r"
+fn foo$0$0foo() {  }
+",
+            "bar",
+            6,
+        );
+        do_check(
+            r"
+fn foo /* $0$0 */ () {}
+",
+            "some comment",
+            6,
+        );
+        do_check(
+            r#"
+fn f() { "$0hello$0" }
+"#,
+            "world",
+            7,
+        );
+    }
+
+    #[test]
+    fn reparse_bom_and_shebang_in_the_middle_of_a_file() {
+        // Only the very start of a file can have a byte order mark or a
+        // shebang, so anywhere else they are errors.
+        do_check("fn f() {$0 $0a }", "\u{FEFF}", 5);
+        do_check("fn f() { $0$0a }", "#!", 5);
+        do_check("fn f() {\n$0$0    a\n}", "#!/usr/bin/env denim\n", 9);
+    }
+
+    #[test]
+    fn reparse_refuses_edits_that_change_the_shape_of_the_tree() {
+        let check = |before: &str, delete: TextRange, insert: &str| {
+            let parse = ast::SourceFile::parse(before);
+            let edit = Indel::replace(delete, insert.to_owned());
+            assert!(incremental_reparse(parse.tree().syntax(), &edit, vec![], vec![]).is_none());
+        };
+
+        // A block turning into an anonymous record.
+        check("fn f() { let a = { x }; }", TextRange::at(20.into(), 0.into()), ": 1");
+        // A blank line detaching a doc comment from its item.
+        check("/// Docs.\nfn f() {}", TextRange::at(9.into(), 0.into()), "\n");
+        // A comment turning into an inner doc comment.
+        check("// Docs.\nfn f() {}", TextRange::at(2.into(), 0.into()), "!");
+        // A separator ending the code section in the middle of a block.
+        check("fn f() { }", TextRange::at(9.into(), 0.into()), "---");
+    }
+
+    /// Makes thousands of random edits to some Denim source, checking that
+    /// every incremental reparse agrees with parsing the edited text from
+    /// scratch.
+    #[test]
+    fn reparse_fuzz() {
+        const SOURCES: &[&str] = &[
+            r#"
+/// Adds things.
+pub fn add(a: i32, b: i32) -> i32 {
+    let c = { x: a, y: b };
+    if a > b { a } else { c.x + c.y }
+}
+
+enum Shape {
+    Circle { radius: f64 },
+    Square(f64),
+}
+
+impl Shape {
+    fn area(self) -> f64 {
+        self.match {
+            Circle { radius } => radius * radius,
+            Square(side) => side * side,
+        }
+    }
+}
+---
+from std/math use {pi, sqrt as root};
+from ./shapes show Shape;
+"#,
+            r#"
+fn main() {
+    let list = [1, 2, ..rest];
+    let map = ["a": 1, "b": 2];
+    for x in list { print("{x}"); }
+    loop { break; }
+}
+
+trait Area {
+    fn area(self) -> f64;
+}
+"#,
+        ];
+        const SNIPPETS: &[&str] = &[
+            "a", "1", " ", "\n", "{", "}", "(", ")", ",", ";", ":", "x: 1", "..", "\"s\"", "//c\n",
+            "///", "fn", "let", "=>", "---", "_", "match", "{ a }", "[", "]", "pay", "рау", "pаy",
+            "\u{FEFF}", "#!",
+        ];
+
+        let mut rng = Rng::new(0x5eed);
+
+        for source in SOURCES {
+            let mut parse = ast::SourceFile::parse(source);
+            for _ in 0..500 {
+                let text = parse.syntax_node().text().to_string();
+                let boundaries =
+                    (0..=text.len()).filter(|&i| text.is_char_boundary(i)).collect::<Vec<_>>();
+                let start = rng.pick(&boundaries);
+                let end = (start + rng.below(4)).min(text.len());
+                let end = (end..=text.len()).find(|&i| text.is_char_boundary(i)).unwrap();
+                let insert = if rng.below(3) == 0 { "" } else { rng.pick(SNIPPETS) };
+
+                let edit = Indel::replace(
+                    TextRange::new(TextSize::of(&text[..start]), TextSize::of(&text[..end])),
+                    insert.to_owned(),
+                );
+                let mut after = text.clone();
+                edit.apply(&mut after);
+
+                let reparsed = parse.reparse(&edit);
+                let from_scratch = ast::SourceFile::parse(&after);
+                assert_eq!(
+                    format!("{:#?}", reparsed.syntax_node()),
+                    format!("{:#?}", from_scratch.syntax_node()),
+                    "\nedit: {edit:?}\nbefore:\n{text}\nafter:\n{after}",
+                );
+                let sorted_errors = |parse: &Parse<ast::SourceFile>| {
+                    let mut errors = parse
+                        .errors()
+                        .iter()
+                        .map(|it| (it.range().start(), it.range().end(), it.to_string()))
+                        .collect::<Vec<_>>();
+                    errors.sort();
+                    errors
+                };
+                assert_eq!(
+                    sorted_errors(&reparsed),
+                    sorted_errors(&from_scratch),
+                    "\nedit: {edit:?}\nbefore:\n{text}\nafter:\n{after}",
+                );
+                let sorted_diagnostics = |parse: &Parse<ast::SourceFile>| {
+                    let mut diagnostics = parse
+                        .tokenizer_diagnostics()
+                        .iter()
+                        .map(|it| (it.range.start, it.range.end, it.code, it.message.clone()))
+                        .collect::<Vec<_>>();
+                    diagnostics.sort_by_key(|it| (it.0, it.1, it.2.as_str(), it.3.clone()));
+                    diagnostics
+                };
+                assert_eq!(
+                    sorted_diagnostics(&reparsed),
+                    sorted_diagnostics(&from_scratch),
+                    "\nedit: {edit:?}\nbefore:\n{text}\nafter:\n{after}",
+                );
+
+                // Keep the text from growing into nonsense.
+                parse = if rng.below(20) == 0 { ast::SourceFile::parse(source) } else { reparsed };
+            }
+        }
+    }
+}
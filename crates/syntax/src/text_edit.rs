@@ -0,0 +1,32 @@
+//! See docs for `Indel`.
+
+use std::ops::Range;
+
+use crate::{TextRange, TextSize};
+
+/// A single atomic text edit: the text in `delete` gets replaced by `insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Indel {
+    pub insert: String,
+    /// Refers to offsets in the original text.
+    pub delete: TextRange,
+}
+
+impl Indel {
+    pub fn insert(offset: TextSize, text: String) -> Indel {
+        Indel::replace(TextRange::empty(offset), text)
+    }
+
+    pub fn delete(range: TextRange) -> Indel {
+        Indel::replace(range, String::new())
+    }
+
+    pub fn replace(range: TextRange, replace_with: String) -> Indel {
+        Indel { delete: range, insert: replace_with }
+    }
+
+    pub fn apply(&self, text: &mut String) {
+        let range: Range<usize> = self.delete.into();
+        text.replace_range(range, &self.insert);
+    }
+}
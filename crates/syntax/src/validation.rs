@@ -0,0 +1,311 @@
+//! Checks for Denim's own rules that the parser lets through.
+//!
+//! The parser accepts a little more than Denim allows (e.g. `a > b` or
+//! `f(1, 2)`) so that it can keep going and so that the resulting errors can
+//! explain what to write instead. This module walks a parsed tree and reports
+//! those constructs.
+
+use crate::{
+    ast::{self, AstNode, HasVisibility},
+    SyntaxError, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, T,
+};
+
+pub(crate) fn validate(root: &SyntaxNode) -> Vec<SyntaxError> {
+    let mut errors = Vec::new();
+    for node in root.descendants() {
+        match node.kind() {
+            SyntaxKind::BIN_EXPR => validate_bin_expr(&node, &mut errors),
+            SyntaxKind::ARG_LIST => {
+                let callee = node.parent().and_then(ast::CallExpr::cast).and_then(|it| it.expr());
+                if !callee.is_some_and(|it| is_constructor(&it)) {
+                    validate_arg_labels(&node, &mut errors);
+                }
+            }
+            SyntaxKind::METHOD_CALL_ARG_LIST => validate_arg_labels(&node, &mut errors),
+            SyntaxKind::IMPL => {
+                let fns = ast::Impl::cast(node).and_then(|it| it.assoc_item_list());
+                for fn_ in fns.iter().flat_map(|it| it.fns()) {
+                    if let Some(vis) = fn_.visibility() {
+                        errors.push(SyntaxError::new(
+                            "functions in an `impl` are exactly as visible as the type they \
+                             belong to, so they cannot be `pub`",
+                            vis.syntax().text_range(),
+                        ));
+                    }
+                }
+            }
+            SyntaxKind::PARAM => {
+                if let Some(mut_token) = ast::Param::cast(node).and_then(|it| it.mut_token()) {
+                    errors.push(SyntaxError::new(
+                        "only `self` can be a `mut` parameter: give the parameter a `mut` type \
+                         instead, e.g. `list: mut [int]`",
+                        mut_token.text_range(),
+                    ));
+                }
+            }
+            SyntaxKind::META_SECTION => {
+                for child in node.children() {
+                    if ast::CodeItem::can_cast(child.kind()) || child.kind() == SyntaxKind::LET_STMT
+                    {
+                        errors.push(SyntaxError::new(
+                            "code items must come before `---`, which is followed only by \
+                             dependencies",
+                            range_without_leading_trivia(&child),
+                        ));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    errors
+}
+
+/// Reports the operators that Denim leaves out in favor of methods or of
+/// other operators.
+fn validate_bin_expr(node: &SyntaxNode, errors: &mut Vec<SyntaxError>) {
+    // The operands are nodes, so the tokens are the (possibly composite)
+    // operator.
+    let op = node
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| !it.kind().is_trivia())
+        .collect::<Vec<_>>();
+    let (Some(first), Some(last)) = (op.first(), op.last()) else {
+        return;
+    };
+    let range = TextRange::new(first.text_range().start(), last.text_range().end());
+    let kinds = op.iter().map(SyntaxToken::kind).collect::<Vec<_>>();
+
+    let message = match kinds.as_slice() {
+        [T![>]] => "Denim has no `>` operator: swap the operands and use `<` instead".to_owned(),
+        [T![>], T![=]] => {
+            "Denim has no `>=` operator: swap the operands and use `<=` instead".to_owned()
+        }
+        [T![%]] => {
+            "Denim has no `%` operator: use the `mod` method instead, e.g. `a.mod(3)`".to_owned()
+        }
+        [T![|]] => "Denim has no bitwise operators: use `or` to combine booleans, or the \
+                    `bitwise_or` method for numbers"
+            .to_owned(),
+        [T![&]] => "Denim has no bitwise operators: use `and` to combine booleans, or the \
+                    `bitwise_and` method for numbers"
+            .to_owned(),
+        [T![<], T![<]] => {
+            "Denim has no bitwise operators: use the `bitwise_shift` method instead, e.g. \
+             `a.bitwise_shift(2)`"
+                .to_owned()
+        }
+        [T![>], T![>]] => {
+            "Denim has no bitwise operators: use the `bitwise_unshift` method instead, e.g. \
+             `a.bitwise_unshift(2)`"
+                .to_owned()
+        }
+        [T![<], T![<=]] => compound_assignment_message(node, "<<"),
+        [T![>], T![>], T![=]] => compound_assignment_message(node, ">>"),
+        [_, T![=]] => compound_assignment_message(node, op[0].text()),
+        _ => return,
+    };
+    errors.push(SyntaxError::new(message, range));
+}
+
+/// Explains how to write the compound assignment `node`, which uses `op=`, out
+/// without any of the operators that Denim leaves out.
+fn compound_assignment_message(node: &SyntaxNode, op: &str) -> String {
+    let lhs = node.children().find_map(ast::Expr::cast).map(|it| it.syntax().to_string());
+    let lhs = lhs.as_deref().filter(|it| !it.contains('\n')).unwrap_or("a");
+    let rhs = match op {
+        "%" => format!("{lhs}.mod(3)"),
+        "|" => format!("{lhs}.bitwise_or(3)"),
+        "&" => format!("{lhs}.bitwise_and(3)"),
+        "<<" => format!("{lhs}.bitwise_shift(3)"),
+        ">>" => format!("{lhs}.bitwise_unshift(3)"),
+        _ => format!("{lhs} {op} 3"),
+    };
+    format!("Denim has no `{op}=` operator: write the assignment out instead, e.g. `{lhs} = {rhs}`")
+}
+
+/// Reports the unlabeled arguments of a call with more than one argument.
+///
+/// An argument that is just a name, like `age` in `story(age, name)`, labels
+/// itself.
+fn validate_arg_labels(arg_list: &SyntaxNode, errors: &mut Vec<SyntaxError>) {
+    let args = arg_list
+        .children()
+        .filter(|it| matches!(it.kind(), SyntaxKind::ARG | SyntaxKind::MAYBE_SELFIFIED_ARG))
+        .collect::<Vec<_>>();
+    if args.len() < 2 {
+        return;
+    }
+
+    for arg in args {
+        // A selfified argument, like `&other`, is labeled by its name.
+        let Some(arg) = ast::Arg::cast(arg.clone())
+            .or_else(|| ast::MaybeSelfifiedArg::cast(arg).and_then(|it| it.arg()))
+        else {
+            continue;
+        };
+        let value = arg.syntax().children().find_map(ast::Expr::cast);
+        if arg.label().is_some() || value.as_ref().is_some_and(is_plain_name) {
+            continue;
+        }
+        errors.push(SyntaxError::new(
+            "arguments must be labeled when there is more than one of them, e.g. \
+             `story(age: 20, name: \"Galois\")`",
+            arg.syntax().text_range(),
+        ));
+    }
+}
+
+/// Returns whether `expr` is a single name, like `age`, but not `a::age`.
+pub(crate) fn is_plain_name(expr: &ast::Expr) -> bool {
+    let ast::Expr::PathExpr(path_expr) = expr else {
+        return false;
+    };
+    path_expr.path().is_some_and(|path| {
+        path.qualifier().is_none()
+            && path.segment().is_some_and(|it| it.syntax().children().count() == 1)
+    })
+}
+
+/// Returns whether `callee` names an enum variant or a type, like `Some` or
+/// `a::Pair`, whose fields are positional rather than labeled.
+fn is_constructor(callee: &ast::Expr) -> bool {
+    let ast::Expr::PathExpr(path_expr) = callee else {
+        return false;
+    };
+    path_expr
+        .path()
+        .and_then(|it| it.segment())
+        .and_then(|it| it.name_ref())
+        .and_then(|it| it.ident_token())
+        .is_some_and(|it| it.text().starts_with(char::is_uppercase))
+}
+
+/// Returns the range of `node` without the comments and whitespace that the
+/// parser attached to its start.
+fn range_without_leading_trivia(node: &SyntaxNode) -> TextRange {
+    let start = node
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|it| !it.kind().is_trivia())
+        .map_or(node.text_range().start(), |it| it.text_range().start());
+    TextRange::new(start, node.text_range().end())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast;
+
+    /// Parses `text` and returns the text that each error points at, along
+    /// with its message.
+    fn errors(text: &str) -> Vec<(String, String)> {
+        ast::SourceFile::parse(text)
+            .errors()
+            .iter()
+            .map(|it| (text[it.range()].to_owned(), it.message().to_owned()))
+            .collect()
+    }
+
+    fn assert_valid(text: &str) {
+        assert_eq!(errors(text), [], "{text:?} should be valid");
+    }
+
+    #[test]
+    fn excluded_operators() {
+        let text = "fn f() { a > b; a >= b; a % 2; a | b; a & b; a << 1; a >> 1; }";
+        let errors = errors(text);
+        let ranges = errors.iter().map(|(range, _)| range.as_str()).collect::<Vec<_>>();
+        assert_eq!(ranges, [">", ">=", "%", "|", "&", "<<", ">>"]);
+        assert!(errors[0].1.contains("use `<` instead"));
+        assert!(errors[1].1.contains("use `<=` instead"));
+        assert!(errors[2].1.contains("`a.mod(3)`"));
+        assert!(errors[5].1.contains("`bitwise_shift`"));
+
+        assert_valid("fn f() { a < b and b <= c or a == 1 + 2; }");
+    }
+
+    #[test]
+    fn compound_assignment() {
+        assert_eq!(
+            errors("fn f() { count += 3; }"),
+            [(
+                "+=".to_owned(),
+                "Denim has no `+=` operator: write the assignment out instead, e.g. `count = \
+                 count + 3`"
+                    .to_owned()
+            )]
+        );
+        let errors =
+            errors("fn f() { a -= 1; a *= 1; a /= 1; a %= 1; a |= 1; a &= 1; a <<= 1; a >>= 1; }");
+        let ranges = errors.iter().map(|(range, _)| range.as_str()).collect::<Vec<_>>();
+        assert_eq!(ranges, ["-=", "*=", "/=", "%=", "|=", "&=", "<<=", ">>="]);
+        assert!(errors[3].1.ends_with("`a = a.mod(3)`"));
+        assert!(errors[6].1.starts_with("Denim has no `<<=` operator"));
+        assert!(errors[7].1.ends_with("`a = a.bitwise_unshift(3)`"));
+    }
+
+    #[test]
+    fn strict_param_labeling() {
+        let errors = errors(r#"fn f() { story(20, "Galois"); x.story(age: 20, "Galois"); }"#);
+        let ranges = errors.iter().map(|(range, _)| range.as_str()).collect::<Vec<_>>();
+        assert_eq!(ranges, ["20", r#""Galois""#, r#""Galois""#]);
+        assert!(errors[0].1.contains("`story(age: 20, name: \"Galois\")`"));
+
+        // Single arguments, names, selfified arguments and constructors need
+        // no labels.
+        assert_valid(
+            r#"
+fn f() {
+  print("Galois");
+  story(name: "Galois", age: 20);
+  story(age, name);
+  40.add(&other, amount: 2);
+  Pair(1, 2);
+  Shape::Rect(1, 2);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn pub_impl_fns() {
+        assert_eq!(
+            errors("impl Point { pub fn new() {} fn x(self) {} }")
+                .into_iter()
+                .map(|it| it.0)
+                .collect::<Vec<_>>(),
+            ["pub"]
+        );
+        assert_valid("pub fn f() {} pub type T = int; trait A { fn a(self); }");
+    }
+
+    #[test]
+    fn mut_params() {
+        let errors = errors("impl S { fn f(mut self, mut a: int) { |mut b| b; } }");
+        let ranges = errors.iter().map(|(range, _)| range.as_str()).collect::<Vec<_>>();
+        assert_eq!(ranges, ["mut", "mut"]);
+        assert!(errors[0].1.contains("`list: mut [int]`"));
+    }
+
+    #[test]
+    fn code_items_after_separator() {
+        let text = "fn main() {}\n---\nfrom foo use bar;\n/// Docs.\nfn late() {}\nlet x = 1;\n";
+        let ranges = errors(text).into_iter().map(|(range, _)| range).collect::<Vec<_>>();
+        assert_eq!(ranges, ["fn late() {}", "let x = 1;"]);
+    }
+
+    #[test]
+    fn incremental_reparse_revalidates() {
+        let parse = ast::SourceFile::parse("fn f() { a > b; }");
+        assert_eq!(parse.errors().len(), 1);
+
+        let edit = crate::Indel::replace(crate::TextRange::at(11.into(), 1.into()), "<".into());
+        assert_eq!(parse.reparse(&edit).errors(), []);
+
+        let edit = crate::Indel::replace(crate::TextRange::at(9.into(), 1.into()), "c".into());
+        let reparsed = parse.reparse(&edit);
+        assert_eq!(reparsed.errors().len(), 1);
+        assert_eq!(reparsed.errors(), ast::SourceFile::parse("fn f() { c > b; }").errors());
+    }
+}
@@ -0,0 +1,72 @@
+//! Measures how quickly `tokenize` gets through large Denim sources.
+//!
+//! Run with `cargo bench -p tokenizer`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use divan::{counter::BytesCount, Bencher};
+use stdx::DENIM_EXTENSION;
+
+/// Rough size of each corpus, which is about that of a large generated file.
+const CORPUS_LEN: usize = 1 << 20;
+
+fn main() {
+    divan::main();
+}
+
+/// Returns the sources of the parser test fixtures, which cover most of the
+/// language and are laid out the way people write Denim.
+fn fixtures() -> String {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../syntax/test_data");
+    let mut paths = fixture_paths(&dir);
+    paths.sort();
+
+    paths.iter().map(|path| fs::read_to_string(path).unwrap() + "\n").collect()
+}
+
+fn fixture_paths(dir: &Path) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            paths.extend(fixture_paths(&path));
+        } else if path.extension().is_some_and(|it| it == DENIM_EXTENSION) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// Returns `source` repeated until it is at least [CORPUS_LEN] bytes long.
+fn repeat_to_corpus_len(source: &str) -> String {
+    source.repeat(CORPUS_LEN.div_ceil(source.len()))
+}
+
+/// Returns the fixtures with a comment that is not ASCII at the end of every
+/// line, so that no line gets through on the ASCII fast path alone.
+fn non_ascii_fixtures() -> String {
+    let source: String =
+        fixtures().lines().map(|line| format!("{line} // значение — 値 👖\n")).collect();
+    repeat_to_corpus_len(&source)
+}
+
+fn bench_tokenize(bencher: Bencher, make_source: fn() -> String) {
+    let source = make_source();
+
+    bencher
+        .counter(BytesCount::of_str(&source))
+        .bench(|| tokenizer::tokenize(divan::black_box(&source)).count());
+}
+
+#[divan::bench]
+fn fixtures_corpus(bencher: Bencher) {
+    bench_tokenize(bencher, || repeat_to_corpus_len(&fixtures()));
+}
+
+#[divan::bench]
+fn non_ascii_fixtures_corpus(bencher: Bencher) {
+    bench_tokenize(bencher, non_ascii_fixtures);
+}
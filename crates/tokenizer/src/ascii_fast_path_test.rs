@@ -0,0 +1,52 @@
+use stdx::rand::Rng;
+
+use crate::{
+    cursor::IS_ASCII_FAST_PATH_ENABLED, goldens::golden_test_cases::GoldenTestCases, token::Token,
+    tokenize::tokenize,
+};
+
+/// Fragments of Denim source that random inputs are stitched together from,
+/// chosen to put ASCII right next to the non-ASCII characters that the ASCII
+/// fast path has to hand back to the slow path.
+const FRAGMENTS: &[&str] = &[
+    " ", "\t", "\n", "\r\n", "\x0B", "\x0C", "\0", "\u{85}", "\u{A0}", "\u{2003}", "\u{2028}",
+    "\u{200B}", "\u{FEFF}", "a", "_", "Z9", "café", "e\u{301}", "раypal", "αlpha", "👖", "ー",
+    "//", "///", "/*", "*/", "\"", "'", "$", "${", "}", "r#\"", "b'", "c\"", "0x", "1.5e", "u8",
+    "let", "fn", "#!", "[",
+];
+
+/// Tokenizes `input` exactly like [tokenize], except without taking the ASCII
+/// fast path.
+fn tokenize_without_ascii_fast_path(input: &str) -> Vec<Token> {
+    IS_ASCII_FAST_PATH_ENABLED.set(false);
+    let tokens = tokenize(input).collect();
+    IS_ASCII_FAST_PATH_ENABLED.set(true);
+
+    tokens
+}
+
+fn assert_same_tokens(input: &str) {
+    assert_eq!(
+        tokenize(input).collect::<Vec<_>>(),
+        tokenize_without_ascii_fast_path(input),
+        "tokens of {input:?} differ"
+    );
+}
+
+#[test]
+fn goldens_tokenize_the_same_without_ascii_fast_path() {
+    for golden_test_case in GoldenTestCases::in_dir("ok").chain(GoldenTestCases::in_dir("err")) {
+        assert_same_tokens(&golden_test_case.source);
+    }
+}
+
+#[test]
+fn random_inputs_tokenize_the_same_without_ascii_fast_path() {
+    let mut rng = Rng::new(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..10_000 {
+        let fragment_count = rng.below(16);
+        let input: String = (0..fragment_count).map(|_| rng.pick(FRAGMENTS)).collect();
+
+        assert_same_tokens(&input);
+    }
+}
@@ -1,6 +1,14 @@
 use std::str::Chars;
 
-use crate::constants::EOF_CHAR;
+use crate::{constants::EOF_CHAR, interpolated_str::InterpolatedStr, special_char::SpecialChar};
+
+#[cfg(test)]
+thread_local! {
+    /// Whether new cursors on this thread take the ASCII fast path, so that
+    /// tests can compare tokenizing with and without it.
+    pub(crate) static IS_ASCII_FAST_PATH_ENABLED: std::cell::Cell<bool> =
+        const { std::cell::Cell::new(true) };
+}
 
 /// Peekable iterator over a char sequence.
 ///
@@ -22,6 +30,13 @@ pub struct Cursor<'a> {
     /// a traditional ASCII 8-bit `char`.
     #[cfg(debug_assertions)]
     prev: char,
+    /// Interpolated string literals that have been started but not finished,
+    /// innermost last (e.g. `"a ${"b $c"} d"` nests two of them).
+    pub(crate) interpolated_strs: Vec<InterpolatedStr>,
+    /// `false` if [Cursor::eat_ascii_while] should fall back to
+    /// [Cursor::eat_while], so that tests can compare the two.
+    #[cfg(test)]
+    is_ascii_fast_path_enabled: bool,
 }
 
 impl<'a> Cursor<'a> {
@@ -33,6 +48,9 @@ impl<'a> Cursor<'a> {
             len_remaining: input.len(),
             #[cfg(debug_assertions)]
             prev: EOF_CHAR,
+            interpolated_strs: Vec::new(),
+            #[cfg(test)]
+            is_ascii_fast_path_enabled: IS_ASCII_FAST_PATH_ENABLED.get(),
         }
     }
 
@@ -48,11 +66,76 @@ impl<'a> Cursor<'a> {
         Some(c)
     }
 
+    /// Eats symbols while predicate returns true or until the end of file is
+    /// reached, just like [Cursor::eat_while].
+    ///
+    /// Runs of ASCII are scanned byte by byte using `ascii_predicate`, which
+    /// must agree with `predicate` on every ASCII character, so that only
+    /// non-ASCII characters need to be decoded.
+    pub(crate) fn eat_ascii_while(
+        &mut self,
+        ascii_predicate: impl Fn(u8) -> bool,
+        mut predicate: impl FnMut(char) -> bool,
+    ) {
+        #[cfg(test)]
+        if !self.is_ascii_fast_path_enabled {
+            return self.eat_while(predicate);
+        }
+
+        loop {
+            let rest = self.chars.as_str();
+            let ascii_len = rest
+                .bytes()
+                .position(|b| !b.is_ascii() || !ascii_predicate(b))
+                .unwrap_or(rest.len());
+
+            if ascii_len > 0 {
+                #[cfg(debug_assertions)]
+                {
+                    self.prev = char::from(rest.as_bytes()[ascii_len - 1]);
+                }
+
+                // ASCII bytes always end on a char boundary.
+                self.chars = rest[ascii_len..].chars();
+            }
+
+            let c = self.first();
+            if c.is_ascii() || !predicate(c) {
+                break;
+            }
+
+            self.bump();
+        }
+    }
+
+    /// Eats symbols until the next `byte` or the end of file is reached,
+    /// without decoding any of them.
+    pub(crate) fn eat_until_ascii(&mut self, byte: u8) {
+        debug_assert!(byte.is_ascii());
+
+        #[cfg(test)]
+        if !self.is_ascii_fast_path_enabled {
+            return self.eat_while(|c| c != char::from(byte));
+        }
+
+        let rest = self.chars.as_str();
+        let len = rest.find(char::from(byte)).unwrap_or(rest.len());
+
+        #[cfg(debug_assertions)]
+        if let Some(c) = rest[..len].chars().next_back() {
+            self.prev = c;
+        }
+
+        // An ASCII byte is never part of a multi-byte character, so `len` is
+        // always on a char boundary.
+        self.chars = rest[len..].chars();
+    }
+
     /// Eats symbols while predicate returns true or until the end of file is
     /// reached.
     pub(crate) fn eat_while(&mut self, mut predicate: impl FnMut(char) -> bool) {
-        // It was tried making optimized version of this for eg. line comments, but
-        // LLVM can inline all of this and compile it down to fast iteration over bytes.
+        // See `eat_ascii_while` for a version of this that skips decoding runs
+        // of ASCII, which most Denim source consists of.
         while predicate(self.first()) && !self.is_eof() {
             self.bump();
         }
@@ -68,6 +151,12 @@ impl<'a> Cursor<'a> {
         self.chars.clone().next().unwrap_or(EOF_CHAR)
     }
 
+    /// Returns `true` if the next symbols start a string interpolation, i.e. a
+    /// `$` followed by either an identifier or a `{`.
+    pub(crate) fn is_at_interpolation_start(&self) -> bool {
+        self.first() == '$' && (self.second() == '{' || self.second().is_ident_start())
+    }
+
     /// Returns `true` if there is nothing more to consume.
     pub(crate) fn is_eof(&self) -> bool {
         self.chars.as_str().is_empty()
@@ -2,6 +2,7 @@ use crate::{
     constants::EOF_CHAR,
     cursor::Cursor,
     raw_str_error::RawStrError,
+    special_char::SpecialChar,
     string_literal_ending::StringLiteralEnding::{self, *},
 };
 
@@ -31,25 +32,61 @@ impl<'a> Cursor<'a> {
     }
 
     /// Eats unicode codepoints until they no longer fit the definition of a
-    /// double-quoted string, returning a [DoubleQuotedStringEnding] that
-    /// details how the string literal ends, if at all.
-    pub(crate) fn eat_double_quoted_string(&mut self) -> StringLiteralEnding {
+    /// double-quoted string without interpolations (e.g. a byte string),
+    /// returning `true` if the double-quoted string terminates as expected.
+    pub(crate) fn eat_double_quoted_string(&mut self) -> bool {
         debug_assert!(self.prev() == '"');
 
-        // Look for a multi-line string.
-        if self.first() == '"' && self.second() == '"' {
-            self.bump();
-            self.bump();
-
-            return self.eat_multiline_double_quoted_string();
-        }
-
         while let Some(c) = self.bump() {
             match c {
                 '"' => {
-                    return TerminatedString { is_multiline: false };
+                    return true;
+                }
+                '\\' if matches!(self.first(), '\\' | '"') => {
+                    // Bump again to skip escaped character.
+                    self.bump();
+                }
+                _ => (),
+            }
+        }
+
+        // End of file reached.
+        false
+    }
+
+    /// Eats unicode codepoints until they no longer fit the definition of the
+    /// text of a double-quoted string, returning a [StringLiteralEnding] that
+    /// details how the string literal ends, if at all, or [None] if the text
+    /// is interrupted by an interpolation (e.g. `$name` or `${expr}`).
+    ///
+    /// * `is_multiline` is `true` if the string literal began with `"""`
+    pub(crate) fn eat_double_quoted_string_text(
+        &mut self,
+        is_multiline: bool,
+    ) -> Option<StringLiteralEnding> {
+        loop {
+            if self.is_at_interpolation_start() {
+                return None;
+            }
+
+            let Some(c) = self.bump() else {
+                break;
+            };
+
+            match c {
+                '"' if !is_multiline => {
+                    return Some(TerminatedString { is_multiline: false });
+                }
+                // Check to see if this is the `"""` end of a multi-line
+                // string.
+                '"' if self.first() == '"' && self.second() == '"' => {
+                    // Eat the multi-line string ending.
+                    self.bump();
+                    self.bump();
+
+                    return Some(TerminatedString { is_multiline: true });
                 }
-                '\\' if self.first() == '\\' || self.first() == '"' => {
+                '\\' if matches!(self.first(), '\\' | '"' | '$') => {
                     // Bump again to skip escaped character.
                     self.bump();
                 }
@@ -58,7 +95,7 @@ impl<'a> Cursor<'a> {
         }
 
         // End of file reached.
-        UnterminatedString
+        Some(UnterminatedString)
     }
 
     /// Eats unicode codepoints until they no longer fit the definition of a
@@ -98,33 +135,26 @@ impl<'a> Cursor<'a> {
         has_digits
     }
 
-    /// Eats unicode codepoints until they no longer fit the definition of a
-    /// multi-line string, returning a [DoubleQuotedStringEnding] that details
-    /// how the string literal ends, if at all.
-    pub(crate) fn eat_multiline_double_quoted_string(&mut self) -> StringLiteralEnding {
-        debug_assert!(self.prev() == '"');
-
-        while let Some(c) = self.bump() {
-            match c {
-                // Check to see if this is the `"""` end of a multi-line
-                // string.
-                '"' if self.first() == '"' && self.second() == '"' => {
-                    // Eat the multi-line string ending.
-                    self.bump();
-                    self.bump();
+    /// Eats unicode codepoints until they no longer fit the definition of the
+    /// non-first characters of an identifier.
+    pub(crate) fn eat_ident_rest(&mut self) {
+        self.eat_ascii_while(
+            |b| b.is_ascii_alphanumeric() || b == b'_',
+            |c| c.is_after_ident_start(),
+        );
+    }
 
-                    return TerminatedString { is_multiline: true };
-                }
-                '\\' if self.first() == '\\' || self.first() == '"' => {
-                    // Bump again to skip escaped character.
-                    self.bump();
-                }
-                _ => (),
-            }
+    /// Eats unicode codepoints until they no longer fit the definition of a
+    /// literal suffix (e.g. the `u8` of `12u8`), eating nothing at all if the
+    /// next codepoint cannot start an identifier.
+    pub(crate) fn eat_literal_suffix(&mut self) {
+        if !self.first().is_ident_start() {
+            return;
         }
 
-        // End of file reached.
-        UnterminatedString
+        self.bump();
+
+        self.eat_ident_rest();
     }
 
     /// Eats unicode codepoints until they no longer fit the definition of a
@@ -132,8 +162,8 @@ impl<'a> Cursor<'a> {
     /// following the initial `r` if and only if the raw string is correctly defined.
     ///
     /// * `prefix_len` is the number of unicode codepoints that account for the
-    ///    beginning of the string literal (e.g. a string that begins `r##"` has
-    ///    a `prefix_len` of `4`).
+    ///   beginning of the string literal (e.g. a string that begins `r##"` has
+    ///   a `prefix_len` of `4`).
     pub(crate) fn eat_raw_double_quoted_string(
         &mut self,
         prefix_len: u32,
@@ -202,8 +232,8 @@ impl<'a> Cursor<'a> {
     /// following the initial `r`.
     ///
     /// * `prefix_len` is the number of unicode codepoints that account for the
-    ///    beginning of the string literal (e.g. a string that begins `r##"` has
-    ///    a `prefix_len` of `4`).
+    ///   beginning of the string literal (e.g. a string that begins `r##"` has
+    ///   a `prefix_len` of `4`).
     fn eat_raw_double_quoted_string_unvalidated(
         &mut self,
         prefix_len: u32,
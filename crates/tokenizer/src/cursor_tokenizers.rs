@@ -1,5 +1,6 @@
 use crate::{
     cursor::Cursor,
+    interpolated_str::{InterpolatedStr, InterpolatedStrState},
     literal_kind::LiteralKind::{self, *},
     numeric_base::NumericBase::*,
     special_char::SpecialChar,
@@ -16,12 +17,29 @@ impl<'a> Cursor<'a> {
     /// When there are no more tokens left to parse, a [TokenKind::End] token is
     /// returned.
     pub(crate) fn tokenize_next(&mut self) -> Token {
+        let kind = match self.interpolated_strs.last().map(|it| it.state) {
+            Some(InterpolatedStrState::Text) => self.tokenize_interpolated_str_text(),
+            Some(InterpolatedStrState::Ident) => self.tokenize_interpolated_ident(),
+            Some(InterpolatedStrState::Braced { .. }) | None => self.tokenize_code(),
+        };
+
+        let token = Token { kind, len: self.pos_within_token() };
+
+        self.reset_pos_within_token();
+
+        token
+    }
+
+    /// Attempts to recognize the next token as a token of Denim code (as
+    /// opposed to the text of a string literal), only advancing the cursor
+    /// and returning it if this attempt is successful.
+    fn tokenize_code(&mut self) -> TokenKind {
         let first_char = match self.bump() {
             Some(c) => c,
-            None => return Token { kind: TokenKind::End, len: 0 },
+            None => return TokenKind::End,
         };
 
-        let kind = match first_char {
+        match first_char {
             // Slash, comment or block comment.
             '/' => match self.first() {
                 '/' => self.tokenize_line_comment(),
@@ -39,11 +57,18 @@ impl<'a> Cursor<'a> {
 
                     let kind = RawStr { pound_count: maybe_pound_count.ok() };
 
-                    Literal { kind }
+                    Literal { kind, suffix_start: self.pos_within_token() }
                 }
                 _ => self.tokenize_ident_or_unknown_prefix(),
             },
 
+            // Byte literal, byte string literal, raw byte string literal or
+            // identifier.
+            'b' => self.tokenize_byte_literal_or_ident(),
+
+            // C string literal, raw C string literal or identifier.
+            'c' => self.tokenize_c_str_literal_or_ident(),
+
             // Identifier (this should be checked after other variant that can
             // start as identifier).
             c if c.is_ident_start() => self.tokenize_ident_or_unknown_prefix(),
@@ -52,12 +77,16 @@ impl<'a> Cursor<'a> {
             c @ '0'..='9' => {
                 let literal_kind = self.tokenize_numeric_literal(c);
 
-                // TODO: add support for type suffixes e.g. `123i32`.
+                // Type suffixes, e.g. the `i32` of `123i32`, are eaten as part
+                // of the literal and validated later on.
+                let suffix_start = self.pos_within_token();
+                self.eat_literal_suffix();
 
-                Literal { kind: literal_kind }
+                Literal { kind: literal_kind, suffix_start }
             }
 
-            // Either a section separator, `---`, or a normal minus, `-`.
+            // Either a section separator, `---`, a thin arrow, `->`, or a
+            // normal minus, `-`.
             '-' => match (self.first(), self.second()) {
                 ('-', '-') => {
                     // Advance past the remaining section separator characters.
@@ -66,6 +95,12 @@ impl<'a> Cursor<'a> {
 
                     SectionSeparator
                 }
+                ('>', _) => {
+                    // Advance past the remaining thin arrow character.
+                    self.bump();
+
+                    ThinArrow
+                }
                 _ => Minus,
             },
 
@@ -80,9 +115,23 @@ impl<'a> Cursor<'a> {
                 _ => Colon,
             },
 
-            // Either a `..` or `.`.
-            '.' => match self.first() {
-                '.' => {
+            // Either a `...`, `..=`, `..` or `.`.
+            '.' => match (self.first(), self.second()) {
+                ('.', '.') => {
+                    // Advance past the remaining dotdotdot characters.
+                    self.bump();
+                    self.bump();
+
+                    DotDotDot
+                }
+                ('.', '=') => {
+                    // Advance past the remaining dotdoteq characters.
+                    self.bump();
+                    self.bump();
+
+                    DotDotEq
+                }
+                ('.', _) => {
                     // Advance past the remaining dotdot character.
                     self.bump();
 
@@ -91,7 +140,7 @@ impl<'a> Cursor<'a> {
                 _ => Dot,
             },
 
-            // Either a `===`, `==`, or `=`.
+            // Either a `===`, `==`, `=>`, or `=`.
             '=' => match (self.first(), self.second()) {
                 ('=', '=') => {
                     // Advance past the remaining eqeqeq characters.
@@ -106,6 +155,12 @@ impl<'a> Cursor<'a> {
 
                     EqEq
                 }
+                ('>', _) => {
+                    // Advance past the remaining fat arrow character.
+                    self.bump();
+
+                    FatArrow
+                }
                 _ => Eq,
             },
 
@@ -132,8 +187,35 @@ impl<'a> Cursor<'a> {
             ',' => Comma,
             '(' => OpenParen,
             ')' => CloseParen,
-            '{' => OpenBrace,
-            '}' => CloseBrace,
+            '{' => {
+                if let Some(InterpolatedStr {
+                    state: InterpolatedStrState::Braced { depth }, ..
+                }) = self.interpolated_strs.last_mut()
+                {
+                    *depth += 1;
+                }
+
+                OpenBrace
+            }
+            '}' => match self.interpolated_strs.last_mut() {
+                // The `}` that closes a `${}` resumes the string literal.
+                Some(
+                    interpolated_str @ InterpolatedStr {
+                        state: InterpolatedStrState::Braced { depth: 0 },
+                        ..
+                    },
+                ) => {
+                    interpolated_str.state = InterpolatedStrState::Text;
+
+                    InterpolationEnd
+                }
+                Some(InterpolatedStr { state: InterpolatedStrState::Braced { depth }, .. }) => {
+                    *depth -= 1;
+
+                    CloseBrace
+                }
+                _ => CloseBrace,
+            },
             '[' => OpenBracket,
             ']' => CloseBracket,
             '@' => At,
@@ -141,7 +223,16 @@ impl<'a> Cursor<'a> {
             '~' => Tilde,
             '?' => Question,
             '$' => Dollar,
-            '<' => Lt,
+            // Either a `<=` or `<`.
+            '<' => match self.first() {
+                '=' => {
+                    // Advance past the remaining lteq character.
+                    self.bump();
+
+                    LtEq
+                }
+                _ => Lt,
+            },
             '>' => Gt,
             '&' => Amp,
             '|' => Pipe,
@@ -153,24 +244,69 @@ impl<'a> Cursor<'a> {
             '\'' => self.tokenize_char_literal(),
 
             // String literal.
-            '"' => {
-                let ending = self.eat_double_quoted_string();
-
-                Literal { kind: Str { ending } }
-            }
+            '"' => self.tokenize_str_literal(),
 
             // Identifier starting with an emoji. Only lexed for graceful error recovery.
             c if !c.is_ascii() && c.is_emoji_char() => self.tokenize_fake_ident_or_unknown_prefix(),
 
             // 🤷🏾‍♂️.
             _ => Unknown,
+        }
+    }
+
+    /// Attempts to recognize the next token as a byte literal, a byte string
+    /// literal, a raw byte string literal or an identifier, only advancing the
+    /// cursor and returning it if this attempt is successful.
+    fn tokenize_byte_literal_or_ident(&mut self) -> TokenKind {
+        debug_assert!(self.prev() == 'b');
+
+        let kind = match (self.first(), self.second()) {
+            ('\'', _) => {
+                self.bump();
+
+                Byte { is_terminated: self.eat_single_quoted_string() }
+            }
+            ('"', _) => {
+                self.bump();
+
+                ByteStr { is_terminated: self.eat_double_quoted_string() }
+            }
+            ('r', '"') | ('r', '#') => {
+                self.bump();
+
+                let maybe_pound_count = self.eat_raw_double_quoted_string(2);
+
+                RawByteStr { pound_count: maybe_pound_count.ok() }
+            }
+            _ => return self.tokenize_ident_or_unknown_prefix(),
         };
 
-        let token = Token { kind, len: self.pos_within_token() };
+        Literal { kind, suffix_start: self.pos_within_token() }
+    }
 
-        self.reset_pos_within_token();
+    /// Attempts to recognize the next token as a C string literal, a raw C
+    /// string literal or an identifier, only advancing the cursor and returning
+    /// it if this attempt is successful.
+    fn tokenize_c_str_literal_or_ident(&mut self) -> TokenKind {
+        debug_assert!(self.prev() == 'c');
 
-        token
+        let kind = match (self.first(), self.second()) {
+            ('"', _) => {
+                self.bump();
+
+                CStr { is_terminated: self.eat_double_quoted_string() }
+            }
+            ('r', '"') | ('r', '#') => {
+                self.bump();
+
+                let maybe_pound_count = self.eat_raw_double_quoted_string(2);
+
+                RawCStr { pound_count: maybe_pound_count.ok() }
+            }
+            _ => return self.tokenize_ident_or_unknown_prefix(),
+        };
+
+        Literal { kind, suffix_start: self.pos_within_token() }
     }
 
     /// Attempts to recognize the next token a character literal, only advancing
@@ -180,7 +316,7 @@ impl<'a> Cursor<'a> {
 
         let is_terminated = self.eat_single_quoted_string();
 
-        return Literal { kind: Char { is_terminated } };
+        Literal { kind: Char { is_terminated }, suffix_start: self.pos_within_token() }
     }
 
     /// Attempts to recognize the next token as either an unknown prefix or an
@@ -208,7 +344,7 @@ impl<'a> Cursor<'a> {
         debug_assert!(self.prev().is_ident_start());
 
         // Front is already eaten, eat the rest of identifier.
-        self.eat_while(|c| c.is_after_ident_start());
+        self.eat_ident_rest();
 
         // Known prefixes must have been handled earlier. So if
         // we see a prefix here, it is definitely an unknown prefix.
@@ -265,7 +401,7 @@ impl<'a> Cursor<'a> {
         let is_doc_comment = self.first() == '/' && self.second() != '/';
 
         // Line comment is terminated by the end of the current line.
-        self.eat_while(|c| c != '\n');
+        self.eat_until_ascii(b'\n');
 
         LineComment { is_doc_comment }
     }
@@ -320,7 +456,7 @@ impl<'a> Cursor<'a> {
                 '.' | 'e' | 'E' => {}
 
                 // Just a 0.
-                _ => return Int { base, is_empty: true },
+                _ => return Int { base, is_empty: false },
             }
         } else {
             // No base prefix, parse number in the usual way.
@@ -337,7 +473,7 @@ impl<'a> Cursor<'a> {
                 self.bump();
 
                 let mut has_empty_exponent = false;
-                if self.first().is_digit(10) {
+                if self.first().is_ascii_digit() {
                     self.eat_decimal_digits();
 
                     match self.first() {
@@ -363,12 +499,99 @@ impl<'a> Cursor<'a> {
         }
     }
 
+    /// Attempts to recognize the next token as the identifier of a `$name`
+    /// string interpolation, only advancing the cursor and returning it if
+    /// this attempt is successful.
+    fn tokenize_interpolated_ident(&mut self) -> TokenKind {
+        debug_assert!(self.first().is_ident_start());
+
+        self.bump();
+        self.eat_ident_rest();
+
+        // Unlike other identifiers, this one cannot be a literal prefix since
+        // it is directly followed by the text of the string literal.
+        self.set_interpolated_str_state(InterpolatedStrState::Text);
+
+        Ident
+    }
+
+    /// Attempts to recognize the next token as either a string interpolation
+    /// start or the text of the interpolated string literal that the cursor is
+    /// in, only advancing the cursor and returning it if this attempt is
+    /// successful.
+    fn tokenize_interpolated_str_text(&mut self) -> TokenKind {
+        if self.is_at_interpolation_start() {
+            self.bump();
+
+            return if self.first() == '{' {
+                self.bump();
+                self.set_interpolated_str_state(InterpolatedStrState::Braced { depth: 0 });
+
+                InterpolationStart { is_braced: true }
+            } else {
+                self.set_interpolated_str_state(InterpolatedStrState::Ident);
+
+                InterpolationStart { is_braced: false }
+            };
+        }
+
+        let is_multiline = self.interpolated_strs.last().is_some_and(|it| it.is_multiline);
+
+        match self.eat_double_quoted_string_text(is_multiline) {
+            Some(ending) => {
+                self.interpolated_strs.pop();
+
+                // An interpolated string literal that is unterminated right
+                // after an interpolation has no text left to make a token of.
+                if self.pos_within_token() == 0 {
+                    return End;
+                }
+
+                StrEnd { ending }
+            }
+            None => StrFragment,
+        }
+    }
+
+    /// Attempts to recognize the next token as a string literal, or as the
+    /// start of a string literal with interpolations, only advancing the
+    /// cursor and returning it if this attempt is successful.
+    fn tokenize_str_literal(&mut self) -> TokenKind {
+        debug_assert!(self.prev() == '"');
+
+        // Look for a multi-line string.
+        let is_multiline = self.first() == '"' && self.second() == '"';
+        if is_multiline {
+            self.bump();
+            self.bump();
+        }
+
+        match self.eat_double_quoted_string_text(is_multiline) {
+            Some(ending) => Literal { kind: Str { ending }, suffix_start: self.pos_within_token() },
+            None => {
+                self.interpolated_strs
+                    .push(InterpolatedStr { is_multiline, state: InterpolatedStrState::Text });
+
+                StrStart { is_multiline }
+            }
+        }
+    }
+
+    /// Updates the state of the innermost interpolated string literal.
+    fn set_interpolated_str_state(&mut self, state: InterpolatedStrState) {
+        if let Some(interpolated_str) = self.interpolated_strs.last_mut() {
+            interpolated_str.state = state;
+        }
+    }
+
     /// Attempts to recognize the next token as whitespace, only advancing the
     /// cursor and returning it if this attempt is successful.
     fn tokenize_whitespace(&mut self) -> TokenKind {
         debug_assert!(self.prev().is_whitespace());
 
-        self.eat_while(|c| c.is_whitespace());
+        // `char::is_whitespace` accepts `\t`, `\n`, `\x0B`, `\x0C`, `\r` and ` `
+        // out of all of ASCII.
+        self.eat_ascii_while(|b| matches!(b, b'\t'..=b'\r' | b' '), |c| c.is_whitespace());
 
         Whitespace
     }
@@ -1,4 +1,4 @@
 #[cfg(test)]
-mod golden_test_cases;
+pub(crate) mod golden_test_cases;
 #[cfg(test)]
 mod goldens_test;
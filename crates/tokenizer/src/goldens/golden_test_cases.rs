@@ -10,10 +10,10 @@ use crate::{token::Token, tokenize::tokenize};
 const FIRST_COLUMN_WIDTH: usize = 60;
 
 /// File extension used by source files.
-const SOURCE_FILE_EXTENSION: &'static str = "denim";
+const SOURCE_FILE_EXTENSION: &str = "denim";
 
 /// File extension used by token golden files.
-const TOKENS_FILE_EXTENSION: &'static str = "dtok";
+const TOKENS_FILE_EXTENSION: &str = "dtok";
 
 /// A single test case that compares the "correct" tokenization of Denim
 /// source code against its "actual" tokenization.
@@ -21,7 +21,7 @@ const TOKENS_FILE_EXTENSION: &'static str = "dtok";
 pub(crate) struct GoldenTestCase {
     pub(crate) source_path: PathBuf,
     pub(crate) tokens_path: PathBuf,
-    source: String,
+    pub(crate) source: String,
 }
 
 impl GoldenTestCase {
@@ -81,7 +81,7 @@ pub(crate) struct GoldenTestCases();
 impl GoldenTestCases {
     /// Reads all of the golden test cases defined within
     /// `goldens/{dir_name}`.
-    pub(crate) fn in_dir(dir_name: &'static str) -> impl Iterator<Item = GoldenTestCase> {
+    pub(crate) fn in_dir(dir_name: &str) -> impl Iterator<Item = GoldenTestCase> {
         let crate_root_dir_path = Path::new(env!("CARGO_MANIFEST_DIR"));
 
         let goldens_dir_path = crate_root_dir_path.join("src").join("goldens");
@@ -0,0 +1,108 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+use unicode_script::{Script, UnicodeScript};
+use unicode_security::{skeleton, MixedScript};
+
+/// Enumerates the ways in which an identifier can be misleading to read, even
+/// though it is perfectly valid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdentHygieneWarning {
+    /// The identifier mixes scripts and contains a character that is easily
+    /// mistaken for some ASCII text (e.g. the Cyrillic `а` in `pаypal` looks
+    /// just like the Latin `a`).
+    Confusable {
+        /// Byte offset of the confusable character within the identifier.
+        offset: usize,
+        /// The confusable character.
+        c: char,
+        /// ASCII text that `c` can be mistaken for.
+        lookalike: String,
+    },
+    /// The identifier mixes characters from scripts that are not normally
+    /// written together (e.g. Latin and Greek in `αlpha`).
+    MixedScript {
+        /// Full names of the scripts that are mixed, in order of appearance.
+        scripts: Vec<&'static str>,
+    },
+}
+
+/// Returns `ident` normalized to Unicode Normalization Form C (NFC), which is
+/// the form that identifiers are compared in.
+///
+/// Borrows `ident` if it is already normalized, which includes all ASCII text.
+pub fn nfc_normalize_ident(ident: &str) -> Cow<'_, str> {
+    match is_nfc_quick(ident.chars()) {
+        IsNormalized::Yes => Cow::Borrowed(ident),
+        IsNormalized::No | IsNormalized::Maybe => {
+            let normalized: String = ident.nfc().collect();
+            if normalized == ident {
+                Cow::Borrowed(ident)
+            } else {
+                Cow::Owned(normalized)
+            }
+        }
+    }
+}
+
+/// Returns every [IdentHygieneWarning] that applies to `ident` on its own,
+/// which is never any for ASCII identifiers.
+///
+/// Following [UTS #39](https://www.unicode.org/reports/tr39/), identifiers
+/// written in a single script, like `привет` or `αβγ`, are fine however much
+/// their characters resemble ASCII. See [IdentSkeletons] for the identifiers
+/// that are only confusing next to one another.
+pub fn check_ident_hygiene(ident: &str) -> Vec<IdentHygieneWarning> {
+    let mut warnings = Vec::new();
+    if ident.is_ascii() || ident.is_single_script() {
+        return warnings;
+    }
+
+    for (offset, c) in ident.char_indices().filter(|(_, c)| !c.is_ascii()) {
+        let lookalike: String = skeleton(c.encode_utf8(&mut [0; 4])).collect();
+        if lookalike.is_ascii() {
+            warnings.push(IdentHygieneWarning::Confusable { offset, c, lookalike });
+        }
+    }
+
+    let mut scripts = Vec::new();
+    for script in ident.chars().map(|c| c.script()) {
+        if !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+            && !scripts.contains(&script.full_name())
+        {
+            scripts.push(script.full_name());
+        }
+    }
+    warnings.push(IdentHygieneWarning::MixedScript { scripts });
+
+    warnings
+}
+
+/// Remembers the identifiers of a file by their skeleton, the text that
+/// [UTS #39](https://www.unicode.org/reports/tr39/) considers them
+/// indistinguishable from, to find the identifiers that look alike.
+#[derive(Debug, Default)]
+pub struct IdentSkeletons {
+    /// The first identifier seen with each skeleton.
+    first_by_skeleton: HashMap<String, String>,
+}
+
+impl IdentSkeletons {
+    /// Records `ident`, and returns the identifier recorded earlier that is
+    /// different from, but looks just like, `ident` (e.g. the Latin `pay` for
+    /// the Cyrillic `рау`), if any.
+    ///
+    /// Like rustc, this leaves pairs of ASCII identifiers (e.g. `rn` and `m`)
+    /// alone.
+    pub fn check(&mut self, ident: &str) -> Option<&str> {
+        let ident = nfc_normalize_ident(ident);
+        let other = self
+            .first_by_skeleton
+            .entry(skeleton(&ident).collect())
+            .or_insert_with(|| ident.clone().into_owned());
+        if *other == ident || (other.is_ascii() && ident.is_ascii()) {
+            return None;
+        }
+        Some(other)
+    }
+}
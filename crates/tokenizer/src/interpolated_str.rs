@@ -0,0 +1,27 @@
+/// String literal containing at least one interpolation (e.g. `"Hi $name!"`)
+/// that the tokenizer is currently in the middle of.
+///
+/// Interpolated strings are split into several tokens, so the tokenizer has to
+/// remember which part of the string it is in between tokens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct InterpolatedStr {
+    /// `true` if the string literal is a multi-line string literal.
+    pub(crate) is_multiline: bool,
+    /// Which part of the string literal comes next.
+    pub(crate) state: InterpolatedStrState,
+}
+
+/// Enumerates the parts of an interpolated string literal that the tokenizer
+/// can be in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum InterpolatedStrState {
+    /// Literal text, e.g. the `Hi ` in `"Hi $name!"`.
+    Text,
+    /// The identifier of a `$name` interpolation.
+    Ident,
+    /// Code inside of a `${}` interpolation.
+    Braced {
+        /// How many `{` inside of the interpolation are still open.
+        depth: u32,
+    },
+}
@@ -1,9 +1,13 @@
+#[cfg(test)]
+mod ascii_fast_path_test;
 mod constants;
 mod cursor;
 mod cursor_eaters;
 mod cursor_tokenizers;
 mod escape_error;
 mod goldens;
+mod ident_hygiene;
+mod interpolated_str;
 mod literal_kind;
 mod mixed_unit;
 mod mode;
@@ -17,10 +21,13 @@ mod tokenize;
 pub mod unescape;
 
 pub use escape_error::*;
+pub use ident_hygiene::*;
 pub use literal_kind::*;
 pub use mixed_unit::*;
 pub use mode::*;
+pub use numeric_base::*;
 pub use string_literal_ending::*;
+pub use token::*;
 pub use token_kind::*;
 pub use tokenize::*;
 pub use unescape::*;
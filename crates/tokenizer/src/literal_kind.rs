@@ -8,6 +8,21 @@ use crate::{numeric_base::NumericBase, string_literal_ending::StringLiteralEndin
 /// `rustc_ast::ast::LitKind`).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LiteralKind {
+    /// "b'a'", "b'\\'", "b'''", "b';"
+    Byte {
+        /// `true` if this literal ends correctly with a `'`.
+        is_terminated: bool,
+    },
+    /// "b"abc"", "b"abc"
+    ByteStr {
+        /// `true` if this literal ends correctly with a `"`.
+        is_terminated: bool,
+    },
+    /// "c"abc"", "c"abc"
+    CStr {
+        /// `true` if this literal ends correctly with a `"`.
+        is_terminated: bool,
+    },
     /// "'a'", "'\\'", "'''", "';"
     Char {
         /// `true` if this literal ends correctly with a `'`.
@@ -29,6 +44,20 @@ pub enum LiteralKind {
         /// (e.g. `0x` or `0b`).
         is_empty: bool,
     },
+    /// "br"abc"", "br#"abc"#", "br####"ab"###"c"####", "br#"a".
+    RawByteStr {
+        /// Counts the number of `#` characters used to define the raw string.
+        ///
+        /// [None] indicates an invalid literal.
+        pound_count: Option<u8>,
+    },
+    /// "cr"abc"", "cr#"abc"#", "cr#"a".
+    RawCStr {
+        /// Counts the number of `#` characters used to define the raw string.
+        ///
+        /// [None] indicates an invalid literal.
+        pound_count: Option<u8>,
+    },
     /// "r"abc"", "r#"abc"#", "r####"ab"###"c"####", "r#"a".
     RawStr {
         /// Counts the number of `#` characters used to define the raw string.
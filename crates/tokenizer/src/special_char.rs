@@ -2,6 +2,7 @@ use unicode_xid::UnicodeXID;
 
 /// [char] that can be recognized as serving a specific purpose in the Denim
 /// language.
+#[allow(clippy::wrong_self_convention)]
 pub(crate) trait SpecialChar {
     /// Returns `true` if this [char] is valid as a non-first character of an
     /// identifier.
@@ -1,4 +1,4 @@
-use crate::literal_kind::LiteralKind;
+use crate::{literal_kind::LiteralKind, string_literal_ending::StringLiteralEnding};
 
 /// Enum representing common lexeme types.
 ///
@@ -29,6 +29,14 @@ pub enum TokenKind {
     /// Two consecutive dots. This operator is used in lots of different
     /// situations - spread, rest, range, etc.
     DotDot,
+    /// `...`
+    ///
+    /// Three consecutive dots, used for variadic parameters.
+    DotDotDot,
+    /// `..=`
+    ///
+    /// Inclusive range operator.
+    DotDotEq,
     /// `::`.
     ///
     /// Syntax used to separate paths and reference functions.
@@ -41,10 +49,24 @@ pub enum TokenKind {
     EqEq,
     /// "===".
     EqEqEq,
+    /// `=>`.
+    ///
+    /// Syntax used to separate the pattern of a match arm from its body.
+    FatArrow,
     /// Any identifier (e.g. a variable name or a keyword).
     ///
     /// At this step, keywords are also considered identifiers.
     Ident,
+    /// `}` that ends a `${}` string interpolation.
+    InterpolationEnd,
+    /// `$` or `${` that starts a string interpolation.
+    ///
+    /// A `$` is always followed by an [TokenKind::Ident], while a `${` is
+    /// followed by arbitrary tokens up until its [TokenKind::InterpolationEnd].
+    InterpolationStart {
+        /// `true` if this interpolation start is a `${`.
+        is_braced: bool,
+    },
     /// Like `Ident`, but containing invalid unicode codepoints.
     InvalidIdent,
     /// `// comment`.
@@ -55,14 +77,22 @@ pub enum TokenKind {
     /// Syntax that defines actual, usable data directly in source code.
     ///
     /// Examples: `12u8`, `1.0e-40`, `"123"`. Note that `_` is an invalid
-    /// suffix, but may be present here on string and float literals. Users of
+    /// suffix, but may be present here on numeric literals. Users of
     /// this type will need to check for and reject that case.
     ///
     /// See [LiteralKind] for more details.
     Literal {
         /// Specifies the general type of `Literal`.
         kind: LiteralKind,
+        /// Byte offset of this `Literal`'s suffix within the token (e.g. `3`
+        /// for `123i32`).
+        ///
+        /// Only numeric literals can have a suffix, so this is the length of
+        /// the whole token for every other kind of literal.
+        suffix_start: u32,
     },
+    /// "<=".
+    LtEq,
     /// "!=".
     NEq,
     /// "!==".
@@ -72,6 +102,33 @@ pub enum TokenKind {
     /// Syntax used to separate the imports/exports section from the "real"
     /// source code.
     SectionSeparator,
+    /// `#!/usr/bin/env denim`.
+    ///
+    /// Line at the very start of a file that tells the OS how to run it as a
+    /// script, not including its line break.
+    Shebang,
+    /// Text of a string literal that ends the string literal after its last
+    /// interpolation, e.g. the `!"` in `"Hi $name!"`.
+    StrEnd {
+        /// Describes how the string literal ends.
+        ending: StringLiteralEnding,
+    },
+    /// Text of a string literal in between two of its interpolations, e.g. the
+    /// ` and ` in `"$a and $b"`.
+    StrFragment,
+    /// Text of a string literal that starts the string literal up until its
+    /// first interpolation, e.g. the `"Hi ` in `"Hi $name!"`.
+    ///
+    /// String literals without interpolations are tokenized as a single
+    /// [TokenKind::Literal] instead.
+    StrStart {
+        /// `true` if the string literal is a multi-line string literal.
+        is_multiline: bool,
+    },
+    /// `->`.
+    ///
+    /// Syntax used to introduce the return type of a function.
+    ThinArrow,
     /// Unrecognized prefix, like `foo#`, `foo'`, `foo"`.
     ///
     /// NOTE: only the prefix (`foo`) is included in the token, not the
@@ -1,8 +1,66 @@
-use crate::{cursor::Cursor, token::Token, token_kind::TokenKind};
+use crate::{
+    cursor::Cursor,
+    token::Token,
+    token_kind::TokenKind::{self, *},
+};
+
+/// Character that some editors put at the very start of UTF-8 files to mark
+/// them as such.
+pub const BYTE_ORDER_MARK: char = '\u{FEFF}';
 
 /// Creates a new [Iterator] that exhaustively produces each Denim [Token] that
-/// comprises `input` in order from top of the file to the bottom of the file.
+/// comprises `input`, a whole file, in order from top of the file to the
+/// bottom of the file.
+///
+/// A [BYTE_ORDER_MARK] at the very start of `input` is tokenized as
+/// [TokenKind::Whitespace], so that it is ignored, and a shebang line right
+/// after it as [TokenKind::Shebang] (see [strip_shebang]). Use [tokenize_code]
+/// for text that does not start a file.
 pub fn tokenize(input: &str) -> impl Iterator<Item = Token> + '_ {
+    let bom_len = if input.starts_with(BYTE_ORDER_MARK) { BYTE_ORDER_MARK.len_utf8() } else { 0 };
+    let shebang_len = strip_shebang(&input[bom_len..]).unwrap_or(0);
+
+    let prelude = [(Whitespace, bom_len), (Shebang, shebang_len)]
+        .into_iter()
+        .filter(|&(_, len)| len > 0)
+        .map(|(kind, len)| Token { kind, len: len as u32 });
+
+    prelude.chain(tokenize_code(&input[bom_len + shebang_len..]))
+}
+
+/// Returns the length of the shebang line that `input` starts with (e.g.
+/// `#!/usr/bin/env denim`), not including its line break, or [None] if
+/// `input` does not start with one.
+///
+/// Just like in Rust, a `#!` does not start a shebang if it starts an inner
+/// attribute instead, even if the `#!` and the `[` are separated by whitespace
+/// and comments (e.g. `#! /* hi */ [allow(unused)]`).
+pub fn strip_shebang(input: &str) -> Option<usize> {
+    let input_tail = input.strip_prefix("#!")?;
+
+    let mut offset = 0;
+    let next_non_trivia = tokenize_code(input_tail).find(|token| {
+        let text = &input_tail[offset..][..token.len as usize];
+        offset += text.len();
+
+        match token.kind {
+            Whitespace | LineComment { is_doc_comment: false } => false,
+            BlockComment { .. } => is_block_doc_comment(text),
+            _ => true,
+        }
+    });
+
+    if next_non_trivia.is_some_and(|token| token.kind == OpenBracket) {
+        return None;
+    }
+
+    Some("#!".len() + input_tail.lines().next().unwrap_or_default().len())
+}
+
+/// Like [tokenize], but for `input` that does not start a file (e.g. a block
+/// being reparsed), so a [BYTE_ORDER_MARK] or `#!` at its start is tokenized
+/// like anywhere else.
+pub fn tokenize_code(input: &str) -> impl Iterator<Item = Token> + '_ {
     let mut cursor = Cursor::new(input);
 
     std::iter::from_fn(move || {
@@ -15,3 +73,10 @@ pub fn tokenize(input: &str) -> impl Iterator<Item = Token> + '_ {
         }
     })
 }
+
+/// Returns `true` if the block comment `text` is a doc comment (e.g.
+/// `/** Docs */`), which Rust does not skip when looking for a shebang.
+fn is_block_doc_comment(text: &str) -> bool {
+    (text.starts_with("/**") && !text.starts_with("/***") && !text.starts_with("/**/"))
+        || text.starts_with("/*!")
+}
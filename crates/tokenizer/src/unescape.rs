@@ -71,7 +71,8 @@ where
                     _ => scan_escape::<T>(&mut chars, mode),
                 }
             }
-            '"' => Err(EscapeError::EscapeOnlyChar),
+            // NOTE: a bare `"` can only end up here from within a multi-line
+            // string literal, where it is allowed.
             '\r' => Err(EscapeError::BareCarriageReturn),
             _ => ascii_check(c, allow_unicode_chars).map(T::from),
         };
@@ -155,6 +156,7 @@ fn scan_escape<T: From<char> + From<u8>>(
     // Previous character was '\\', unescape what follows.
     let res: char = match chars.next().ok_or(EscapeError::LoneSlash)? {
         '"' => '"',
+        '$' => '$',
         'n' => '\n',
         'r' => '\r',
         't' => '\t',
@@ -265,7 +265,7 @@ impl<'a, 'b> AstNodesSrc<'a, 'b> {
         }
 
         let ast = quote! {
-            #![allow(non_snake_case)]
+            #![allow(non_snake_case, clippy::enum_variant_names, clippy::wrong_self_convention)]
             use crate::{
                 SyntaxNode, SyntaxToken, SyntaxKind::{self, *},
                 ast::{self, AstNode, AstChildren, support},
@@ -12,7 +12,13 @@ use crate::{
 
 use super::{ast_nodes_src::AstNodesSrc, grammar_facts::GrammarFacts};
 
-pub fn codegen_grammar_files(command: &CodegenCommands, project_root: PathBuf, check: bool) {
+/// Generates the grammar and syntax source files from `denim.ungram`.
+///
+/// Returns the files that were out of date; when `check` is set, they are left
+/// as they are.
+pub fn codegen_grammar_files(project_root: PathBuf, check: bool) -> Vec<PathBuf> {
+    let command = &CodegenCommands::Grammar { check };
+    let mut stale_files = Vec::new();
     let grammar = read_to_string(project_root.join("crates/syntax/denim.ungram"))
         .unwrap()
         .parse::<Grammar>()
@@ -27,37 +33,64 @@ pub fn codegen_grammar_files(command: &CodegenCommands, project_root: PathBuf, c
     let syntax_kinds_file_content = syntax_kinds_src.print(command);
     let syntax_kinds_file_path = project_root.join("crates/parser/src/syntax_kind/generated.rs");
 
-    ensure_file_contents(
-        command,
+    if ensure_file_contents(
         &project_root,
         syntax_kinds_file_path.as_path(),
         &syntax_kinds_file_content,
         check,
-    );
+    ) {
+        stale_files.push(syntax_kinds_file_path);
+    }
 
     let ast_tokens_src = AstTokensSrc::generate(&ast_src);
 
     let ast_tokens_file_content = ast_tokens_src.print(command);
     let ast_tokens_file_path = project_root.join("crates/syntax/src/ast/generated/tokens.rs");
 
-    ensure_file_contents(
-        command,
+    if ensure_file_contents(
         &project_root,
         ast_tokens_file_path.as_path(),
         &ast_tokens_file_content,
         check,
-    );
+    ) {
+        stale_files.push(ast_tokens_file_path);
+    }
 
     let ast_nodes_src = AstNodesSrc::generate(&ast_src, &syntax_kinds_src);
 
     let ast_nodes_file_content = ast_nodes_src.print(command, &grammar_facts);
     let ast_nodes_file_path = project_root.join("crates/syntax/src/ast/generated/nodes.rs");
 
-    ensure_file_contents(
-        command,
+    if ensure_file_contents(
         &project_root,
         ast_nodes_file_path.as_path(),
         &ast_nodes_file_content,
         check,
-    );
+    ) {
+        stale_files.push(ast_nodes_file_path);
+    }
+
+    stale_files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{codegen::stale_files_message, project_root};
+
+    /// Fails if `denim.ungram` or the grammar facts were changed without
+    /// running `cargo xtask gen grammar`.
+    #[test]
+    fn generated_grammar_files_are_up_to_date() {
+        let stale_files = codegen_grammar_files(project_root(), true);
+        assert!(
+            stale_files.is_empty(),
+            "{}",
+            stale_files_message(
+                &CodegenCommands::Grammar { check: true },
+                &project_root(),
+                &stale_files
+            )
+        );
+    }
 }
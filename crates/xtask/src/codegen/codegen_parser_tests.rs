@@ -0,0 +1,172 @@
+use std::{
+    collections::BTreeMap,
+    fs::{read_dir, read_to_string, remove_file},
+    path::{Path, PathBuf},
+};
+
+use stdx::DENIM_EXTENSION;
+
+use crate::codegen::file_util::ensure_file_contents;
+
+/// A test inlined in the grammar as a comment, e.g.
+///
+/// ```text
+/// // test function_with_zero_parameters
+/// // fn foo() {}
+/// ```
+#[derive(Debug)]
+struct InlineTest {
+    name: String,
+    text: String,
+    is_err: bool,
+}
+
+/// Extracts the inline tests of the grammar in `crates/parser/src/grammar`
+/// into `crates/syntax/test_data/parser/inline`, one source file per test.
+///
+/// Tests that start with `// test` go into `ok/`, and tests that start with
+/// `// test_err` go into `err/`. Fixtures of tests that no longer exist are
+/// removed.
+///
+/// Returns the fixtures that were out of date; when `check` is set, they are
+/// left as they are.
+pub fn codegen_parser_tests(project_root: PathBuf, check: bool) -> Vec<PathBuf> {
+    let mut stale_files = Vec::new();
+    let parser_src = project_root.join("crates/parser/src");
+    let mut tests = BTreeMap::new();
+    let mut grammar_files = vec![parser_src.join("grammar.rs")];
+    grammar_files.extend(rust_files(&parser_src.join("grammar")));
+    for file in grammar_files {
+        for test in collect_tests(&read_to_string(&file).unwrap()) {
+            if let Some(existing) = tests.insert(test.name.clone(), test) {
+                panic!("duplicate inline test `{}` in {}", existing.name, file.display());
+            }
+        }
+    }
+
+    let inline_tests_dir = project_root.join("crates/syntax/test_data/parser/inline");
+    for (dir, is_err) in [("ok", false), ("err", true)] {
+        let dir = inline_tests_dir.join(dir);
+        let mut expected_files = Vec::new();
+        for test in tests.values().filter(|it| it.is_err == is_err) {
+            let file = dir.join(format!("{}.{DENIM_EXTENSION}", test.name));
+            if ensure_file_contents(&project_root, &file, &test.text, check) {
+                stale_files.push(file.clone());
+            }
+            expected_files.push(file);
+        }
+
+        for file in existing_fixtures(&dir) {
+            if expected_files.contains(&file) {
+                continue;
+            }
+            stale_files.push(file.clone());
+            if check {
+                eprintln!("{} does not belong to any inline test", file.display());
+                continue;
+            }
+            eprintln!("removing {}, which does not belong to any inline test", file.display());
+            remove_file(&file).unwrap();
+            let _ = remove_file(file.with_extension("rast"));
+        }
+    }
+
+    stale_files
+}
+
+/// Returns the tests in the `//` comment blocks of `text` that start with
+/// `test` or `test_err`.
+fn collect_tests(text: &str) -> Vec<InlineTest> {
+    let mut tests = Vec::new();
+    for block in comment_blocks(text) {
+        let Some((header, lines)) = block.split_first() else {
+            continue;
+        };
+        let (is_err, name) = match header.split_once(' ') {
+            Some(("test", name)) => (false, name),
+            Some(("test_err", name)) => (true, name),
+            _ => continue,
+        };
+
+        let mut text = lines.join("\n");
+        text.push('\n');
+        tests.push(InlineTest { name: name.trim().to_owned(), text, is_err });
+    }
+    tests
+}
+
+/// Returns the contents of each run of consecutive `//` comments in `text`,
+/// leaving out doc comments.
+fn comment_blocks(text: &str) -> Vec<Vec<&str>> {
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_start();
+        match line.strip_prefix("//") {
+            Some(comment) if !comment.starts_with(['/', '!']) => {
+                block.push(comment.strip_prefix(' ').unwrap_or(comment));
+            }
+            _ if !block.is_empty() => blocks.push(std::mem::take(&mut block)),
+            _ => (),
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+fn rust_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(rust_files(&path));
+        } else if path.extension().is_some_and(|it| it == "rs") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+fn existing_fixtures(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .map(|it| it.unwrap().path())
+        .filter(|it| it.extension().is_some_and(|it| it == DENIM_EXTENSION))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_tests_from_comment_blocks() {
+        let tests = collect_tests(
+            "
+/// Docs, not a test.
+// test a_fn
+// fn a() {
+//     1
+// }
+fn a() {}
+
+    // Not a test.
+    // test not_first
+    // fn b() {}
+
+    // test_err missing_name
+    // fn () {}
+",
+        );
+        let tests = tests.iter().map(|it| (it.name.as_str(), it.text.as_str(), it.is_err));
+        assert_eq!(
+            tests.collect::<Vec<_>>(),
+            [("a_fn", "fn a() {\n    1\n}\n", false), ("missing_name", "fn () {}\n", true)]
+        );
+    }
+}
@@ -6,15 +6,11 @@ use std::{
 use crate::CodegenCommands;
 
 /// Checks that the `file` has the specified `contents`. If that is not the
-/// case, updates the file and then fails the test.
+/// case, prints a diff when `check` is set, and updates the file otherwise.
+///
+/// Returns whether the file was out of date.
 #[allow(clippy::print_stderr)]
-pub fn ensure_file_contents(
-    command: &CodegenCommands,
-    project_root: &PathBuf,
-    file: &Path,
-    contents: &str,
-    check: bool,
-) -> bool {
+pub fn ensure_file_contents(project_root: &Path, file: &Path, contents: &str, check: bool) -> bool {
     let contents = normalize_newlines(contents);
     if let Ok(old_contents) = fs::read_to_string(file) {
         if normalize_newlines(&old_contents) == contents {
@@ -25,16 +21,11 @@ pub fn ensure_file_contents(
 
     let display_path = file.strip_prefix(project_root).unwrap_or(file);
     if check {
-        panic!(
-            "{} was not up-to-date{}",
-            file.display(),
-            if std::env::var("CI").is_ok() {
-                format!(
-                    "\n    NOTE: run `cargo xtask {command}` locally and commit the updated files\n"
-                )
-            } else {
-                "".to_owned()
-            }
+        let old_contents = fs::read_to_string(file).map(|it| normalize_newlines(&it));
+        eprintln!(
+            "\n\x1b[31;1merror\x1b[0m: {} was not up-to-date:\n{}",
+            display_path.display(),
+            diff(old_contents.as_deref().unwrap_or_default(), &contents),
         );
     } else {
         eprintln!(
@@ -46,10 +37,79 @@ pub fn ensure_file_contents(
             let _ = fs::create_dir_all(parent);
         }
         fs::write(file, contents).unwrap();
-        true
     }
+    true
+}
+
+/// Describes the out of date `files` that a check found, and how to update
+/// them.
+pub fn stale_files_message(
+    command: &CodegenCommands,
+    project_root: &Path,
+    files: &[PathBuf],
+) -> String {
+    let mut message = format!("{} file(s) were not up-to-date:\n", files.len());
+    for file in files {
+        let display_path = file.strip_prefix(project_root).unwrap_or(file);
+        message.push_str(&format!("    {}\n", display_path.display()));
+    }
+    message
+        .push_str(&format!("    NOTE: run `cargo xtask {command}` and commit the updated files\n"));
+    message
 }
 
 fn normalize_newlines(s: &str) -> String {
     s.replace("\r\n", "\n")
 }
+
+/// Number of unchanged lines shown around the changed ones in a diff.
+const DIFF_CONTEXT: usize = 3;
+
+/// Returns a line diff that turns `old` into `new`, showing everything between
+/// the first and the last changed line as a single hunk.
+fn diff(old: &str, new: &str) -> String {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let start = prefix.saturating_sub(DIFF_CONTEXT);
+    let context_after = suffix.min(DIFF_CONTEXT);
+    let mut diff = format!(
+        "@@ -{},{} +{},{} @@\n",
+        start + 1,
+        old.len() - suffix + context_after - start,
+        start + 1,
+        new.len() - suffix + context_after - start,
+    );
+    let mut push_lines = |marker: char, lines: &[&str]| {
+        for line in lines {
+            diff.push(marker);
+            diff.push_str(line);
+            diff.push('\n');
+        }
+    };
+    push_lines(' ', &old[start..prefix]);
+    push_lines('-', &old[prefix..old.len() - suffix]);
+    push_lines('+', &new[prefix..new.len() - suffix]);
+    push_lines(' ', &old[old.len() - suffix..old.len() - suffix + context_after]);
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_shows_changed_lines_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "a\nb\nc\nd\nE\nE2\nf\ng\nh\n";
+        assert_eq!(diff(old, new), "@@ -2,7 +2,8 @@\n b\n c\n d\n-e\n+E\n+E2\n f\n g\n h\n");
+        assert_eq!(diff("", "a\n"), "@@ -1,0 +1,1 @@\n+a\n");
+    }
+}
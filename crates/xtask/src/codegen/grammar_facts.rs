@@ -1,5 +1,5 @@
 use serde::Deserialize;
-use std::{collections::HashMap, fs::read_to_string, path::PathBuf};
+use std::{collections::HashMap, fs::read_to_string, path::Path};
 
 #[derive(Debug, Deserialize)]
 pub struct GrammarFacts {
@@ -13,7 +13,7 @@ pub struct GrammarFacts {
 }
 
 impl GrammarFacts {
-    pub fn read(project_root: &PathBuf) -> GrammarFacts {
+    pub fn read(project_root: &Path) -> GrammarFacts {
         let grammar_facts_file_path = project_root.join("crates/syntax/denim_grammar_facts.toml");
 
         let grammar_facts_file_contents = read_to_string(grammar_facts_file_path).unwrap();
@@ -2,6 +2,7 @@ mod ast_nodes_src;
 mod ast_src;
 mod ast_tokens_src;
 mod codegen_grammar_files;
+mod codegen_parser_tests;
 mod codegen_util;
 mod english_util;
 mod file_util;
@@ -10,3 +11,5 @@ mod lower;
 mod syntax_kinds_src;
 
 pub use codegen_grammar_files::*;
+pub use codegen_parser_tests::*;
+pub use file_util::stale_files_message;
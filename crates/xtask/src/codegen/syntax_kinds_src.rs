@@ -33,7 +33,7 @@ impl SyntaxKindsSrc {
         let mut sorted_punct_entries: Vec<_> = grammar_facts.punctuation_names.iter().collect();
 
         // Ensure that `puncts` has a stable order.
-        sorted_punct_entries.sort_by(|a, b| a.1.cmp(&b.1));
+        sorted_punct_entries.sort_by(|a, b| a.1.cmp(b.1));
 
         let puncts: Vec<(String, String)> =
             sorted_punct_entries.into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
@@ -69,7 +69,7 @@ impl SyntaxKindsSrc {
                 }
                 _ => {
                     let idx =
-                        puncts.iter().position(|(punct, _)| punct == &name).unwrap_or_else(|| {
+                        puncts.iter().position(|(punct, _)| *punct == name).unwrap_or_else(|| {
                             panic!("Grammar references unknown punctuation {name:?}")
                         });
                     used_puncts[idx] = true;
@@ -77,9 +77,9 @@ impl SyntaxKindsSrc {
             }
         });
 
-        puncts.iter().zip(used_puncts).filter(|(_, used)| !used).for_each(|((punct, _), _)| {
+        if let Some(((punct, _), _)) = puncts.iter().zip(used_puncts).find(|(_, used)| !used) {
             panic!("Punctuation {punct:?} is not used in grammar");
-        });
+        }
         keywords.extend(
             grammar_facts.reserved_words.iter().map(|reserved_word| reserved_word.to_owned()),
         );
@@ -127,6 +127,9 @@ impl SyntaxKindsSrc {
             if "{}[]()".contains(token) {
                 let c = token.chars().next().unwrap();
                 quote! { #c }
+            // Underscore is an identifier in the proc-macro API.
+            } else if token == "_" {
+                quote! { _ }
             } else {
                 let cs = token.chars().map(|c| Punct::new(c, Spacing::Joint));
                 quote! { #(#cs)* }
@@ -239,6 +242,10 @@ impl SyntaxKindsSrc {
                 [ident] => { $crate::SyntaxKind::IDENT };
                 [string] => { $crate::SyntaxKind::STRING };
                 [shebang] => { $crate::SyntaxKind::SHEBANG };
+                [string_start] => { $crate::SyntaxKind::STRING_START };
+                [string_end] => { $crate::SyntaxKind::STRING_END };
+                [interpolation_start] => { $crate::SyntaxKind::INTERPOLATION_START };
+                [interpolation_end] => { $crate::SyntaxKind::INTERPOLATION_END };
             }
         };
 
@@ -247,4 +254,5 @@ impl SyntaxKindsSrc {
 }
 
 const EOF: &str = "EOF";
-const TOKENS: &[&str] = &["ERROR", "WHITESPACE", "NEWLINE", "COMMENT"];
+const TOKENS: &[&str] =
+    &["ERROR", "WHITESPACE", "NEWLINE", "COMMENT", "SHEBANG", "STRING_FRAGMENT"];
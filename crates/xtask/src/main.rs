@@ -1,9 +1,9 @@
 use clap::{Parser, Subcommand};
-use codegen::codegen_grammar_files;
+use codegen::{codegen_grammar_files, codegen_parser_tests, stale_files_message};
 use std::env;
 use std::fmt::Display;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{self, Command};
 
 mod codegen;
 
@@ -36,7 +36,14 @@ enum BookCommands {
 pub enum CodegenCommands {
     /// Generates all grammar and syntax source files.
     #[command(name = "grammar")]
-    Grammar,
+    Grammar {
+        /// Fails with a diff instead of updating files that are out of date.
+        #[arg(long)]
+        check: bool,
+    },
+    /// Extracts the inline tests of the grammar into parser test fixtures.
+    #[command(name = "parser-tests")]
+    ParserTests,
 }
 
 fn main() {
@@ -62,8 +69,18 @@ fn main() {
             }
         },
         Some(Commands::Codegen(codegen_command)) => match codegen_command {
-            CodegenCommands::Grammar => {
-                codegen_grammar_files(codegen_command, project_root(), false)
+            CodegenCommands::Grammar { check } => {
+                let stale_files = codegen_grammar_files(project_root(), *check);
+                if *check && !stale_files.is_empty() {
+                    eprint!(
+                        "{}",
+                        stale_files_message(codegen_command, &project_root(), &stale_files)
+                    );
+                    process::exit(1);
+                }
+            }
+            CodegenCommands::ParserTests => {
+                codegen_parser_tests(project_root(), false);
             }
         },
         _ => {}
@@ -81,7 +98,8 @@ fn project_root() -> PathBuf {
 impl Display for CodegenCommands {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CodegenCommands::Grammar => write!(f, "gen grammar"),
+            CodegenCommands::Grammar { .. } => write!(f, "gen grammar"),
+            CodegenCommands::ParserTests => write!(f, "gen parser-tests"),
         }
     }
 }